@@ -0,0 +1,386 @@
+use super::Interval;
+use std::cmp::Ordering::{Equal, Greater, Less};
+use std::fmt::Display;
+
+/// Allen's interval relations
+///
+/// Relations only depend on endpoint values, openness is ignored: `[0,1)` meets `[1,2]`
+/// just like `[0,1]` does. Variants are ordered so that a relation and its converse are
+/// symmetric around `Equals`.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Relation {
+    Before,
+    Meets,
+    Overlaps,
+    Starts,
+    During,
+    Finishes,
+    Equals,
+    FinishedBy,
+    Contains,
+    StartedBy,
+    OverlappedBy,
+    MetBy,
+    After,
+}
+
+use Relation::*;
+
+const RELATIONS: [Relation; 13] = [
+    Before,
+    Meets,
+    Overlaps,
+    Starts,
+    During,
+    Finishes,
+    Equals,
+    FinishedBy,
+    Contains,
+    StartedBy,
+    OverlappedBy,
+    MetBy,
+    After,
+];
+
+impl Display for Relation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Before => "before",
+            Meets => "meets",
+            Overlaps => "overlaps",
+            Starts => "starts",
+            During => "during",
+            Finishes => "finishes",
+            Equals => "equals",
+            FinishedBy => "finished by",
+            Contains => "contains",
+            StartedBy => "started by",
+            OverlappedBy => "overlapped by",
+            MetBy => "met by",
+            After => "after",
+        };
+        write!(f, "{name}")
+    }
+}
+
+impl Relation {
+    /// Classify relation of `a` with respect to `b`
+    ///
+    /// Returns `None` if one of the intervals is empty. Degenerate intervals (singletons)
+    /// get the first relation, in `Equals`, `Before`, `Meets`, `After`, `MetBy` order,
+    /// matching their endpoints.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Relation, Closed, Open};
+    ///
+    /// let a = Interval::new(Closed(0.), Open(10.));
+    /// let b = Interval::new(Closed(10.), Closed(20.));
+    /// let c = Interval::new(Closed(12.), Closed(15.));
+    ///
+    /// assert_eq!(Relation::between(a, b), Some(Relation::Meets));
+    /// assert_eq!(Relation::between(c, b), Some(Relation::During));
+    /// ```
+    ///
+    pub fn between(a: Interval, b: Interval) -> Option<Relation> {
+        let ((a1, a2), (b1, b2)) = (a.endpoints()?, b.endpoints()?);
+
+        let relation = if a1 == b1 && a2 == b2 {
+            Equals
+        } else if a2 < b1 {
+            Before
+        } else if a2 == b1 {
+            Meets
+        } else if b2 < a1 {
+            After
+        } else if b2 == a1 {
+            MetBy
+        } else {
+            match (a1.partial_cmp(&b1)?, a2.partial_cmp(&b2)?) {
+                (Less, Less) => Overlaps,
+                (Less, Equal) => FinishedBy,
+                (Less, Greater) => Contains,
+                (Equal, Less) => Starts,
+                (Equal, Greater) => StartedBy,
+                (Greater, Less) => During,
+                (Greater, Equal) => Finishes,
+                (Greater, Greater) => OverlappedBy,
+                (Equal, Equal) => Equals,
+            }
+        };
+        Some(relation)
+    }
+
+    /// Relation of `b` with respect to `a` when `self` is the relation of `a` with respect
+    /// to `b`
+    ///
+    pub fn converse(self) -> Relation {
+        RELATIONS[12 - self as usize]
+    }
+
+    /// Possible relations of `a` with respect to `c`, knowing `a self b` and `b other c`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::Relation::*;
+    ///
+    /// let r = Meets.compose(During);
+    ///
+    /// assert!(r.contains(Overlaps));
+    /// assert!(r.contains(Starts));
+    /// assert!(r.contains(During));
+    /// assert_eq!(r.len(), 3);
+    /// ```
+    ///
+    pub fn compose(self, other: Relation) -> RelationSet {
+        RelationSet(COMPOSITION[self as usize][other as usize])
+    }
+}
+
+/// Set of Allen relations (a disjunction of relations)
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct RelationSet(u16);
+
+impl Display for RelationSet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{{")?;
+        for (i, relation) in self.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{relation}")?;
+        }
+        write!(f, "}}")
+    }
+}
+
+impl From<Relation> for RelationSet {
+    fn from(relation: Relation) -> Self {
+        RelationSet(1 << relation as u16)
+    }
+}
+
+impl FromIterator<Relation> for RelationSet {
+    fn from_iter<T: IntoIterator<Item = Relation>>(iter: T) -> Self {
+        let mut res = RelationSet::new();
+        for relation in iter {
+            res.insert(relation);
+        }
+        res
+    }
+}
+
+impl RelationSet {
+    pub fn new() -> Self {
+        RelationSet(0)
+    }
+
+    /// Set of all thirteen relations (no information)
+    ///
+    pub fn all() -> Self {
+        RelationSet(ALL)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn contains(&self, relation: Relation) -> bool {
+        self.0 & (1 << relation as u16) != 0
+    }
+
+    pub fn insert(&mut self, relation: Relation) {
+        self.0 |= 1 << relation as u16;
+    }
+
+    pub fn union(self, other: RelationSet) -> Self {
+        RelationSet(self.0 | other.0)
+    }
+
+    pub fn intersection(self, other: RelationSet) -> Self {
+        RelationSet(self.0 & other.0)
+    }
+
+    /// Relations in ascending order
+    ///
+    pub fn iter(&self) -> impl Iterator<Item = Relation> + '_ {
+        RELATIONS.into_iter().filter(|r| self.contains(*r))
+    }
+
+    pub fn converse(self) -> Self {
+        self.iter().map(Relation::converse).collect()
+    }
+
+    /// Compose every relation of `self` with every relation of `other`
+    ///
+    /// Used to propagate constraints transitively: knowing `a self b` and `b other c`,
+    /// the result holds all possible relations of `a` with respect to `c`.
+    ///
+    pub fn compose(self, other: RelationSet) -> Self {
+        let mut res = RelationSet::new();
+        for r1 in self.iter() {
+            for r2 in other.iter() {
+                res = res.union(r1.compose(r2));
+            }
+        }
+        res
+    }
+}
+
+const B: u16 = 1 << Before as u16;
+const M: u16 = 1 << Meets as u16;
+const O: u16 = 1 << Overlaps as u16;
+const S: u16 = 1 << Starts as u16;
+const D: u16 = 1 << During as u16;
+const F: u16 = 1 << Finishes as u16;
+const E: u16 = 1 << Equals as u16;
+const FI: u16 = 1 << FinishedBy as u16;
+const DI: u16 = 1 << Contains as u16;
+const SI: u16 = 1 << StartedBy as u16;
+const OI: u16 = 1 << OverlappedBy as u16;
+const MI: u16 = 1 << MetBy as u16;
+const A: u16 = 1 << After as u16;
+const ALL: u16 = (1 << 13) - 1;
+
+/// Allen's composition table, indexed by `[a rel b][b rel c]`
+///
+#[rustfmt::skip]
+const COMPOSITION: [[u16; 13]; 13] = [
+    // Before
+    [B, B, B, B, B | M | O | S | D, B | M | O | S | D, B, B, B, B, B | M | O | S | D, B | M | O | S | D, ALL],
+    // Meets
+    [B, B, B, M, O | S | D, O | S | D, M, B, B, M, O | S | D, F | E | FI, DI | SI | OI | MI | A],
+    // Overlaps
+    [B, B, B | M | O, O, O | S | D, O | S | D, O, B | M | O, B | M | O | FI | DI, O | FI | DI, O | S | D | F | E | FI | DI | SI | OI, DI | SI | OI, DI | SI | OI | MI | A],
+    // Starts
+    [B, B, B | M | O, S, D, D, S, B | M | O, B | M | O | FI | DI, S | E | SI, D | F | OI, MI, A],
+    // During
+    [B, B, B | M | O | S | D, D, D, D, D, B | M | O | S | D, ALL, D | F | OI | MI | A, D | F | OI | MI | A, A, A],
+    // Finishes
+    [B, M, O | S | D, D, D, F, F, F | E | FI, DI | SI | OI | MI | A, OI | MI | A, OI | MI | A, A, A],
+    // Equals
+    [B, M, O, S, D, F, E, FI, DI, SI, OI, MI, A],
+    // FinishedBy
+    [B, M, O, O, O | S | D, F | E | FI, FI, FI, DI, DI, DI | SI | OI, DI | SI | OI, DI | SI | OI | MI | A],
+    // Contains
+    [B | M | O | FI | DI, O | FI | DI, O | FI | DI, O | FI | DI, O | S | D | F | E | FI | DI | SI | OI, DI | SI | OI, DI, DI, DI, DI, DI | SI | OI, DI | SI | OI, DI | SI | OI | MI | A],
+    // StartedBy
+    [B | M | O | FI | DI, O | FI | DI, O | FI | DI, S | E | SI, D | F | OI, OI, SI, DI, DI, SI, OI, MI, A],
+    // OverlappedBy
+    [B | M | O | FI | DI, O | FI | DI, O | S | D | F | E | FI | DI | SI | OI, D | F | OI, D | F | OI, OI, OI, DI | SI | OI, DI | SI | OI | MI | A, OI | MI | A, OI | MI | A, A, A],
+    // MetBy
+    [B | M | O | FI | DI, S | E | SI, D | F | OI, D | F | OI, D | F | OI, MI, MI, MI, A, A, A, A, A],
+    // After
+    [ALL, D | F | OI | MI | A, D | F | OI | MI | A, D | F | OI | MI | A, D | F | OI | MI | A, A, A, A, A, A, A, A, A],
+];
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Closed, Open, Unbound, EMPTY};
+
+    #[test]
+    fn test_between_1() {
+        let b = Interval::new(Closed(10.), Closed(20.));
+        let expected = [
+            (Interval::new(Closed(0.), Closed(5.)), Before),
+            (Interval::new(Closed(0.), Closed(10.)), Meets),
+            (Interval::new(Closed(5.), Closed(15.)), Overlaps),
+            (Interval::new(Closed(10.), Closed(15.)), Starts),
+            (Interval::new(Closed(12.), Closed(15.)), During),
+            (Interval::new(Closed(15.), Closed(20.)), Finishes),
+            (Interval::new(Closed(10.), Closed(20.)), Equals),
+            (Interval::new(Closed(5.), Closed(20.)), FinishedBy),
+            (Interval::new(Closed(5.), Closed(25.)), Contains),
+            (Interval::new(Closed(10.), Closed(25.)), StartedBy),
+            (Interval::new(Closed(15.), Closed(25.)), OverlappedBy),
+            (Interval::new(Closed(20.), Closed(25.)), MetBy),
+            (Interval::new(Closed(25.), Closed(30.)), After),
+        ];
+        for (a, relation) in expected {
+            assert_eq!(Relation::between(a, b), Some(relation));
+            assert_eq!(Relation::between(b, a), Some(relation.converse()));
+        }
+    }
+
+    #[test]
+    fn test_between_2() {
+        let a = Interval::new(Closed(0.), Open(10.));
+        let b = Interval::new(Open(10.), Closed(20.));
+        assert_eq!(Relation::between(a, b), Some(Meets));
+        assert_eq!(Relation::between(a, EMPTY), None);
+        assert_eq!(Relation::between(EMPTY, a), None);
+    }
+
+    #[test]
+    fn test_between_3() {
+        let a = Interval::new(Unbound, Closed(10.));
+        let b = Interval::new(Unbound, Closed(20.));
+        assert_eq!(Relation::between(a, b), Some(Starts));
+        assert_eq!(
+            Relation::between(b, Interval::new(Open(0.), Unbound)),
+            Some(Overlaps)
+        );
+    }
+
+    #[test]
+    fn test_converse_1() {
+        for relation in RELATIONS {
+            assert_eq!(relation.converse().converse(), relation);
+        }
+        assert_eq!(Equals.converse(), Equals);
+        assert_eq!(During.converse(), Contains);
+    }
+
+    #[test]
+    fn test_compose_1() {
+        // Brute force check of the composition table over integer endpoints
+        let intervals: Vec<_> = (0..7)
+            .flat_map(|i| {
+                (i + 1..7).map(move |j| Interval::new(Closed(i as f64), Closed(j as f64)))
+            })
+            .collect();
+        let mut expected = [[RelationSet::new(); 13]; 13];
+        for a in intervals.iter() {
+            for b in intervals.iter() {
+                for c in intervals.iter() {
+                    let ab = Relation::between(*a, *b).unwrap();
+                    let bc = Relation::between(*b, *c).unwrap();
+                    let ac = Relation::between(*a, *c).unwrap();
+                    expected[ab as usize][bc as usize].insert(ac);
+                }
+            }
+        }
+        for r1 in RELATIONS {
+            for r2 in RELATIONS {
+                assert_eq!(r1.compose(r2), expected[r1 as usize][r2 as usize]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_compose_2() {
+        let r = RelationSet::from(Before).union(Meets.into());
+        assert_eq!(r.compose(Before.into()), Before.into());
+        assert_eq!(RelationSet::new().compose(r), RelationSet::new());
+        assert_eq!(After.compose(Before), RelationSet::all());
+    }
+
+    #[test]
+    fn test_relation_set_1() {
+        let r: RelationSet = [Starts, During, Starts].into_iter().collect();
+        assert_eq!(r.len(), 2);
+        assert!(r.contains(During));
+        assert!(!r.contains(Finishes));
+        assert_eq!(r.converse(), [StartedBy, Contains].into_iter().collect());
+        assert_eq!(format!("{r}"), "{starts, during}");
+    }
+}
@@ -0,0 +1,139 @@
+//! Support for `assert_interval_eq!`
+
+use super::{Closed, Interval, IntervalSet, Open, Unbound};
+
+/// Approximate equality with a readable report of differences
+///
+pub trait ApproxEq {
+    /// Check if endpoints are within `eps` of each other, with the same openness
+    ///
+    fn approx_eq(&self, other: &Self, eps: f64) -> bool;
+
+    /// Describe both values, line by line, marking differences
+    ///
+    fn diff(&self, other: &Self, eps: f64) -> String;
+}
+
+impl ApproxEq for Interval {
+    fn approx_eq(&self, other: &Self, eps: f64) -> bool {
+        Interval::approx_eq(*self, *other, eps)
+    }
+
+    fn diff(&self, other: &Self, _eps: f64) -> String {
+        format!("  left: {}\n right: {}", exact(*self), exact(*other))
+    }
+}
+
+impl ApproxEq for IntervalSet {
+    fn approx_eq(&self, other: &Self, eps: f64) -> bool {
+        let (a, b) = (self.as_slice(), other.as_slice());
+        a.len() == b.len() && a.iter().zip(b).all(|(a, b)| a.approx_eq(b, eps))
+    }
+
+    fn diff(&self, other: &Self, eps: f64) -> String {
+        let (a, b) = (self.as_slice(), other.as_slice());
+        let mut lines = Vec::new();
+        for i in 0..a.len().max(b.len()) {
+            match (a.get(i), b.get(i)) {
+                (Some(a), Some(b)) if a.approx_eq(b, eps) => {
+                    lines.push(format!("  {}", exact(*a)));
+                }
+                (a, b) => {
+                    lines.extend(a.map(|a| format!("- {}", exact(*a))));
+                    lines.extend(b.map(|b| format!("+ {}", exact(*b))));
+                }
+            }
+        }
+        lines.join("\n")
+    }
+}
+
+/// Display interval with endpoints at full precision
+///
+fn exact(a: Interval) -> String {
+    if a.is_empty() {
+        return "∅".to_string();
+    }
+    let left = match a.into_bounds().0 {
+        Closed(k) => format!("[{k:?}"),
+        Open(k) => format!("({k:?}"),
+        Unbound => "(-∞".to_string(),
+    };
+    let right = match a.into_bounds().1 {
+        Closed(k) => format!("{k:?}]"),
+        Open(k) => format!("{k:?})"),
+        Unbound => "+∞)".to_string(),
+    };
+    format!("{left},{right}")
+}
+
+/// Assert that two intervals (or interval sets) are equal, endpoints being compared within
+/// an optional `epsilon`
+///
+/// On failure, both values are displayed at full precision; for sets, segments only in the
+/// left value are marked `-` and segments only in the right value `+`.
+///
+/// # Example
+///
+/// ```
+/// use interval::{assert_interval_eq, Interval, IntervalSet, Closed, Open};
+///
+/// let a = Interval::new(Closed(0.), Open(0.1 + 0.2));
+///
+/// assert_interval_eq!(a, Interval::new(Closed(0.), Open(0.3)), epsilon = 1e-9);
+/// assert_interval_eq!(IntervalSet::from(&[a]), IntervalSet::from(&[a]));
+/// ```
+///
+#[macro_export]
+macro_rules! assert_interval_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::assert_interval_eq!($left, $right, epsilon = 0.)
+    };
+    ($left:expr, $right:expr, epsilon = $eps:expr $(,)?) => {
+        match (&$left, &$right, $eps) {
+            (left, right, eps) => {
+                if !$crate::approx::ApproxEq::approx_eq(left, right, eps) {
+                    panic!(
+                        "assertion `left ≈ right` failed (epsilon = {:e})\n{}",
+                        eps,
+                        $crate::approx::ApproxEq::diff(left, right, eps)
+                    );
+                }
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::EMPTY;
+
+    #[test]
+    fn test_assert_interval_eq_1() {
+        let a = Interval::new(Unbound, Closed(0.3));
+        assert_interval_eq!(
+            a,
+            Interval::new(Unbound, Closed(0.1 + 0.2)),
+            epsilon = 1e-12
+        );
+        assert_interval_eq!(EMPTY, EMPTY);
+    }
+
+    #[test]
+    #[should_panic(expected = "  left: [0.0,1.0)\n right: [0.0,1.5)")]
+    fn test_assert_interval_eq_2() {
+        let a = Interval::new(Closed(0.), Open(1.));
+        assert_interval_eq!(a, Interval::new(Closed(0.), Open(1.5)), epsilon = 0.1);
+    }
+
+    #[test]
+    #[should_panic(expected = "  [0.0,1.0]\n- [2.0,3.0]\n+ [2.0,4.0]\n+ [5.0,6.0]")]
+    fn test_assert_interval_eq_3() {
+        let a = Interval::new(Closed(0.), Closed(1.));
+        let b = Interval::new(Closed(2.), Closed(3.));
+        let c = Interval::new(Closed(2.), Closed(4.));
+        let d = Interval::new(Closed(5.), Closed(6.));
+        assert_interval_eq!(IntervalSet::from(&[a, b]), IntervalSet::from(&[a, c, d]));
+    }
+}
@@ -0,0 +1,412 @@
+//! Interval arithmetic on closed hulls
+//!
+//! Operands are taken by their endpoint values regardless of openness and results are
+//! closed (or unbound) intervals, a sound over-approximation of the exact result. Computed
+//! endpoints are rounded outward by one ulp when the operation is inexact, which is told
+//! by error-free transformations, so that exact results are kept as they are.
+
+use super::interval::{next_down, next_up};
+use super::{Interval, EMPTY, INFINITY};
+
+/// Rounded value of an operation, with a number having the sign of the exact value minus
+/// the rounded one (NaN if unknown)
+///
+#[derive(Debug, Clone, Copy)]
+struct Rounded(f64, f64);
+
+impl Rounded {
+    fn exact(x: f64) -> Self {
+        Rounded(x, 0.)
+    }
+
+    /// Largest float not above the exact value
+    ///
+    fn down(self) -> f64 {
+        match self {
+            Rounded(x, err) if err < 0. || err.is_nan() => next_down(x),
+            Rounded(x, _) => x,
+        }
+    }
+
+    /// Smallest float not below the exact value
+    ///
+    fn up(self) -> f64 {
+        match self {
+            Rounded(x, err) if err > 0. || err.is_nan() => next_up(x),
+            Rounded(x, _) => x,
+        }
+    }
+}
+
+/// Largest finite float of the sign of `x`, beyond which an overflowing result lies
+///
+fn overflowed(x: f64) -> Rounded {
+    Rounded(f64::MAX.copysign(x), x)
+}
+
+/// `a + b`, error given by Knuth's two-sum
+///
+fn add_value(a: f64, b: f64) -> Rounded {
+    let s = a + b;
+    if a.is_infinite() || b.is_infinite() {
+        return Rounded::exact(s);
+    }
+    if s.is_infinite() {
+        return overflowed(s);
+    }
+    let bb = s - a;
+    Rounded(s, (a - (s - bb)) + (b - bb))
+}
+
+/// Product of endpoint values with the `0 × ∞ = 0` convention, error given by a fused
+/// multiply-add
+///
+fn mul_value(a: f64, b: f64) -> Rounded {
+    let p = a * b;
+    if a == 0. || b == 0. {
+        Rounded::exact(0.)
+    } else if a.is_infinite() || b.is_infinite() {
+        Rounded::exact(p)
+    } else if p.is_infinite() {
+        overflowed(p)
+    } else if p.abs() < f64::MIN_POSITIVE {
+        // Error of an underflowing product may not be representable
+        Rounded(p, f64::NAN)
+    } else {
+        Rounded(p, a.mul_add(b, -p))
+    }
+}
+
+/// `1 / a`, `1 / 0` being `+∞`
+///
+fn recip_value(a: f64) -> Rounded {
+    let q = 1. / a;
+    if a == 0. || a.is_infinite() {
+        Rounded::exact(q)
+    } else if q.is_infinite() || q.abs() < f64::MIN_POSITIVE {
+        Rounded(q, f64::NAN)
+    } else {
+        // 1/a - q = -(q a - 1) / a
+        Rounded(q, -q.mul_add(a, -1.) * a.signum())
+    }
+}
+
+/// `√a` for `a >= 0`
+///
+fn sqrt_value(a: f64) -> Rounded {
+    let r = a.sqrt();
+    if r == 0. || r.is_infinite() {
+        Rounded::exact(r)
+    } else {
+        // √a - r has the sign of a - r²
+        Rounded(r, -r.mul_add(r, -a))
+    }
+}
+
+pub(crate) fn neg(a: Interval) -> Interval {
+    match a.endpoints() {
+        Some((a1, a2)) => Interval::from_endpoints(-a2, -a1),
+        None => EMPTY,
+    }
+}
+
+pub(crate) fn add(a: Interval, b: Interval) -> Interval {
+    match (a.endpoints(), b.endpoints()) {
+        (Some((a1, a2)), Some((b1, b2))) => {
+            Interval::from_endpoints(add_value(a1, b1).down(), add_value(a2, b2).up())
+        }
+        _ => EMPTY,
+    }
+}
+
+pub(crate) fn sub(a: Interval, b: Interval) -> Interval {
+    add(a, neg(b))
+}
+
+pub(crate) fn mul(a: Interval, b: Interval) -> Interval {
+    match (a.endpoints(), b.endpoints()) {
+        (Some((a1, a2)), Some((b1, b2))) => {
+            let products = [
+                mul_value(a1, b1),
+                mul_value(a1, b2),
+                mul_value(a2, b1),
+                mul_value(a2, b2),
+            ];
+            let lower = products
+                .iter()
+                .map(|p| p.down())
+                .fold(f64::INFINITY, f64::min);
+            let upper = products
+                .iter()
+                .map(|p| p.up())
+                .fold(f64::NEG_INFINITY, f64::max);
+            Interval::from_endpoints(lower, upper)
+        }
+        _ => EMPTY,
+    }
+}
+
+/// Hull of `{1/x, x ∈ a, x ≠ 0}`
+///
+fn recip(a: Interval) -> Interval {
+    match a.endpoints() {
+        Some((a1, a2)) if a1 > 0. || a2 < 0. => {
+            Interval::from_endpoints(recip_value(a2).down(), recip_value(a1).up())
+        }
+        Some((a1, a2)) if a1 < 0. && a2 > 0. => INFINITY,
+        // Remaining intervals have a zero endpoint
+        Some((a1, a2)) if a1 == a2 => EMPTY,
+        Some((a1, _)) if a1 < 0. => {
+            Interval::from_endpoints(f64::NEG_INFINITY, recip_value(a1).up())
+        }
+        Some((_, a2)) => Interval::from_endpoints(recip_value(a2).down(), f64::INFINITY),
+        None => EMPTY,
+    }
+}
+
+/// Hull of `{x / y, x ∈ a, y ∈ b, y ≠ 0}`
+///
+pub(crate) fn div(a: Interval, b: Interval) -> Interval {
+    if a.is_empty() || b.is_empty() {
+        EMPTY
+    } else if contains_value(a, 0.) && contains_value(b, 0.) {
+        INFINITY
+    } else {
+        mul(a, recip(b))
+    }
+}
+
+pub(crate) fn sqr(a: Interval) -> Interval {
+    match a.endpoints() {
+        Some((a1, a2)) if a1 <= 0. && a2 >= 0. => {
+            Interval::from_endpoints(0., mul_value(a1, a1).up().max(mul_value(a2, a2).up()))
+        }
+        Some((a1, a2)) => {
+            let (s1, s2) = (mul_value(a1, a1), mul_value(a2, a2));
+            Interval::from_endpoints(s1.down().min(s2.down()), s1.up().max(s2.up()))
+        }
+        None => EMPTY,
+    }
+}
+
+/// Hull of `{x ∈ domain, x² ∈ a}`
+///
+pub(crate) fn sqr_inverse(a: Interval, domain: Interval) -> Interval {
+    match a.endpoints() {
+        Some((_, a2)) if a2 < 0. => EMPTY,
+        Some((a1, a2)) => {
+            let (lower, upper) = (sqrt_value(a1.max(0.)).down(), sqrt_value(a2).up());
+            let positive = Interval::from_endpoints(lower, upper);
+            let negative = Interval::from_endpoints(-upper, -lower);
+            domain
+                .intersection(positive)
+                .hull(domain.intersection(negative))
+        }
+        None => EMPTY,
+    }
+}
+
+/// Check if `x` lies in the closure of interval
+///
+fn contains_value(a: Interval, x: f64) -> bool {
+    matches!(a.endpoints(), Some((lower, upper)) if lower <= x && x <= upper)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Closed, Open, Unbound};
+
+    #[test]
+    fn test_add_1() {
+        assert_eq!(
+            add(
+                Interval::new(Closed(1.), Closed(2.)),
+                Interval::new(Closed(10.), Closed(20.))
+            ),
+            Interval::new(Closed(11.), Closed(22.))
+        );
+        assert_eq!(add(Interval::new(Closed(1.), Closed(2.)), EMPTY), EMPTY);
+        assert_eq!(
+            add(
+                Interval::new(Open(1.), Unbound),
+                Interval::new(Closed(10.), Closed(20.))
+            ),
+            Interval::new(Closed(11.), Unbound)
+        );
+    }
+
+    #[test]
+    fn test_sub_1() {
+        assert_eq!(
+            sub(
+                Interval::new(Closed(1.), Closed(2.)),
+                Interval::new(Closed(10.), Closed(20.))
+            ),
+            Interval::new(Closed(-19.), Closed(-8.))
+        );
+    }
+
+    #[test]
+    fn test_mul_1() {
+        assert_eq!(
+            mul(
+                Interval::new(Closed(-1.), Closed(2.)),
+                Interval::new(Closed(3.), Closed(4.))
+            ),
+            Interval::new(Closed(-4.), Closed(8.))
+        );
+        assert_eq!(
+            mul(
+                Interval::new(Closed(-1.), Closed(-2.)),
+                Interval::new(Closed(3.), Closed(4.))
+            ),
+            EMPTY
+        );
+        assert_eq!(
+            mul(
+                Interval::new(Closed(-2.), Closed(-1.)),
+                Interval::new(Closed(-4.), Closed(3.))
+            ),
+            Interval::new(Closed(-6.), Closed(8.))
+        );
+    }
+
+    #[test]
+    fn test_mul_2() {
+        assert_eq!(
+            mul(Interval::new(Closed(0.), Closed(0.)), INFINITY),
+            Interval::new(Closed(0.), Closed(0.))
+        );
+        assert_eq!(
+            mul(
+                Interval::new(Closed(0.), Closed(1.)),
+                Interval::new(Closed(1.), Unbound)
+            ),
+            Interval::new(Closed(0.), Unbound)
+        );
+    }
+
+    #[test]
+    fn test_div_1() {
+        assert_eq!(
+            div(
+                Interval::new(Closed(1.), Closed(2.)),
+                Interval::new(Closed(2.), Closed(4.))
+            ),
+            Interval::new(Closed(0.25), Closed(1.))
+        );
+        assert_eq!(
+            div(
+                Interval::new(Closed(-1.), Closed(2.)),
+                Interval::new(Closed(-2.), Closed(4.))
+            ),
+            INFINITY
+        );
+        assert_eq!(
+            div(
+                Interval::new(Closed(1.), Closed(2.)),
+                Interval::new(Closed(0.), Closed(0.))
+            ),
+            EMPTY
+        );
+        assert_eq!(
+            div(
+                Interval::new(Closed(1.), Closed(2.)),
+                Interval::new(Closed(0.), Closed(4.))
+            ),
+            Interval::new(Closed(0.25), Unbound)
+        );
+        assert_eq!(
+            div(
+                Interval::new(Closed(1.), Closed(2.)),
+                Interval::new(Closed(-4.), Closed(0.))
+            ),
+            Interval::new(Unbound, Closed(-0.25))
+        );
+    }
+
+    #[test]
+    fn test_sqr_1() {
+        assert_eq!(
+            sqr(Interval::new(Closed(-3.), Closed(2.))),
+            Interval::new(Closed(0.), Closed(9.))
+        );
+        assert_eq!(
+            sqr(Interval::new(Closed(-3.), Closed(-2.))),
+            Interval::new(Closed(4.), Closed(9.))
+        );
+        assert_eq!(
+            sqr(Interval::new(Closed(2.), Closed(3.))),
+            Interval::new(Closed(4.), Closed(9.))
+        );
+    }
+
+    #[test]
+    fn test_rounding_1() {
+        // Inexact operations give enclosures of the exact results, one ulp wide
+        let k = Interval::singleton;
+        let (lower, upper) = add(k(0.1), k(0.2)).endpoints().unwrap();
+        assert_eq!(next_up(lower), upper);
+        assert!(lower <= 0.1 + 0.2 && 0.1 + 0.2 <= upper);
+        let (lower, upper) = div(k(1.), k(3.)).endpoints().unwrap();
+        assert_eq!(next_up(lower), upper);
+        assert!(lower.mul_add(3., -1.) < 0. && upper.mul_add(3., -1.) > 0.);
+        let (lower, upper) = mul(k(0.1), k(0.1)).endpoints().unwrap();
+        assert!(0.1f64.mul_add(0.1, -lower) > 0. && 0.1f64.mul_add(0.1, -upper) < 0.);
+        let (lower, upper) = sqr_inverse(k(2.), Interval::new(Closed(0.), Closed(2.)))
+            .endpoints()
+            .unwrap();
+        assert!(lower.mul_add(lower, -2.) < 0. && upper.mul_add(upper, -2.) > 0.);
+    }
+
+    #[test]
+    fn test_rounding_2() {
+        let k = Interval::singleton;
+        assert_eq!(
+            add(k(f64::MAX), k(f64::MAX)),
+            Interval::new(Closed(f64::MAX), Unbound)
+        );
+        assert_eq!(
+            mul(k(-f64::MAX), k(2.)),
+            Interval::new(Unbound, Closed(-f64::MAX))
+        );
+        let tiny = f64::from_bits(1);
+        let (lower, upper) = mul(k(tiny), k(0.5)).endpoints().unwrap();
+        assert!(lower < 0. && upper >= tiny);
+        assert_eq!(div(k(1.), k(4.)), k(0.25));
+        assert_eq!(
+            sqr_inverse(k(4.), Interval::new(Closed(0.), Closed(10.))),
+            k(2.)
+        );
+    }
+
+    #[test]
+    fn test_sqr_inverse_1() {
+        assert_eq!(
+            sqr_inverse(
+                Interval::new(Closed(4.), Closed(9.)),
+                Interval::new(Closed(0.), Closed(10.))
+            ),
+            Interval::new(Closed(2.), Closed(3.))
+        );
+        assert_eq!(
+            sqr_inverse(
+                Interval::new(Closed(4.), Closed(9.)),
+                Interval::new(Closed(-10.), Closed(10.))
+            ),
+            Interval::new(Closed(-3.), Closed(3.))
+        );
+        assert_eq!(
+            sqr_inverse(
+                Interval::new(Closed(4.), Closed(9.)),
+                Interval::new(Closed(-1.), Closed(1.))
+            ),
+            EMPTY
+        );
+        assert_eq!(
+            sqr_inverse(Interval::new(Closed(-4.), Closed(-1.)), INFINITY),
+            EMPTY
+        );
+    }
+}
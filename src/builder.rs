@@ -0,0 +1,162 @@
+use super::{Interval, IntervalSet};
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::sync::Mutex;
+
+/// Smallest number of intervals buffered by a shard before they are merged into its set
+///
+const PENDING: usize = 1024;
+
+/// Accumulator of intervals shared between threads, giving their union on `finish`
+///
+/// Insertions go to one of several shards, each thread sticking to its own, so that
+/// concurrent threads rarely wait for each other. Each shard merges its buffered intervals
+/// from time to time, so that memory stays proportional to the number of segments of the
+/// union rather than to the number of insertions.
+///
+/// # Example
+///
+/// ```
+/// use interval::{Interval, IntervalSet, IntervalSetBuilder, Closed, Open};
+///
+/// let builder = IntervalSetBuilder::new();
+/// std::thread::scope(|scope| {
+///     for t in 0..4 {
+///         let builder = &builder;
+///         scope.spawn(move || {
+///             for i in 0..100 {
+///                 let k = (4 * i + t) as f64;
+///                 builder.insert(Interval::new(Closed(k), Open(k + 1.)));
+///             }
+///         });
+///     }
+/// });
+///
+/// assert_eq!(builder.finish(), IntervalSet::from(&[Interval::new(Closed(0.), Open(400.))]));
+/// ```
+///
+#[derive(Debug)]
+pub struct IntervalSetBuilder {
+    shards: Vec<Mutex<Shard>>,
+}
+
+#[derive(Debug, Default)]
+struct Shard {
+    set: IntervalSet,
+    pending: Vec<Interval>,
+}
+
+impl Shard {
+    /// Merge buffered intervals into set once they outnumber its segments (and `PENDING`),
+    /// so that merging costs amortized `O(log n)` per insertion
+    ///
+    fn merge_if_full(&mut self) {
+        if self.pending.len() < PENDING.max(self.set.as_slice().len()) {
+            return;
+        }
+        let mut segments = std::mem::take(&mut self.pending);
+        segments.extend_from_slice(self.set.as_slice());
+        self.set = IntervalSet::from_unsorted(segments).checked();
+    }
+}
+
+impl Default for IntervalSetBuilder {
+    fn default() -> Self {
+        IntervalSetBuilder::new()
+    }
+}
+
+impl IntervalSetBuilder {
+    /// Builder with as many shards as the available parallelism
+    ///
+    pub fn new() -> Self {
+        let n = std::thread::available_parallelism().map_or(1, |n| n.get());
+        IntervalSetBuilder::with_shards(n)
+    }
+
+    /// Builder with `n` shards (at least one)
+    ///
+    pub fn with_shards(n: usize) -> Self {
+        IntervalSetBuilder {
+            shards: (0..n.max(1)).map(|_| Mutex::default()).collect(),
+        }
+    }
+
+    pub fn insert(&self, interval: Interval) {
+        if interval.is_empty() {
+            return;
+        }
+        let mut shard = self.shard().lock().unwrap();
+        shard.pending.push(interval);
+        shard.merge_if_full();
+    }
+
+    pub fn extend(&self, intervals: impl IntoIterator<Item = Interval>) {
+        let mut shard = self.shard().lock().unwrap();
+        shard
+            .pending
+            .extend(intervals.into_iter().filter(|i| !i.is_empty()));
+        shard.merge_if_full();
+    }
+
+    /// Union of every interval inserted
+    ///
+    pub fn finish(self) -> IntervalSet {
+        let segments: Vec<_> = self
+            .shards
+            .into_iter()
+            .flat_map(|shard| {
+                let Shard { set, pending } = shard.into_inner().unwrap();
+                set.as_slice().to_vec().into_iter().chain(pending)
+            })
+            .collect();
+        IntervalSet::from_unsorted(segments).checked()
+    }
+
+    /// Shard of current thread
+    ///
+    fn shard(&self) -> &Mutex<Shard> {
+        let mut hasher = DefaultHasher::new();
+        std::thread::current().id().hash(&mut hasher);
+        &self.shards[hasher.finish() as usize % self.shards.len()]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Closed, Open, EMPTY};
+
+    #[test]
+    fn test_builder_1() {
+        let builder = IntervalSetBuilder::with_shards(0);
+        assert_eq!(builder.shards.len(), 1);
+        builder.insert(EMPTY);
+        builder.extend([EMPTY]);
+        assert!(builder.finish().is_empty());
+    }
+
+    #[test]
+    fn test_builder_2() {
+        // Enough insertions for shards to merge their buffers several times
+        let builder = IntervalSetBuilder::with_shards(3);
+        std::thread::scope(|scope| {
+            for t in 0..6 {
+                let builder = &builder;
+                scope.spawn(move || {
+                    for i in 0..2000 {
+                        let k = (6 * i + t) as f64;
+                        builder.insert(Interval::new(Closed(k), Open(k + 0.5)));
+                    }
+                    builder.extend([Interval::new(Closed(-1.), Open(t as f64))]);
+                });
+            }
+        });
+        let res = builder.finish();
+        assert!(res.is_valid());
+        // [-1, 5) swallows the first five segments and is continued by the sixth one
+        assert_eq!(res.as_slice().len(), 11995);
+        assert_eq!(res.as_slice()[0], Interval::new(Closed(-1.), Open(5.5)));
+        assert_eq!(res.measure(), 6.5 + 0.5 * 11994.);
+    }
+}
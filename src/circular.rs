@@ -0,0 +1,476 @@
+use super::{Closed, Interval, IntervalError, IntervalSet, Open};
+use std::fmt::Display;
+
+/// Closed arc of a periodic domain `[0, period)`, such as angles
+///
+/// An arc runs counterclockwise from its start to its end and may wrap around `0`, like
+/// `[350°, 10°]`. Positions are normalized into `[0, period)`.
+///
+/// # Example
+///
+/// ```
+/// use interval::CircularInterval;
+///
+/// let north = CircularInterval::new(350., 10., 360.).unwrap();
+///
+/// assert!(north.contains(355.));
+/// assert!(north.contains(5.));
+/// assert!(north.contains(-5.));
+/// assert!(!north.contains(180.));
+/// assert_eq!(north.length(), 20.);
+/// ```
+///
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CircularInterval {
+    period: f64,
+    arc: Arc,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Arc {
+    Empty,
+    Full,
+    /// `start` lies in `[0, period)` and `0 <= len < period`
+    Span {
+        start: f64,
+        len: f64,
+    },
+}
+
+impl Display for CircularInterval {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.arc {
+            Arc::Empty => write!(f, "∅"),
+            Arc::Full => write!(f, "[0,{:5.2})", self.period),
+            Arc::Span { start, len } => {
+                write!(f, "[{start:5.2},{:5.2}]", self.normalize(start + len))
+            }
+        }
+    }
+}
+
+impl CircularInterval {
+    /// Build arc running counterclockwise from `start` to `end`
+    ///
+    /// The arc covers the whole domain when `end - start >= period`. Fails unless `period`
+    /// is positive and finite, and `start` and `end` are finite.
+    ///
+    pub fn new(start: f64, end: f64, period: f64) -> Result<Self, IntervalError> {
+        let circle = CircularInterval::empty(period)?;
+        if start.is_nan() || end.is_nan() {
+            return Err(IntervalError::NanEndpoint);
+        }
+        if start.is_infinite() || end.is_infinite() {
+            return Err(IntervalError::Unbounded);
+        }
+        // rem_euclid may round up to period itself
+        let len = (end - start).rem_euclid(period);
+        if end - start >= period || len >= period {
+            Ok(circle.with_arc(Arc::Full))
+        } else {
+            Ok(circle.span(start, len))
+        }
+    }
+
+    /// Empty arc of domain `[0, period)`
+    ///
+    /// Fails unless `period` is positive and finite.
+    ///
+    pub fn empty(period: f64) -> Result<Self, IntervalError> {
+        CircularInterval::checked_period(period)?;
+        Ok(CircularInterval {
+            period,
+            arc: Arc::Empty,
+        })
+    }
+
+    /// Whole domain `[0, period)`
+    ///
+    /// Fails unless `period` is positive and finite.
+    ///
+    pub fn full(period: f64) -> Result<Self, IntervalError> {
+        CircularInterval::checked_period(period)?;
+        Ok(CircularInterval {
+            period,
+            arc: Arc::Full,
+        })
+    }
+
+    pub fn period(&self) -> f64 {
+        self.period
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.arc == Arc::Empty
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.arc == Arc::Full
+    }
+
+    /// Normalized start and end of arc, `None` if empty or full
+    ///
+    pub fn endpoints(&self) -> Option<(f64, f64)> {
+        match self.arc {
+            Arc::Span { start, len } => Some((start, self.normalize(start + len))),
+            _ => None,
+        }
+    }
+
+    /// Length of arc
+    ///
+    pub fn length(&self) -> f64 {
+        match self.arc {
+            Arc::Empty => 0.,
+            Arc::Full => self.period,
+            Arc::Span { len, .. } => len,
+        }
+    }
+
+    /// Position `x` brought back into `[0, period)`
+    ///
+    pub fn normalize(&self, x: f64) -> f64 {
+        let x = x.rem_euclid(self.period);
+        // rem_euclid may round up to period itself
+        if x == self.period {
+            0.
+        } else {
+            x
+        }
+    }
+
+    /// Check if position `x` (normalized first) belongs to arc
+    ///
+    pub fn contains(&self, x: f64) -> bool {
+        match self.arc {
+            Arc::Empty => false,
+            Arc::Full => x.is_finite(),
+            Arc::Span { start, len } => self.normalize(x - start) <= len,
+        }
+    }
+
+    /// Compute union of arcs
+    ///
+    /// Returns a single arc when arcs overlap, both arcs otherwise (in the same way as
+    /// `Interval::union`). Arcs of different periods are not merged.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::CircularInterval;
+    ///
+    /// let a = CircularInterval::new(300., 350., 360.).unwrap();
+    /// let b = CircularInterval::new(340., 20., 360.).unwrap();
+    ///
+    /// assert_eq!(a.union(b), (CircularInterval::new(300., 20., 360.).unwrap(), None));
+    /// ```
+    ///
+    pub fn union(self, other: CircularInterval) -> (CircularInterval, Option<CircularInterval>) {
+        if self.period != other.period {
+            return (self, Some(other));
+        }
+        match (self.arc, other.arc) {
+            (Arc::Empty, _) | (_, Arc::Full) => (other, None),
+            (_, Arc::Empty) | (Arc::Full, _) => (self, None),
+            (Arc::Span { start: s1, len: l1 }, Arc::Span { start: s2, len: l2 }) => {
+                let (d12, d21) = (self.normalize(s2 - s1), self.normalize(s1 - s2));
+                let merged = if d12 <= l1 {
+                    Some((s1, l1.max(d12 + l2)))
+                } else if d21 <= l2 {
+                    Some((s2, l2.max(d21 + l1)))
+                } else {
+                    None
+                };
+                match merged {
+                    Some(_) if d12 <= l1 && d21 <= l2 => (self.with_arc(Arc::Full), None),
+                    Some((_, len)) if len >= self.period => (self.with_arc(Arc::Full), None),
+                    Some((start, len)) => (self.span(start, len), None),
+                    None => (self, Some(other)),
+                }
+            }
+        }
+    }
+
+    /// Compute intersection of arcs
+    ///
+    /// Two arcs may intersect in two disjoint pieces, the second one being returned apart
+    /// (in the same way as `union`). Arcs of different periods don't intersect.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::CircularInterval;
+    ///
+    /// let a = CircularInterval::new(0., 270., 360.).unwrap();
+    /// let b = CircularInterval::new(180., 90., 360.).unwrap();
+    ///
+    /// assert_eq!(a.intersection(b), (
+    ///     CircularInterval::new(0., 90., 360.).unwrap(),
+    ///     Some(CircularInterval::new(180., 270., 360.).unwrap()),
+    /// ));
+    /// ```
+    ///
+    pub fn intersection(
+        self,
+        other: CircularInterval,
+    ) -> (CircularInterval, Option<CircularInterval>) {
+        if self.period != other.period {
+            return (self.with_arc(Arc::Empty), None);
+        }
+        match (self.arc, other.arc) {
+            (Arc::Empty, _) | (_, Arc::Full) => (self, None),
+            (_, Arc::Empty) | (Arc::Full, _) => (other, None),
+            (Arc::Span { start: s1, len: l1 }, Arc::Span { start: s2, len: l2 }) => {
+                // unroll other arc around self, starting at 0
+                let d = self.normalize(s2 - s1);
+                let pieces: Vec<_> = [d - self.period, d]
+                    .into_iter()
+                    .filter_map(|a| {
+                        let (lower, upper) = (a.max(0.), (a + l2).min(l1));
+                        (lower <= upper).then(|| self.span(s1 + lower, upper - lower))
+                    })
+                    .collect();
+                match pieces[..] {
+                    [] => (self.with_arc(Arc::Empty), None),
+                    [a] => (a, None),
+                    [a, b] => (a, Some(b)),
+                    _ => unreachable!(),
+                }
+            }
+        }
+    }
+
+    /// Arc as a subset of `[0, period)`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{CircularInterval, Interval, IntervalSet, Closed, Open};
+    ///
+    /// let north = CircularInterval::new(350., 10., 360.).unwrap();
+    ///
+    /// assert_eq!(north.to_interval_set(), IntervalSet::from(&[
+    ///     Interval::new(Closed(0.), Closed(10.)),
+    ///     Interval::new(Closed(350.), Open(360.)),
+    /// ]));
+    /// ```
+    ///
+    pub fn to_interval_set(&self) -> IntervalSet {
+        match self.arc {
+            Arc::Empty => IntervalSet::new(),
+            Arc::Full => IntervalSet::from(&[Interval::new(Closed(0.), Open(self.period))]),
+            Arc::Span { start, len } if start + len < self.period => {
+                IntervalSet::from(&[Interval::new(Closed(start), Closed(start + len))])
+            }
+            Arc::Span { start, len } => IntervalSet::from(&[
+                Interval::new(Closed(0.), Closed(start + len - self.period)),
+                Interval::new(Closed(start), Open(self.period)),
+            ]),
+        }
+    }
+
+    fn checked_period(period: f64) -> Result<(), IntervalError> {
+        if period.is_finite() && period > 0. {
+            Ok(())
+        } else {
+            Err(IntervalError::InvalidPeriod)
+        }
+    }
+
+    /// Arc of the same period
+    ///
+    fn with_arc(&self, arc: Arc) -> CircularInterval {
+        CircularInterval {
+            period: self.period,
+            arc,
+        }
+    }
+
+    fn span(&self, start: f64, len: f64) -> CircularInterval {
+        CircularInterval {
+            period: self.period,
+            arc: Arc::Span {
+                start: self.normalize(start),
+                len,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_new_1() {
+        assert_eq!(
+            CircularInterval::new(0., 1., 0.),
+            Err(IntervalError::InvalidPeriod)
+        );
+        assert_eq!(
+            CircularInterval::new(0., f64::NAN, 360.),
+            Err(IntervalError::NanEndpoint)
+        );
+        assert_eq!(
+            CircularInterval::new(0., f64::INFINITY, 360.),
+            Err(IntervalError::Unbounded)
+        );
+        for period in [0., -1., f64::NAN, f64::INFINITY] {
+            assert_eq!(
+                CircularInterval::empty(period),
+                Err(IntervalError::InvalidPeriod)
+            );
+            assert_eq!(
+                CircularInterval::full(period),
+                Err(IntervalError::InvalidPeriod)
+            );
+        }
+        assert!(CircularInterval::new(0., 360., 360.).unwrap().is_full());
+        assert!(CircularInterval::new(-10., 400., 360.).unwrap().is_full());
+        assert_eq!(
+            CircularInterval::new(370., 380., 360.).unwrap(),
+            CircularInterval::new(10., 20., 360.).unwrap()
+        );
+        assert_eq!(
+            CircularInterval::new(-10., 10., 360.).unwrap().endpoints(),
+            Some((350., 10.))
+        );
+        assert_eq!(CircularInterval::new(10., 10., 360.).unwrap().length(), 0.);
+    }
+
+    #[test]
+    fn test_new_2() {
+        let a = CircularInterval::new(-1e-20, 10., 360.).unwrap();
+        assert_eq!(a.endpoints(), Some((0., 10.)));
+        assert_eq!(a.to_string(), "[ 0.00,10.00]");
+
+        let b = CircularInterval::new(1e-20, 0., 360.).unwrap();
+        assert!(b.is_full());
+        assert_eq!(
+            b.to_interval_set(),
+            IntervalSet::from(&[Interval::new(Closed(0.), Open(360.))])
+        );
+    }
+
+    #[test]
+    fn test_contains_1() {
+        let a = CircularInterval::new(350., 10., 360.).unwrap();
+        assert!(a.contains(350.));
+        assert!(a.contains(10.));
+        assert!(a.contains(0.));
+        assert!(a.contains(720.));
+        assert!(!a.contains(10.5));
+        assert!(!a.contains(f64::NAN));
+        assert!(!CircularInterval::empty(360.).unwrap().contains(0.));
+        assert!(CircularInterval::full(360.).unwrap().contains(123.));
+    }
+
+    #[test]
+    fn test_union_1() {
+        let (a, b) = (
+            CircularInterval::new(350., 10., 360.).unwrap(),
+            CircularInterval::new(90., 180., 360.).unwrap(),
+        );
+        assert_eq!(a.union(b), (a, Some(b)));
+        assert_eq!(
+            a.union(CircularInterval::new(10., 90., 360.).unwrap()),
+            (CircularInterval::new(350., 90., 360.).unwrap(), None)
+        );
+        assert_eq!(
+            CircularInterval::new(10., 90., 360.).unwrap().union(a),
+            (CircularInterval::new(350., 90., 360.).unwrap(), None)
+        );
+        assert_eq!(
+            a.union(CircularInterval::new(0., 5., 360.).unwrap()),
+            (a, None)
+        );
+        assert_eq!(a.union(CircularInterval::empty(360.).unwrap()), (a, None));
+    }
+
+    #[test]
+    fn test_union_2() {
+        let (a, b) = (
+            CircularInterval::new(0., 200., 360.).unwrap(),
+            CircularInterval::new(180., 10., 360.).unwrap(),
+        );
+        assert_eq!(a.union(b), (CircularInterval::full(360.).unwrap(), None));
+        let (a, b) = (
+            CircularInterval::new(0., 180., 360.).unwrap(),
+            CircularInterval::new(180., 359., 360.).unwrap(),
+        );
+        assert_eq!(
+            a.union(b),
+            (CircularInterval::new(0., 359., 360.).unwrap(), None)
+        );
+    }
+
+    #[test]
+    fn test_intersection_1() {
+        let a = CircularInterval::new(350., 10., 360.).unwrap();
+        assert_eq!(
+            a.intersection(CircularInterval::new(0., 90., 360.).unwrap()),
+            (CircularInterval::new(0., 10., 360.).unwrap(), None)
+        );
+        assert_eq!(
+            a.intersection(CircularInterval::new(340., 355., 360.).unwrap()),
+            (CircularInterval::new(350., 355., 360.).unwrap(), None)
+        );
+        assert!(a
+            .intersection(CircularInterval::new(90., 180., 360.).unwrap())
+            .0
+            .is_empty());
+        assert_eq!(
+            a.intersection(CircularInterval::full(360.).unwrap()),
+            (a, None)
+        );
+        assert_eq!(
+            a.intersection(CircularInterval::new(10., 20., 360.).unwrap()),
+            (CircularInterval::new(10., 10., 360.).unwrap(), None)
+        );
+    }
+
+    #[test]
+    fn test_intersection_2() {
+        let (a, b) = (
+            CircularInterval::new(0., 270., 360.).unwrap(),
+            CircularInterval::new(180., 90., 360.).unwrap(),
+        );
+        assert_eq!(
+            a.intersection(b),
+            (
+                CircularInterval::new(0., 90., 360.).unwrap(),
+                Some(CircularInterval::new(180., 270., 360.).unwrap())
+            )
+        );
+        assert_eq!(
+            b.intersection(a),
+            (
+                CircularInterval::new(180., 270., 360.).unwrap(),
+                Some(CircularInterval::new(0., 90., 360.).unwrap())
+            )
+        );
+    }
+
+    #[test]
+    fn test_to_interval_set_1() {
+        assert!(CircularInterval::empty(360.)
+            .unwrap()
+            .to_interval_set()
+            .is_empty());
+        assert_eq!(
+            CircularInterval::new(10., 20., 360.)
+                .unwrap()
+                .to_interval_set(),
+            IntervalSet::from(&[Interval::new(Closed(10.), Closed(20.))])
+        );
+    }
+
+    #[test]
+    fn test_display_1() {
+        assert_eq!(
+            format!("{}", CircularInterval::new(350., 10., 360.).unwrap()),
+            "[350.00,10.00]"
+        );
+        assert_eq!(format!("{}", CircularInterval::empty(360.).unwrap()), "∅");
+    }
+}
@@ -0,0 +1,301 @@
+//! Algorithms over collections of intervals
+//!
+//! Endpoints are compared exactly, so that `[0,1)` and `[1,2]` do not overlap while `[0,1]`
+//! and `[1,2]` do.
+
+use std::collections::BTreeSet;
+
+use super::interval::Edge;
+use super::{Interval, EMPTY};
+
+/// Select a maximum set of pairwise disjoint intervals
+///
+/// Greedy activity selection: intervals are scanned by increasing right endpoint and kept
+/// when they do not overlap the previously kept one. Empty intervals are never selected.
+/// Returns indices of selected intervals, by increasing right endpoint.
+///
+/// # Example
+///
+/// ```
+/// use interval::{select_max_non_overlapping, Interval, Closed, Open};
+///
+/// let meetings = [
+///     Interval::new(Closed(9.), Open(10.)),
+///     Interval::new(Closed(9.5), Open(11.)),
+///     Interval::new(Closed(10.), Open(12.)),
+///     Interval::new(Closed(12.), Open(13.)),
+/// ];
+///
+/// assert_eq!(select_max_non_overlapping(&meetings), vec![0, 2, 3]);
+/// ```
+///
+pub fn select_max_non_overlapping(intervals: &[Interval]) -> Vec<usize> {
+    let mut candidates: Vec<(usize, Edge, Edge)> = intervals
+        .iter()
+        .enumerate()
+        .filter_map(|(i, a)| a.edges().map(|(start, end)| (i, start, end)))
+        .collect();
+    candidates.sort_by_key(|&(i, _, end)| (end, i));
+
+    let mut selected = Vec::new();
+    let mut last: Option<Edge> = None;
+    for (i, start, end) in candidates {
+        // None sorts before any edge
+        if last <= Some(start) {
+            selected.push(i);
+            last = Some(end);
+        }
+    }
+    selected
+}
+
+/// Maximum number of intervals sharing a common point, with the first interval where
+/// this depth is reached
+///
+/// Computed by sweeping sorted endpoints. Returns `(0, EMPTY)` when no interval is
+/// non-empty.
+///
+/// # Example
+///
+/// ```
+/// use interval::{max_overlap, Interval, Closed, Open};
+///
+/// let calls = [
+///     Interval::new(Closed(0.), Open(10.)),
+///     Interval::new(Closed(5.), Open(15.)),
+///     Interval::new(Closed(8.), Open(9.)),
+///     Interval::new(Closed(12.), Open(20.)),
+/// ];
+///
+/// assert_eq!(max_overlap(&calls), (3, Interval::new(Closed(8.), Open(9.))));
+/// ```
+///
+pub fn max_overlap(intervals: &[Interval]) -> (usize, Interval) {
+    let mut events: Vec<(Edge, isize)> = Vec::new();
+    for (start, end) in intervals.iter().filter_map(|a| a.edges()) {
+        events.push((start, 1));
+        events.push((end, -1));
+    }
+    events.sort_by_key(|event| event.0);
+
+    let (mut depth, mut max) = (0, 0);
+    let mut peak = EMPTY;
+    let mut i = 0;
+    while i < events.len() {
+        let edge = events[i].0;
+        while i < events.len() && events[i].0 == edge {
+            depth += events[i].1;
+            i += 1;
+        }
+        if depth > max {
+            if let Some(&(next, _)) = events.get(i) {
+                max = depth;
+                peak = Interval::from_edges(edge, next);
+            }
+        }
+    }
+    (max as usize, peak)
+}
+
+/// Pairs of indices `(i, j)`, `i < j`, of intersecting intervals
+///
+/// Computed by sweeping sorted endpoints, in `O(n log n + k)` for `k` pairs. Pairs are
+/// returned in increasing order.
+///
+/// # Example
+///
+/// ```
+/// use interval::{overlap_graph, Interval, Closed, Open};
+///
+/// let a = [
+///     Interval::new(Closed(0.), Open(10.)),
+///     Interval::new(Closed(10.), Open(20.)),
+///     Interval::new(Closed(5.), Closed(15.)),
+/// ];
+///
+/// assert_eq!(overlap_graph(&a), vec![(0, 2), (1, 2)]);
+/// ```
+///
+pub fn overlap_graph(intervals: &[Interval]) -> Vec<(usize, usize)> {
+    let mut pairs = Vec::new();
+    let mut active: BTreeSet<usize> = BTreeSet::new();
+    for (_, is_start, _, i) in sweep(&[intervals]) {
+        if is_start {
+            pairs.extend(active.iter().map(|&j| (j.min(i), j.max(i))));
+            active.insert(i);
+        } else {
+            active.remove(&i);
+        }
+    }
+    pairs.sort_unstable();
+    pairs
+}
+
+/// Every overlapping pair `(i, j, a[i] ∩ b[j])` between two collections
+///
+/// Sort-merge join on sorted endpoints, in `O(n log n + k)` for `k` pairs. Triples are
+/// returned in increasing order of `(i, j)`.
+///
+/// # Example
+///
+/// ```
+/// use interval::{interval_join, Interval, Closed, Open};
+///
+/// let a = [
+///     Interval::new(Closed(0.), Open(10.)),
+///     Interval::new(Closed(20.), Open(30.)),
+/// ];
+/// let b = [Interval::new(Closed(5.), Open(25.))];
+///
+/// assert_eq!(interval_join(&a, &b), vec![
+///     (0, 0, Interval::new(Closed(5.), Open(10.))),
+///     (1, 0, Interval::new(Closed(20.), Open(25.))),
+/// ]);
+/// ```
+///
+pub fn interval_join(a: &[Interval], b: &[Interval]) -> Vec<(usize, usize, Interval)> {
+    let mut res = Vec::new();
+    let mut active: [BTreeSet<usize>; 2] = [BTreeSet::new(), BTreeSet::new()];
+    for (_, is_start, c, k) in sweep(&[a, b]) {
+        if is_start {
+            for &other in active[1 - c].iter() {
+                let (i, j) = if c == 0 { (k, other) } else { (other, k) };
+                res.push((i, j, a[i].intersection(b[j])));
+            }
+            active[c].insert(k);
+        } else {
+            active[c].remove(&k);
+        }
+    }
+    res.sort_unstable_by_key(|&(i, j, _)| (i, j));
+    res
+}
+
+/// Endpoint events `(edge, is_start, collection, index)` of intervals, sorted by edge
+///
+/// At a common edge, ends come before starts: intervals `[start, end)` in edge space meeting
+/// there do not overlap.
+///
+fn sweep(collections: &[&[Interval]]) -> Vec<(Edge, bool, usize, usize)> {
+    let mut events = Vec::new();
+    for (c, intervals) in collections.iter().enumerate() {
+        for (i, (start, end)) in intervals
+            .iter()
+            .enumerate()
+            .filter_map(|(i, a)| a.edges().map(|edges| (i, edges)))
+        {
+            events.push((start, true, c, i));
+            events.push((end, false, c, i));
+        }
+    }
+    events.sort_by_key(|&(edge, is_start, _, _)| (edge, is_start));
+    events
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Closed, Open, Unbound, INFINITY};
+
+    #[test]
+    fn test_select_max_non_overlapping_1() {
+        let a = [
+            Interval::new(Closed(0.), Closed(1.)),
+            Interval::new(Closed(1.), Closed(2.)),
+            Interval::new(Open(1.), Closed(2.)),
+        ];
+        assert_eq!(select_max_non_overlapping(&a), vec![0, 2]);
+    }
+
+    #[test]
+    fn test_select_max_non_overlapping_2() {
+        let a = [
+            Interval::new(Closed(0.), Closed(10.)),
+            EMPTY,
+            Interval::new(Closed(1.), Open(2.)),
+            Interval::new(Closed(2.), Open(3.)),
+            Interval::new(Closed(3.), Unbound),
+        ];
+        assert_eq!(select_max_non_overlapping(&a), vec![2, 3, 4]);
+        assert!(select_max_non_overlapping(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_max_overlap_1() {
+        let a = [
+            Interval::new(Closed(0.), Closed(1.)),
+            Interval::new(Closed(1.), Closed(2.)),
+            Interval::new(Closed(2.), Closed(3.)),
+        ];
+        assert_eq!(max_overlap(&a), (2, Interval::singleton(1.)));
+    }
+
+    #[test]
+    fn test_max_overlap_2() {
+        let a = [
+            Interval::new(Closed(0.), Open(1.)),
+            Interval::new(Closed(1.), Open(2.)),
+            EMPTY,
+        ];
+        assert_eq!(max_overlap(&a), (1, Interval::new(Closed(0.), Open(1.))));
+        assert_eq!(max_overlap(&[]), (0, EMPTY));
+        assert_eq!(max_overlap(&[EMPTY]), (0, EMPTY));
+    }
+
+    #[test]
+    fn test_max_overlap_3() {
+        let a = [
+            Interval::new(Unbound, Closed(5.)),
+            Interval::new(Open(3.), Unbound),
+        ];
+        assert_eq!(max_overlap(&a), (2, Interval::new(Open(3.), Closed(5.))));
+    }
+
+    #[test]
+    fn test_overlap_graph_1() {
+        let a = [
+            Interval::new(Closed(0.), Closed(1.)),
+            Interval::new(Closed(1.), Closed(2.)),
+            Interval::new(Open(2.), Closed(3.)),
+            EMPTY,
+            INFINITY,
+        ];
+        assert_eq!(overlap_graph(&a), vec![(0, 1), (0, 4), (1, 4), (2, 4)]);
+        assert!(overlap_graph(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_overlap_graph_2() {
+        let a = [Interval::singleton(1.), Interval::singleton(1.)];
+        assert_eq!(overlap_graph(&a), vec![(0, 1)]);
+    }
+
+    #[test]
+    fn test_interval_join_1() {
+        let a = [
+            Interval::new(Closed(0.), Open(1.)),
+            Interval::new(Closed(0.), Closed(1.)),
+        ];
+        let b = [
+            Interval::new(Closed(1.), Closed(2.)),
+            Interval::new(Unbound, Closed(0.)),
+            EMPTY,
+        ];
+        assert_eq!(
+            interval_join(&a, &b),
+            vec![
+                (0, 1, Interval::singleton(0.)),
+                (1, 0, Interval::singleton(1.)),
+                (1, 1, Interval::singleton(0.)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_interval_join_2() {
+        let a = [INFINITY];
+        assert!(interval_join(&a, &[]).is_empty());
+        assert!(interval_join(&[], &a).is_empty());
+        assert_eq!(interval_join(&a, &a), vec![(0, 0, INFINITY)]);
+    }
+}
@@ -0,0 +1,415 @@
+use super::arith::{add, div, mul, neg, sqr, sqr_inverse, sub};
+use super::{Interval, EMPTY};
+use auto_ops::{impl_op_ex, impl_op_ex_commutative};
+
+/// Arithmetic expression over variables indexed in a domain slice
+///
+/// Expressions are built with `Expr::var`, `Expr::constant` and the usual operators.
+///
+/// # Example
+///
+/// ```
+/// use interval::{Expr, Interval, Closed};
+///
+/// let (x, y) = (Expr::var(0), Expr::var(1));
+/// let e = x * 2. + y.sqr();
+/// let domains = [
+///     Interval::new(Closed(1.), Closed(2.)),
+///     Interval::new(Closed(-3.), Closed(1.)),
+/// ];
+///
+/// assert_eq!(e.eval(&domains), Interval::new(Closed(2.), Closed(13.)));
+/// ```
+///
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expr {
+    Var(usize),
+    Const(f64),
+    Add(Box<Expr>, Box<Expr>),
+    Sub(Box<Expr>, Box<Expr>),
+    Mul(Box<Expr>, Box<Expr>),
+    Div(Box<Expr>, Box<Expr>),
+    Neg(Box<Expr>),
+    Sqr(Box<Expr>),
+}
+
+/// Value of an expression node computed by the forward pass, with the indices of its
+/// children nodes
+///
+struct Node {
+    value: Interval,
+    children: (usize, usize),
+}
+
+impl Expr {
+    pub fn var(index: usize) -> Self {
+        Expr::Var(index)
+    }
+
+    pub fn constant(k: f64) -> Self {
+        Expr::Const(k)
+    }
+
+    pub fn sqr(self) -> Self {
+        Expr::Sqr(Box::new(self))
+    }
+
+    /// Evaluate expression over variable domains (natural interval extension)
+    ///
+    /// # Panics
+    ///
+    /// Panics if a variable index is out of `domains` bounds.
+    ///
+    pub fn eval(&self, domains: &[Interval]) -> Interval {
+        let mut nodes = Vec::new();
+        let root = self.forward(domains, &mut nodes);
+        nodes[root].value
+    }
+
+    /// Evaluate every node bottom-up, returning index of the node of `self`
+    ///
+    fn forward(&self, domains: &[Interval], nodes: &mut Vec<Node>) -> usize {
+        let (value, children) = match self {
+            Expr::Var(i) => (domains[*i], (0, 0)),
+            Expr::Const(k) => (Interval::singleton(*k), (0, 0)),
+            Expr::Neg(a) | Expr::Sqr(a) => {
+                let i = a.forward(domains, nodes);
+                let value = match self {
+                    Expr::Neg(_) => neg(nodes[i].value),
+                    _ => sqr(nodes[i].value),
+                };
+                (value, (i, 0))
+            }
+            Expr::Add(a, b) | Expr::Sub(a, b) | Expr::Mul(a, b) | Expr::Div(a, b) => {
+                let (i, j) = (a.forward(domains, nodes), b.forward(domains, nodes));
+                let (va, vb) = (nodes[i].value, nodes[j].value);
+                let value = match self {
+                    Expr::Add(..) => add(va, vb),
+                    Expr::Sub(..) => sub(va, vb),
+                    Expr::Mul(..) => mul(va, vb),
+                    _ => div(va, vb),
+                };
+                (value, (i, j))
+            }
+        };
+        nodes.push(Node { value, children });
+        nodes.len() - 1
+    }
+
+    /// Project `target` top-down onto the variable domains
+    ///
+    /// Returns `false` as soon as a node is proven to have no consistent value.
+    ///
+    fn backward(
+        &self,
+        node: usize,
+        target: Interval,
+        nodes: &[Node],
+        domains: &mut [Interval],
+    ) -> bool {
+        let value = nodes[node].value.intersection(target);
+        if value.is_empty() {
+            return false;
+        }
+        let (i, j) = nodes[node].children;
+        match self {
+            Expr::Var(k) => {
+                domains[*k] = domains[*k].intersection(value);
+                !domains[*k].is_empty()
+            }
+            Expr::Const(_) => true,
+            Expr::Neg(a) => a.backward(i, neg(value), nodes, domains),
+            Expr::Sqr(a) => a.backward(i, sqr_inverse(value, nodes[i].value), nodes, domains),
+            Expr::Add(a, b) => {
+                let (va, vb) = (nodes[i].value, nodes[j].value);
+                a.backward(i, sub(value, vb), nodes, domains)
+                    && b.backward(j, sub(value, va), nodes, domains)
+            }
+            Expr::Sub(a, b) => {
+                let (va, vb) = (nodes[i].value, nodes[j].value);
+                a.backward(i, add(value, vb), nodes, domains)
+                    && b.backward(j, sub(va, value), nodes, domains)
+            }
+            Expr::Mul(a, b) => {
+                let (va, vb) = (nodes[i].value, nodes[j].value);
+                a.backward(i, div(value, vb), nodes, domains)
+                    && b.backward(j, div(value, va), nodes, domains)
+            }
+            Expr::Div(a, b) => {
+                let (va, vb) = (nodes[i].value, nodes[j].value);
+                a.backward(i, mul(value, vb), nodes, domains)
+                    && b.backward(j, div(va, value), nodes, domains)
+            }
+        }
+    }
+}
+
+impl_op_ex!(+ |a: &Expr, b: &Expr| -> Expr { Expr::Add(Box::new(a.clone()), Box::new(b.clone())) });
+impl_op_ex!(-|a: &Expr, b: &Expr| -> Expr { Expr::Sub(Box::new(a.clone()), Box::new(b.clone())) });
+impl_op_ex!(*|a: &Expr, b: &Expr| -> Expr { Expr::Mul(Box::new(a.clone()), Box::new(b.clone())) });
+impl_op_ex!(/|a: &Expr, b: &Expr| -> Expr { Expr::Div(Box::new(a.clone()), Box::new(b.clone())) });
+impl_op_ex!(-|a: &Expr| -> Expr { Expr::Neg(Box::new(a.clone())) });
+
+impl_op_ex_commutative!(+ |a: &Expr, k: f64| -> Expr { a + Expr::Const(k) });
+impl_op_ex_commutative!(*|a: &Expr, k: f64| -> Expr { a * Expr::Const(k) });
+impl_op_ex!(-|a: &Expr, k: f64| -> Expr { a - Expr::Const(k) });
+impl_op_ex!(/|a: &Expr, k: f64| -> Expr { a / Expr::Const(k) });
+
+/// Constraint `expr ∈ range`
+///
+/// # Example
+///
+/// ```
+/// use interval::{Constraint, Expr, Interval, Closed};
+///
+/// // x + y = z
+/// let (x, y, z) = (Expr::var(0), Expr::var(1), Expr::var(2));
+/// let c = Constraint::equal(x + y, z);
+///
+/// let mut domains = [
+///     Interval::new(Closed(0.), Closed(10.)),
+///     Interval::new(Closed(0.), Closed(10.)),
+///     Interval::new(Closed(15.), Closed(30.)),
+/// ];
+///
+/// assert!(c.contract(&mut domains));
+/// assert_eq!(domains[0], Interval::new(Closed(5.), Closed(10.)));
+/// assert_eq!(domains[2], Interval::new(Closed(15.), Closed(20.)));
+/// ```
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct Constraint {
+    expr: Expr,
+    range: Interval,
+}
+
+const MAX_ROUNDS: usize = 100;
+
+impl Constraint {
+    pub fn new(expr: Expr, range: Interval) -> Self {
+        Constraint { expr, range }
+    }
+
+    /// Constraint `lhs = rhs`
+    ///
+    pub fn equal(lhs: Expr, rhs: Expr) -> Self {
+        Constraint::new(lhs - rhs, Interval::singleton(0.))
+    }
+
+    /// Contract variable domains with one forward-backward (HC4-revise) pass
+    ///
+    /// Returns `false` if the constraint is proven unsatisfiable, every domain being
+    /// then set to `EMPTY`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a variable index is out of `domains` bounds.
+    ///
+    pub fn contract(&self, domains: &mut [Interval]) -> bool {
+        let mut nodes = Vec::new();
+        let root = self.expr.forward(domains, &mut nodes);
+        if self.expr.backward(root, self.range, &nodes, domains) {
+            true
+        } else {
+            domains.fill(EMPTY);
+            false
+        }
+    }
+}
+
+/// Contract variable domains with every constraint until a fixpoint is reached
+///
+/// Propagation stops when a whole round leaves domains unchanged, or after a fixed number
+/// of rounds for slowly converging systems. Returns `false` if the system is proven
+/// unsatisfiable, every domain being then set to `EMPTY`.
+///
+/// # Example
+///
+/// ```
+/// use interval::{propagate, Constraint, Expr, Interval, Closed};
+///
+/// // x * y = 8, x = 2 * y
+/// let (x, y) = (Expr::var(0), Expr::var(1));
+/// let constraints = [
+///     Constraint::equal(&x * &y, Expr::constant(8.)),
+///     Constraint::equal(x, y * 2.),
+/// ];
+/// let mut domains = [
+///     Interval::new(Closed(0.), Closed(10.)),
+///     Interval::new(Closed(0.), Closed(10.)),
+/// ];
+///
+/// assert!(propagate(&constraints, &mut domains));
+/// assert!(domains[0].contains(4.) && domains[1].contains(2.));
+/// ```
+///
+pub fn propagate(constraints: &[Constraint], domains: &mut [Interval]) -> bool {
+    for _ in 0..MAX_ROUNDS {
+        let previous = domains.to_vec();
+        for constraint in constraints {
+            if !constraint.contract(domains) {
+                return false;
+            }
+        }
+        if previous == domains {
+            break;
+        }
+    }
+    true
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Closed, Unbound, INFINITY};
+
+    #[test]
+    fn test_eval_1() {
+        let (x, y) = (Expr::var(0), Expr::var(1));
+        let domains = [
+            Interval::new(Closed(1.), Closed(2.)),
+            Interval::new(Closed(-1.), Closed(3.)),
+        ];
+        assert_eq!(
+            (&x - &y).eval(&domains),
+            Interval::new(Closed(-2.), Closed(3.))
+        );
+        assert_eq!(
+            (&x * &y).eval(&domains),
+            Interval::new(Closed(-2.), Closed(6.))
+        );
+        assert_eq!(
+            (-&x / 2.).eval(&domains),
+            Interval::new(Closed(-1.), Closed(-0.5))
+        );
+        assert_eq!(
+            (y / x).eval(&domains),
+            Interval::new(Closed(-1.), Closed(3.))
+        );
+    }
+
+    #[test]
+    fn test_contract_add_1() {
+        let (x, y, z) = (Expr::var(0), Expr::var(1), Expr::var(2));
+        let c = Constraint::equal(x + y, z);
+        let mut domains = [
+            Interval::new(Closed(0.), Closed(10.)),
+            Interval::new(Closed(0.), Closed(10.)),
+            Interval::new(Closed(15.), Closed(30.)),
+        ];
+        assert!(c.contract(&mut domains));
+        assert_eq!(
+            domains,
+            [
+                Interval::new(Closed(5.), Closed(10.)),
+                Interval::new(Closed(5.), Closed(10.)),
+                Interval::new(Closed(15.), Closed(20.))
+            ]
+        );
+    }
+
+    #[test]
+    fn test_contract_mul_1() {
+        let (x, y, z) = (Expr::var(0), Expr::var(1), Expr::var(2));
+        let c = Constraint::equal(x * y, z);
+        let mut domains = [
+            Interval::new(Closed(1.), Closed(10.)),
+            Interval::new(Closed(2.), Closed(3.)),
+            Interval::new(Closed(-5.), Closed(4.)),
+        ];
+        assert!(c.contract(&mut domains));
+        assert_eq!(
+            domains,
+            [
+                Interval::new(Closed(1.), Closed(2.)),
+                Interval::new(Closed(2.), Closed(3.)),
+                Interval::new(Closed(2.), Closed(4.))
+            ]
+        );
+    }
+
+    #[test]
+    fn test_contract_sqr_1() {
+        let x = Expr::var(0);
+        let c = Constraint::new(x.sqr(), Interval::new(Closed(4.), Closed(9.)));
+        let mut domains = [Interval::new(Closed(-10.), Closed(1.))];
+        assert!(c.contract(&mut domains));
+        assert_eq!(domains, [Interval::new(Closed(-3.), Closed(-2.))]);
+    }
+
+    #[test]
+    fn test_contract_div_1() {
+        let (x, y) = (Expr::var(0), Expr::var(1));
+        let c = Constraint::new(x / y, Interval::new(Closed(2.), Closed(4.)));
+        let mut domains = [Interval::new(Closed(0.), Closed(8.)), INFINITY];
+        assert!(c.contract(&mut domains));
+        assert_eq!(
+            domains,
+            [
+                Interval::new(Closed(0.), Closed(8.)),
+                Interval::new(Closed(0.), Closed(4.))
+            ]
+        );
+    }
+
+    #[test]
+    fn test_contract_unsat_1() {
+        let (x, y) = (Expr::var(0), Expr::var(1));
+        let c = Constraint::equal(x + y, Expr::constant(100.));
+        let mut domains = [
+            Interval::new(Closed(0.), Closed(10.)),
+            Interval::new(Closed(0.), Closed(10.)),
+        ];
+        assert!(!c.contract(&mut domains));
+        assert_eq!(domains, [EMPTY, EMPTY]);
+    }
+
+    #[test]
+    fn test_contract_keeps_openness_1() {
+        use crate::Open;
+        let (x, y) = (Expr::var(0), Expr::var(1));
+        let c = Constraint::equal(x, y);
+        let mut domains = [
+            Interval::new(Open(0.), Unbound),
+            Interval::new(Unbound, Closed(10.)),
+        ];
+        assert!(c.contract(&mut domains));
+        assert_eq!(domains[0], Interval::new(Open(0.), Closed(10.)));
+        assert_eq!(domains[1], Interval::new(Closed(0.), Closed(10.)));
+    }
+
+    #[test]
+    fn test_propagate_1() {
+        let (x, y) = (Expr::var(0), Expr::var(1));
+        let constraints = [
+            Constraint::equal(&x + &y, Expr::constant(10.)),
+            Constraint::equal(x - y, Expr::constant(4.)),
+        ];
+        let mut domains = [
+            Interval::new(Closed(0.), Closed(10.)),
+            Interval::new(Closed(0.), Closed(10.)),
+        ];
+        assert!(propagate(&constraints, &mut domains));
+        assert_eq!(
+            domains,
+            [
+                Interval::new(Closed(4.), Closed(10.)),
+                Interval::new(Closed(0.), Closed(6.))
+            ]
+        );
+    }
+
+    #[test]
+    fn test_propagate_2() {
+        let (x, y) = (Expr::var(0), Expr::var(1));
+        let constraints = [
+            Constraint::new(&x + &y, Interval::new(Closed(0.), Closed(1.))),
+            Constraint::new(x - y, Interval::new(Closed(5.), Closed(6.))),
+        ];
+        let mut domains = [
+            Interval::new(Closed(0.), Closed(2.)),
+            Interval::new(Closed(0.), Closed(2.)),
+        ];
+        assert!(!propagate(&constraints, &mut domains));
+    }
+}
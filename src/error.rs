@@ -0,0 +1,60 @@
+use std::fmt::Display;
+
+/// Reason why an interval, or a structure built from intervals, can't be constructed
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IntervalError {
+    /// An endpoint is NaN
+    NanEndpoint,
+    /// Lower endpoint lies above upper endpoint
+    ReversedBounds,
+    /// Empty interval (or no interval at all) where a non-empty one is required
+    Empty,
+    /// Unbound endpoint where a bounded one is required
+    Unbounded,
+    /// Intervals don't follow each other exactly
+    NotContiguous,
+    /// Interval is not a subset of the interval required to contain it
+    NotContained,
+    /// Zero count where at least one item is required
+    ZeroCount,
+    /// Period is not positive and finite
+    InvalidPeriod,
+    /// Interval holds more (or less) than a single point
+    NotSingleton,
+    /// Text doesn't describe an interval
+    Parse(String),
+}
+
+impl Display for IntervalError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IntervalError::NanEndpoint => write!(f, "NaN endpoint"),
+            IntervalError::ReversedBounds => write!(f, "lower endpoint above upper endpoint"),
+            IntervalError::Empty => write!(f, "empty interval"),
+            IntervalError::Unbounded => write!(f, "unbound endpoint"),
+            IntervalError::NotContiguous => write!(f, "intervals are not contiguous"),
+            IntervalError::NotContained => write!(f, "interval is not contained"),
+            IntervalError::ZeroCount => write!(f, "zero count"),
+            IntervalError::InvalidPeriod => write!(f, "period is not positive and finite"),
+            IntervalError::NotSingleton => write!(f, "interval is not a singleton"),
+            IntervalError::Parse(text) => write!(f, "can't parse interval from {text:?}"),
+        }
+    }
+}
+
+impl std::error::Error for IntervalError {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_display_1() {
+        assert_eq!(format!("{}", IntervalError::NanEndpoint), "NaN endpoint");
+        assert_eq!(
+            format!("{}", IntervalError::Parse("[1,".to_string())),
+            "can't parse interval from \"[1,\""
+        );
+    }
+}
@@ -0,0 +1,240 @@
+use super::{Interval, IntervalError};
+
+/// Fuzzy interval with trapezoidal membership (feature `fuzzy`)
+///
+/// Degree of membership is `1` on the core, `0` out of the support, and ramps linearly
+/// from the support endpoints to the core endpoints.
+///
+/// # Example
+///
+/// ```
+/// use interval::{FuzzyInterval, Interval, Closed};
+///
+/// let warm = FuzzyInterval::new(
+///     Interval::new(Closed(20.), Closed(25.)),
+///     Interval::new(Closed(15.), Closed(30.)),
+/// ).unwrap();
+///
+/// assert_eq!(warm.membership(22.), 1.);
+/// assert_eq!(warm.membership(17.5), 0.5);
+/// assert_eq!(warm.membership(29.), 0.2);
+/// assert_eq!(warm.membership(10.), 0.);
+/// ```
+///
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FuzzyInterval {
+    core: Interval,
+    support: Interval,
+}
+
+impl FuzzyInterval {
+    /// Build fuzzy interval from its `core` (degree 1) and `support` (degree above 0)
+    ///
+    /// Fails if `core` is empty or not a subset of `support`, or if a side of `support` is
+    /// unbound while the same side of `core` is not.
+    ///
+    pub fn new(core: Interval, support: Interval) -> Result<Self, IntervalError> {
+        let (c1, c2) = core.endpoints().ok_or(IntervalError::Empty)?;
+        if !support.contains_interval(core) {
+            return Err(IntervalError::NotContained);
+        }
+        let (s1, s2) = support.endpoints().ok_or(IntervalError::Empty)?;
+        if (s1.is_infinite() && c1.is_finite()) || (s2.is_infinite() && c2.is_finite()) {
+            return Err(IntervalError::Unbounded);
+        }
+        Ok(FuzzyInterval { core, support })
+    }
+
+    /// Fuzzy interval equal to the non-empty `interval`, without ramps
+    ///
+    pub fn crisp(interval: Interval) -> Result<Self, IntervalError> {
+        FuzzyInterval::new(interval, interval)
+    }
+
+    pub fn core(&self) -> Interval {
+        self.core
+    }
+
+    pub fn support(&self) -> Interval {
+        self.support
+    }
+
+    /// Degree of membership of `x`, from `0` to `1`
+    ///
+    pub fn membership(&self, x: f64) -> f64 {
+        if self.core.contains(x) {
+            return 1.;
+        }
+        if !self.support.contains(x) {
+            return 0.;
+        }
+        let ((c1, c2), (s1, s2)) = (
+            self.core.endpoints().unwrap(),
+            self.support.endpoints().unwrap(),
+        );
+        if x <= c1 {
+            ramp(x - s1, c1 - s1)
+        } else {
+            ramp(s2 - x, s2 - c2)
+        }
+    }
+
+    /// Combine fuzzy intervals with minimum (fuzzy `and`)
+    ///
+    /// Core and support of the result are the intersections of cores and supports, so
+    /// degrees `0` and `1` match the pointwise minimum while ramps in between are linear
+    /// approximations. Returns `None` if cores don't intersect.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{FuzzyInterval, Interval, Closed};
+    ///
+    /// let fuzzy = |c1, c2, s1, s2| FuzzyInterval::new(
+    ///     Interval::new(Closed(c1), Closed(c2)),
+    ///     Interval::new(Closed(s1), Closed(s2)),
+    /// ).unwrap();
+    ///
+    /// let a = fuzzy(2., 6., 0., 8.);
+    /// let b = fuzzy(4., 8., 2., 10.);
+    ///
+    /// assert_eq!(a.min(b), Some(fuzzy(4., 6., 2., 8.)));
+    /// ```
+    ///
+    pub fn min(self, other: FuzzyInterval) -> Option<FuzzyInterval> {
+        FuzzyInterval::new(
+            self.core.intersection(other.core),
+            self.support.intersection(other.support),
+        )
+        .ok()
+    }
+
+    /// Combine fuzzy intervals with maximum (fuzzy `or`)
+    ///
+    /// Core and support of the result are the unions of cores and supports, approximated
+    /// in the same way as `min`. Returns `None` if cores are disjoint, since the union is
+    /// then no longer trapezoidal.
+    ///
+    pub fn max(self, other: FuzzyInterval) -> Option<FuzzyInterval> {
+        match (
+            self.core.union(other.core),
+            self.support.union(other.support),
+        ) {
+            ((core, None), (support, None)) => FuzzyInterval::new(core, support).ok(),
+            _ => None,
+        }
+    }
+}
+
+/// Linear ramp at distance `d` over a ramp of length `len`, vertical ramps giving `0`
+///
+fn ramp(d: f64, len: f64) -> f64 {
+    if len > 0. {
+        d / len
+    } else {
+        0.
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Closed, Open, Unbound, EMPTY, INFINITY};
+
+    #[test]
+    fn test_new_1() {
+        let a = Interval::new(Closed(0.), Closed(1.));
+        let b = Interval::new(Closed(0.), Unbound);
+        assert_eq!(FuzzyInterval::new(EMPTY, a), Err(IntervalError::Empty));
+        assert_eq!(FuzzyInterval::new(b, a), Err(IntervalError::NotContained));
+        assert_eq!(FuzzyInterval::new(a, b), Err(IntervalError::Unbounded));
+        assert_eq!(
+            FuzzyInterval::new(b, INFINITY),
+            Err(IntervalError::Unbounded)
+        );
+        assert!(FuzzyInterval::new(b, b).is_ok());
+        assert!(FuzzyInterval::new(INFINITY, INFINITY).is_ok());
+    }
+
+    #[test]
+    fn test_membership_1() {
+        let a = FuzzyInterval::new(
+            Interval::new(Closed(1.), Closed(2.)),
+            Interval::new(Closed(0.), Closed(4.)),
+        )
+        .unwrap();
+        assert_eq!(a.membership(0.), 0.);
+        assert_eq!(a.membership(0.25), 0.25);
+        assert_eq!(a.membership(1.), 1.);
+        assert_eq!(a.membership(3.), 0.5);
+        assert_eq!(a.membership(4.), 0.);
+        assert_eq!(a.membership(f64::NAN), 0.);
+    }
+
+    #[test]
+    fn test_membership_2() {
+        let a = FuzzyInterval::new(
+            Interval::new(Open(0.), Open(1.)),
+            Interval::new(Closed(0.), Closed(1.)),
+        )
+        .unwrap();
+        assert_eq!(a.membership(0.), 0.);
+        assert_eq!(a.membership(0.5), 1.);
+        let b = FuzzyInterval::crisp(Interval::new(Closed(0.), Unbound)).unwrap();
+        assert_eq!(b.membership(-1.), 0.);
+        assert_eq!(b.membership(1e300), 1.);
+    }
+
+    #[test]
+    fn test_min_1() {
+        let (a, b) = (
+            FuzzyInterval::new(
+                Interval::new(Closed(0.), Closed(1.)),
+                Interval::new(Closed(-1.), Closed(2.)),
+            )
+            .unwrap(),
+            FuzzyInterval::new(
+                Interval::new(Closed(3.), Closed(4.)),
+                Interval::new(Closed(1.), Closed(5.)),
+            )
+            .unwrap(),
+        );
+        assert!(a.min(b).is_none());
+        assert_eq!(a.min(a), Some(a));
+    }
+
+    #[test]
+    fn test_max_1() {
+        let (a, b) = (
+            FuzzyInterval::new(
+                Interval::new(Closed(2.), Closed(6.)),
+                Interval::new(Closed(0.), Closed(8.)),
+            )
+            .unwrap(),
+            FuzzyInterval::new(
+                Interval::new(Closed(4.), Closed(8.)),
+                Interval::new(Closed(2.), Closed(10.)),
+            )
+            .unwrap(),
+        );
+        assert_eq!(
+            a.max(b),
+            Some(
+                FuzzyInterval::new(
+                    Interval::new(Closed(2.), Closed(8.)),
+                    Interval::new(Closed(0.), Closed(10.))
+                )
+                .unwrap()
+            )
+        );
+        assert!(a
+            .max(
+                FuzzyInterval::new(
+                    Interval::new(Closed(7.), Closed(8.)),
+                    Interval::new(Closed(5.), Closed(9.))
+                )
+                .unwrap()
+            )
+            .is_none());
+    }
+}
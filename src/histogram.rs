@@ -0,0 +1,105 @@
+use super::Partition;
+
+/// Count of values falling into each bin of a partition
+///
+/// A value lying exactly on an edge is counted in the bin containing it, according to the
+/// openness of bin bounds. Values outside the partition domain (or NaN) are counted apart.
+///
+/// # Example
+///
+/// ```
+/// use interval::{Histogram, Interval, Closed};
+///
+/// let bins = Interval::new(Closed(0.), Closed(3.)).bins(3).unwrap();
+/// let h = Histogram::from_values(bins, &[0., 0.5, 1., 2.5, 3., 4.]);
+///
+/// assert_eq!(h.counts(), &[2, 1, 2]);
+/// assert_eq!(h.outside(), 1);
+/// ```
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct Histogram {
+    partition: Partition,
+    counts: Vec<usize>,
+    outside: usize,
+}
+
+impl Histogram {
+    /// Empty histogram over bins of `partition`
+    ///
+    pub fn new(partition: Partition) -> Self {
+        let counts = vec![0; partition.len()];
+        Histogram {
+            partition,
+            counts,
+            outside: 0,
+        }
+    }
+
+    pub fn from_values(partition: Partition, values: &[f64]) -> Self {
+        let mut histogram = Histogram::new(partition);
+        for &x in values {
+            histogram.add(x);
+        }
+        histogram
+    }
+
+    /// Count value `x`, returning index of its bin
+    ///
+    pub fn add(&mut self, x: f64) -> Option<usize> {
+        let bin = self.partition.locate(x);
+        match bin {
+            Some(i) => self.counts[i] += 1,
+            None => self.outside += 1,
+        }
+        bin
+    }
+
+    pub fn partition(&self) -> &Partition {
+        &self.partition
+    }
+
+    pub fn counts(&self) -> &[usize] {
+        &self.counts
+    }
+
+    /// Number of values counted out of every bin
+    ///
+    pub fn outside(&self) -> usize {
+        self.outside
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Closed, Interval, Open};
+
+    #[test]
+    fn test_add_1() {
+        let bins = Interval::new(Open(0.), Open(2.)).bins(2).unwrap();
+        let mut h = Histogram::new(bins);
+        assert_eq!(h.add(0.), None);
+        assert_eq!(h.add(1.), Some(1));
+        assert_eq!(h.add(1.5), Some(1));
+        assert_eq!(h.add(2.), None);
+        assert_eq!(h.add(f64::NAN), None);
+        assert_eq!(h.counts(), &[0, 2]);
+        assert_eq!(h.outside(), 3);
+    }
+
+    #[test]
+    fn test_from_values_1() {
+        let bins = Partition::from_breakpoints(&[0., 1., 10., 100.]).unwrap();
+        let h = Histogram::from_values(bins, &[0., 1., 10., 100., 5., 50.]);
+        assert_eq!(h.counts(), &[1, 2, 3]);
+        assert_eq!(h.outside(), 0);
+    }
+
+    #[test]
+    fn test_bins_1() {
+        let a = Interval::new(Closed(0.), Closed(1.));
+        assert_eq!(a.bins(2), Partition::equal_width(a, 2));
+        assert!(a.bins(0).is_err());
+    }
+}
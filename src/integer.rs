@@ -0,0 +1,894 @@
+use super::IntervalError;
+use auto_ops::impl_op_ex;
+use std::fmt::Display;
+
+/// Non-empty range `[lower, upper]` of machine integers `i64`
+///
+/// Arithmetic comes in the flavours of `i64` itself: wrapping operations enclose every
+/// result of the wrapping operation on members of operands, saturating operations every
+/// result of the saturating one. Bitwise operators `&`, `|`, `^` and `!` give the tightest
+/// range holding every result.
+///
+/// See `U64Interval` for unsigned integers.
+///
+/// # Example
+///
+/// ```
+/// use interval::I64Interval;
+///
+/// let a = I64Interval::new(i64::MAX - 10, i64::MAX).unwrap();
+/// let one = I64Interval::singleton(1);
+///
+/// assert_eq!(a.saturating_add(one), I64Interval::new(i64::MAX - 9, i64::MAX).unwrap());
+/// // Some sums overflow and others don't: wrapped results are spread over the whole range
+/// assert_eq!(a.wrapping_add(one), I64Interval::full());
+/// ```
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct I64Interval {
+    lower: i64,
+    upper: i64,
+}
+
+impl Display for I64Interval {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[{},{}]", self.lower, self.upper)
+    }
+}
+
+impl I64Interval {
+    /// Build range `[lower, upper]`, failing if `lower > upper`
+    ///
+    pub fn new(lower: i64, upper: i64) -> Result<Self, IntervalError> {
+        if lower > upper {
+            return Err(IntervalError::ReversedBounds);
+        }
+        Ok(I64Interval { lower, upper })
+    }
+
+    pub fn singleton(k: i64) -> Self {
+        I64Interval { lower: k, upper: k }
+    }
+
+    /// Range of every `i64`
+    ///
+    pub fn full() -> Self {
+        I64Interval {
+            lower: i64::MIN,
+            upper: i64::MAX,
+        }
+    }
+
+    pub fn lower(&self) -> i64 {
+        self.lower
+    }
+
+    pub fn upper(&self) -> i64 {
+        self.upper
+    }
+
+    pub fn contains(&self, k: i64) -> bool {
+        self.lower <= k && k <= self.upper
+    }
+
+    /// Intersection of ranges, `None` if they are disjoint
+    ///
+    pub fn intersection(&self, other: I64Interval) -> Option<I64Interval> {
+        I64Interval::new(self.lower.max(other.lower), self.upper.min(other.upper)).ok()
+    }
+
+    /// Smallest range containing both ranges
+    ///
+    pub fn hull(&self, other: I64Interval) -> I64Interval {
+        I64Interval {
+            lower: self.lower.min(other.lower),
+            upper: self.upper.max(other.upper),
+        }
+    }
+
+    /// Enclosure of `a.wrapping_add(b)` for `a` in `self` and `b` in `other`
+    ///
+    pub fn wrapping_add(&self, other: I64Interval) -> I64Interval {
+        let (lower, upper) = self.exact_add(other);
+        wrapped(lower, upper)
+    }
+
+    /// Range of `a.saturating_add(b)` for `a` in `self` and `b` in `other`
+    ///
+    pub fn saturating_add(&self, other: I64Interval) -> I64Interval {
+        let (lower, upper) = self.exact_add(other);
+        saturated(lower, upper)
+    }
+
+    /// Enclosure of `a.wrapping_mul(b)` for `a` in `self` and `b` in `other`
+    ///
+    pub fn wrapping_mul(&self, other: I64Interval) -> I64Interval {
+        let (lower, upper) = self.exact_mul(other);
+        wrapped(lower, upper)
+    }
+
+    /// Range of `a.saturating_mul(b)` for `a` in `self` and `b` in `other`
+    ///
+    pub fn saturating_mul(&self, other: I64Interval) -> I64Interval {
+        let (lower, upper) = self.exact_mul(other);
+        saturated(lower, upper)
+    }
+
+    /// Enclosure of `a.wrapping_shl(s)` for `a` in `self` and `s` in `amount`
+    ///
+    /// As with `i64::wrapping_shl`, amounts are taken modulo 64.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::I64Interval;
+    ///
+    /// let a = I64Interval::new(1, 3).unwrap();
+    /// let amount = I64Interval::new(2, 4).unwrap();
+    ///
+    /// assert_eq!(a.wrapping_shl(amount), I64Interval::new(4, 48).unwrap());
+    /// assert_eq!(a.wrapping_shl(I64Interval::singleton(66)), I64Interval::new(4, 12).unwrap());
+    /// ```
+    ///
+    pub fn wrapping_shl(&self, amount: I64Interval) -> I64Interval {
+        shift_amounts(amount.lower as i128, amount.upper as i128)
+            .map(|s| {
+                let lower = (self.lower as i128) << s;
+                let upper = (self.upper as i128) << s;
+                wrapped(lower, upper)
+            })
+            .reduce(|res, a| res.hull(a))
+            .unwrap()
+    }
+
+    /// Range of `a.wrapping_shr(s)` (arithmetic shift) for `a` in `self` and `s` in
+    /// `amount`
+    ///
+    /// As with `i64::wrapping_shr`, amounts are taken modulo 64.
+    ///
+    pub fn wrapping_shr(&self, amount: I64Interval) -> I64Interval {
+        // Shift is monotonic in shifted value, and in amount for a given value
+        let amounts = shift_amounts(amount.lower as i128, amount.upper as i128);
+        let (s1, s2) = (*amounts.start(), *amounts.end());
+        I64Interval {
+            lower: (self.lower >> s1).min(self.lower >> s2),
+            upper: (self.upper >> s1).max(self.upper >> s2),
+        }
+    }
+
+    /// Parts of range holding negative and non-negative values, as unsigned ranges of the
+    /// same bits
+    ///
+    fn unsigned_parts(&self) -> impl Iterator<Item = (u64, u64)> {
+        let negative = (self.lower < 0).then(|| (self.lower as u64, self.upper.min(-1) as u64));
+        let positive = (self.upper >= 0).then(|| (self.lower.max(0) as u64, self.upper as u64));
+        negative.into_iter().chain(positive)
+    }
+
+    /// Range of `op` applied to members of ranges, from bounds `(min, max)` of `op` over
+    /// unsigned ranges
+    ///
+    /// Parts of operands of given sign fix the sign bit of results, so that each unsigned
+    /// result range maps back to a signed one.
+    ///
+    fn bitwise(
+        &self,
+        other: I64Interval,
+        bounds: impl Fn((u64, u64), (u64, u64)) -> (u64, u64),
+    ) -> I64Interval {
+        self.unsigned_parts()
+            .flat_map(|a| other.unsigned_parts().map(move |b| (a, b)))
+            .map(|(a, b)| {
+                let (lower, upper) = bounds(a, b);
+                I64Interval {
+                    lower: lower as i64,
+                    upper: upper as i64,
+                }
+            })
+            .reduce(|res, a| res.hull(a))
+            .unwrap()
+    }
+
+    /// Bounds of sum without overflow
+    ///
+    fn exact_add(&self, other: I64Interval) -> (i128, i128) {
+        (
+            self.lower as i128 + other.lower as i128,
+            self.upper as i128 + other.upper as i128,
+        )
+    }
+
+    /// Bounds of product without overflow, from products of endpoints
+    ///
+    fn exact_mul(&self, other: I64Interval) -> (i128, i128) {
+        let products = [
+            self.lower as i128 * other.lower as i128,
+            self.lower as i128 * other.upper as i128,
+            self.upper as i128 * other.lower as i128,
+            self.upper as i128 * other.upper as i128,
+        ];
+        let lower = products.iter().min().unwrap();
+        let upper = products.iter().max().unwrap();
+        (*lower, *upper)
+    }
+}
+
+/// Non-empty range `[lower, upper]` of machine integers `u64`
+///
+/// Operations are those of `I64Interval`, with unsigned order and overflow. Ranges convert
+/// losslessly between both types when all their members are valid for both.
+///
+/// # Example
+///
+/// ```
+/// use interval::{I64Interval, U64Interval};
+///
+/// let a = U64Interval::new(0, 10).unwrap();
+/// let one = U64Interval::singleton(1);
+///
+/// // Adding u64::MAX wraps 0 around to the top of the range, unlike other members
+/// assert_eq!(a.wrapping_add(U64Interval::singleton(u64::MAX)), U64Interval::full());
+/// assert_eq!(a.wrapping_add(one), U64Interval::new(1, 11).unwrap());
+/// assert_eq!(I64Interval::try_from(a), Ok(I64Interval::new(0, 10).unwrap()));
+/// assert!(U64Interval::try_from(I64Interval::new(-1, 10).unwrap()).is_err());
+/// ```
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct U64Interval {
+    lower: u64,
+    upper: u64,
+}
+
+impl Display for U64Interval {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[{},{}]", self.lower, self.upper)
+    }
+}
+
+/// Signed range of an unsigned one, failing with `IntervalError::NotContained` if it holds
+/// values above `i64::MAX`
+///
+impl TryFrom<U64Interval> for I64Interval {
+    type Error = IntervalError;
+
+    fn try_from(range: U64Interval) -> Result<Self, Self::Error> {
+        match (i64::try_from(range.lower), i64::try_from(range.upper)) {
+            (Ok(lower), Ok(upper)) => Ok(I64Interval { lower, upper }),
+            _ => Err(IntervalError::NotContained),
+        }
+    }
+}
+
+/// Unsigned range of a signed one, failing with `IntervalError::NotContained` if it holds
+/// negative values
+///
+impl TryFrom<I64Interval> for U64Interval {
+    type Error = IntervalError;
+
+    fn try_from(range: I64Interval) -> Result<Self, Self::Error> {
+        match (u64::try_from(range.lower), u64::try_from(range.upper)) {
+            (Ok(lower), Ok(upper)) => Ok(U64Interval { lower, upper }),
+            _ => Err(IntervalError::NotContained),
+        }
+    }
+}
+
+impl U64Interval {
+    /// Build range `[lower, upper]`, failing if `lower > upper`
+    ///
+    pub fn new(lower: u64, upper: u64) -> Result<Self, IntervalError> {
+        if lower > upper {
+            return Err(IntervalError::ReversedBounds);
+        }
+        Ok(U64Interval { lower, upper })
+    }
+
+    pub fn singleton(k: u64) -> Self {
+        U64Interval { lower: k, upper: k }
+    }
+
+    /// Range of every `u64`
+    ///
+    pub fn full() -> Self {
+        U64Interval {
+            lower: 0,
+            upper: u64::MAX,
+        }
+    }
+
+    pub fn lower(&self) -> u64 {
+        self.lower
+    }
+
+    pub fn upper(&self) -> u64 {
+        self.upper
+    }
+
+    pub fn contains(&self, k: u64) -> bool {
+        self.lower <= k && k <= self.upper
+    }
+
+    /// Intersection of ranges, `None` if they are disjoint
+    ///
+    pub fn intersection(&self, other: U64Interval) -> Option<U64Interval> {
+        U64Interval::new(self.lower.max(other.lower), self.upper.min(other.upper)).ok()
+    }
+
+    /// Smallest range containing both ranges
+    ///
+    pub fn hull(&self, other: U64Interval) -> U64Interval {
+        U64Interval {
+            lower: self.lower.min(other.lower),
+            upper: self.upper.max(other.upper),
+        }
+    }
+
+    /// Enclosure of `a.wrapping_add(b)` for `a` in `self` and `b` in `other`
+    ///
+    pub fn wrapping_add(&self, other: U64Interval) -> U64Interval {
+        let (lower, upper) = self.exact_add(other);
+        wrapped_unsigned(lower, upper)
+    }
+
+    /// Range of `a.saturating_add(b)` for `a` in `self` and `b` in `other`
+    ///
+    pub fn saturating_add(&self, other: U64Interval) -> U64Interval {
+        let (lower, upper) = self.exact_add(other);
+        saturated_unsigned(lower, upper)
+    }
+
+    /// Enclosure of `a.wrapping_mul(b)` for `a` in `self` and `b` in `other`
+    ///
+    pub fn wrapping_mul(&self, other: U64Interval) -> U64Interval {
+        let (lower, upper) = self.exact_mul(other);
+        wrapped_unsigned(lower, upper)
+    }
+
+    /// Range of `a.saturating_mul(b)` for `a` in `self` and `b` in `other`
+    ///
+    pub fn saturating_mul(&self, other: U64Interval) -> U64Interval {
+        let (lower, upper) = self.exact_mul(other);
+        saturated_unsigned(lower, upper)
+    }
+
+    /// Enclosure of `a.wrapping_shl(s)` for `a` in `self` and `s` in `amount`
+    ///
+    /// As with `u64::wrapping_shl`, amounts are taken modulo 64.
+    ///
+    pub fn wrapping_shl(&self, amount: U64Interval) -> U64Interval {
+        shift_amounts(amount.lower as i128, amount.upper as i128)
+            .map(|s| wrapped_unsigned((self.lower as u128) << s, (self.upper as u128) << s))
+            .reduce(|res, a| res.hull(a))
+            .unwrap()
+    }
+
+    /// Range of `a.wrapping_shr(s)` (logical shift) for `a` in `self` and `s` in `amount`
+    ///
+    /// As with `u64::wrapping_shr`, amounts are taken modulo 64.
+    ///
+    pub fn wrapping_shr(&self, amount: U64Interval) -> U64Interval {
+        let amounts = shift_amounts(amount.lower as i128, amount.upper as i128);
+        U64Interval {
+            lower: self.lower >> amounts.end(),
+            upper: self.upper >> amounts.start(),
+        }
+    }
+
+    /// Bounds of sum without overflow
+    ///
+    fn exact_add(&self, other: U64Interval) -> (u128, u128) {
+        (
+            self.lower as u128 + other.lower as u128,
+            self.upper as u128 + other.upper as u128,
+        )
+    }
+
+    /// Bounds of product without overflow
+    ///
+    fn exact_mul(&self, other: U64Interval) -> (u128, u128) {
+        (
+            self.lower as u128 * other.lower as u128,
+            self.upper as u128 * other.upper as u128,
+        )
+    }
+}
+
+/// Enclosure of exact range `[lower, upper]` reduced modulo `2^64` into `i64`
+///
+/// Reduction is monotonic, so exact, when the range doesn't cross a multiple of `2^64`
+/// (shifted by `i64::MIN`); otherwise reduced values cover the whole `i64` range.
+///
+fn wrapped(lower: i128, upper: i128) -> I64Interval {
+    let window = |k: i128| (k - i64::MIN as i128) >> 64;
+    if window(lower) == window(upper) {
+        I64Interval {
+            lower: lower as i64,
+            upper: upper as i64,
+        }
+    } else {
+        I64Interval::full()
+    }
+}
+
+/// Shift amounts of range taken modulo 64
+///
+fn shift_amounts(lower: i128, upper: i128) -> std::ops::RangeInclusive<u32> {
+    if upper - lower >= 64 || lower.div_euclid(64) != upper.div_euclid(64) {
+        0..=63
+    } else {
+        lower.rem_euclid(64) as u32..=upper.rem_euclid(64) as u32
+    }
+}
+
+// Tightest bounds of bitwise operations over unsigned ranges `[a, b]` and `[c, d]`, after
+// Warren, Hacker's Delight, section 4-3: each one scans bits from the highest one, looking
+// for a bit that can be flipped in an operand while staying in its range
+
+fn min_or((mut a, b): (u64, u64), (mut c, d): (u64, u64)) -> u64 {
+    let mut m = 1 << 63;
+    while m != 0 {
+        if !a & c & m != 0 {
+            let temp = (a | m) & m.wrapping_neg();
+            if temp <= b {
+                a = temp;
+                break;
+            }
+        } else if a & !c & m != 0 {
+            let temp = (c | m) & m.wrapping_neg();
+            if temp <= d {
+                c = temp;
+                break;
+            }
+        }
+        m >>= 1;
+    }
+    a | c
+}
+
+fn max_or((a, mut b): (u64, u64), (c, mut d): (u64, u64)) -> u64 {
+    let mut m = 1 << 63;
+    while m != 0 {
+        if b & d & m != 0 {
+            let temp = (b - m) | (m - 1);
+            if temp >= a {
+                b = temp;
+                break;
+            }
+            let temp = (d - m) | (m - 1);
+            if temp >= c {
+                d = temp;
+                break;
+            }
+        }
+        m >>= 1;
+    }
+    b | d
+}
+
+fn min_and((mut a, b): (u64, u64), (mut c, d): (u64, u64)) -> u64 {
+    let mut m = 1 << 63;
+    while m != 0 {
+        if !a & !c & m != 0 {
+            let temp = (a | m) & m.wrapping_neg();
+            if temp <= b {
+                a = temp;
+                break;
+            }
+            let temp = (c | m) & m.wrapping_neg();
+            if temp <= d {
+                c = temp;
+                break;
+            }
+        }
+        m >>= 1;
+    }
+    a & c
+}
+
+fn max_and((a, mut b): (u64, u64), (c, mut d): (u64, u64)) -> u64 {
+    let mut m = 1 << 63;
+    while m != 0 {
+        if b & !d & m != 0 {
+            let temp = (b & !m) | (m - 1);
+            if temp >= a {
+                b = temp;
+                break;
+            }
+        } else if !b & d & m != 0 {
+            let temp = (d & !m) | (m - 1);
+            if temp >= c {
+                d = temp;
+                break;
+            }
+        }
+        m >>= 1;
+    }
+    b & d
+}
+
+fn min_xor((mut a, b): (u64, u64), (mut c, d): (u64, u64)) -> u64 {
+    let mut m = 1 << 63;
+    while m != 0 {
+        if !a & c & m != 0 {
+            let temp = (a | m) & m.wrapping_neg();
+            if temp <= b {
+                a = temp;
+            }
+        } else if a & !c & m != 0 {
+            let temp = (c | m) & m.wrapping_neg();
+            if temp <= d {
+                c = temp;
+            }
+        }
+        m >>= 1;
+    }
+    a ^ c
+}
+
+fn max_xor((a, mut b): (u64, u64), (c, mut d): (u64, u64)) -> u64 {
+    let mut m = 1 << 63;
+    while m != 0 {
+        if b & d & m != 0 {
+            let temp = (b - m) | (m - 1);
+            if temp >= a {
+                b = temp;
+            } else {
+                let temp = (d - m) | (m - 1);
+                if temp >= c {
+                    d = temp;
+                }
+            }
+        }
+        m >>= 1;
+    }
+    b ^ d
+}
+
+impl_op_ex!(&|a: &I64Interval, b: &I64Interval| -> I64Interval {
+    a.bitwise(*b, |x, y| (min_and(x, y), max_and(x, y)))
+});
+impl_op_ex!(| |a: &I64Interval, b: &I64Interval| -> I64Interval {
+    a.bitwise(*b, |x, y| (min_or(x, y), max_or(x, y)))
+});
+impl_op_ex!(^ |a: &I64Interval, b: &I64Interval| -> I64Interval {
+    a.bitwise(*b, |x, y| (min_xor(x, y), max_xor(x, y)))
+});
+impl_op_ex!(&|a: &U64Interval, b: &U64Interval| -> U64Interval {
+    let (x, y) = ((a.lower, a.upper), (b.lower, b.upper));
+    U64Interval {
+        lower: min_and(x, y),
+        upper: max_and(x, y),
+    }
+});
+impl_op_ex!(| |a: &U64Interval, b: &U64Interval| -> U64Interval {
+    let (x, y) = ((a.lower, a.upper), (b.lower, b.upper));
+    U64Interval {
+        lower: min_or(x, y),
+        upper: max_or(x, y),
+    }
+});
+impl_op_ex!(^ |a: &U64Interval, b: &U64Interval| -> U64Interval {
+    let (x, y) = ((a.lower, a.upper), (b.lower, b.upper));
+    U64Interval {
+        lower: min_xor(x, y),
+        upper: max_xor(x, y),
+    }
+});
+impl_op_ex!(!|a: &U64Interval| -> U64Interval {
+    U64Interval {
+        lower: !a.upper,
+        upper: !a.lower,
+    }
+});
+impl_op_ex!(!|a: &I64Interval| -> I64Interval {
+    I64Interval {
+        lower: !a.upper,
+        upper: !a.lower,
+    }
+});
+
+/// Enclosure of exact range `[lower, upper]` reduced modulo `2^64` into `u64`, as with
+/// `wrapped`
+///
+fn wrapped_unsigned(lower: u128, upper: u128) -> U64Interval {
+    if lower >> 64 == upper >> 64 {
+        U64Interval {
+            lower: lower as u64,
+            upper: upper as u64,
+        }
+    } else {
+        U64Interval::full()
+    }
+}
+
+/// Exact range `[lower, upper]` clamped into `u64`
+///
+fn saturated_unsigned(lower: u128, upper: u128) -> U64Interval {
+    let clamp = |k: u128| k.min(u64::MAX as u128) as u64;
+    U64Interval {
+        lower: clamp(lower),
+        upper: clamp(upper),
+    }
+}
+
+/// Exact range `[lower, upper]` clamped into `i64`
+///
+fn saturated(lower: i128, upper: i128) -> I64Interval {
+    let clamp = |k: i128| k.clamp(i64::MIN as i128, i64::MAX as i128) as i64;
+    I64Interval {
+        lower: clamp(lower),
+        upper: clamp(upper),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_new_1() {
+        assert_eq!(I64Interval::new(2, 1), Err(IntervalError::ReversedBounds));
+        assert_eq!(I64Interval::new(1, 2).unwrap().to_string(), "[1,2]");
+        assert_eq!(
+            I64Interval::new(0, 10)
+                .unwrap()
+                .intersection(I64Interval::new(5, 20).unwrap()),
+            Some(I64Interval::new(5, 10).unwrap())
+        );
+        assert_eq!(
+            I64Interval::new(0, 1)
+                .unwrap()
+                .intersection(I64Interval::new(5, 20).unwrap()),
+            None
+        );
+        assert_eq!(
+            I64Interval::new(0, 1)
+                .unwrap()
+                .hull(I64Interval::new(5, 20).unwrap()),
+            I64Interval::new(0, 20).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_add_1() {
+        assert_eq!(
+            I64Interval::new(1, 2)
+                .unwrap()
+                .wrapping_add(I64Interval::new(10, 20).unwrap()),
+            I64Interval::new(11, 22).unwrap()
+        );
+        // Every sum overflows: range is shifted as a whole
+        let a = I64Interval::new(i64::MAX - 1, i64::MAX).unwrap();
+        assert_eq!(
+            a.wrapping_add(I64Interval::new(2, 3).unwrap()),
+            I64Interval::new(i64::MIN, i64::MIN + 2).unwrap()
+        );
+        assert_eq!(
+            a.saturating_add(I64Interval::new(1, 2).unwrap()),
+            I64Interval::singleton(i64::MAX)
+        );
+        assert_eq!(
+            I64Interval::new(i64::MIN, i64::MIN + 1)
+                .unwrap()
+                .saturating_add(I64Interval::new(-5, 5).unwrap()),
+            I64Interval::new(i64::MIN, i64::MIN + 6).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_mul_1() {
+        assert_eq!(
+            I64Interval::new(-2, 3)
+                .unwrap()
+                .wrapping_mul(I64Interval::new(-5, 4).unwrap()),
+            I64Interval::new(-15, 12).unwrap()
+        );
+        assert_eq!(
+            I64Interval::new(-2, 3)
+                .unwrap()
+                .saturating_mul(I64Interval::new(-5, 4).unwrap()),
+            I64Interval::new(-15, 12).unwrap()
+        );
+        let big = I64Interval::new(1 << 62, 1 << 62).unwrap();
+        assert_eq!(
+            big.wrapping_mul(I64Interval::new(4, 4).unwrap()),
+            I64Interval::singleton(0)
+        );
+        assert_eq!(
+            big.wrapping_mul(I64Interval::new(3, 4).unwrap()),
+            I64Interval::new(-(1 << 62), 0).unwrap()
+        );
+        assert_eq!(
+            big.wrapping_mul(I64Interval::new(1, 4).unwrap()),
+            I64Interval::full()
+        );
+        assert_eq!(
+            big.saturating_mul(I64Interval::new(-4, 1).unwrap()),
+            I64Interval::new(i64::MIN, 1 << 62).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_wrapping_1() {
+        // Brute force soundness check on values near overflow
+        let values = [i64::MIN, i64::MIN + 1, -3, 0, 2, i64::MAX - 1, i64::MAX];
+        for (i, &a1) in values.iter().enumerate() {
+            for &a2 in &values[i..] {
+                for (j, &b1) in values.iter().enumerate() {
+                    for &b2 in &values[j..] {
+                        let (a, b) = (
+                            I64Interval::new(a1, a2).unwrap(),
+                            I64Interval::new(b1, b2).unwrap(),
+                        );
+                        for x in [a1, a2] {
+                            for y in [b1, b2] {
+                                assert!(a.wrapping_add(b).contains(x.wrapping_add(y)));
+                                assert!(a.wrapping_mul(b).contains(x.wrapping_mul(y)));
+                                assert!(a.saturating_mul(b).contains(x.saturating_mul(y)));
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_bitwise_1() {
+        assert_eq!(
+            I64Interval::new(4, 5).unwrap() & I64Interval::new(6, 7).unwrap(),
+            I64Interval::new(4, 5).unwrap()
+        );
+        assert_eq!(
+            I64Interval::new(4, 5).unwrap() | I64Interval::new(8, 8).unwrap(),
+            I64Interval::new(12, 13).unwrap()
+        );
+        assert_eq!(
+            I64Interval::new(-1, -1).unwrap() ^ I64Interval::new(0, 5).unwrap(),
+            I64Interval::new(-6, -1).unwrap()
+        );
+        assert_eq!(
+            !I64Interval::new(-3, 4).unwrap(),
+            I64Interval::new(-5, 2).unwrap()
+        );
+        assert_eq!(
+            I64Interval::new(i64::MIN, -1).unwrap() & I64Interval::new(0, i64::MAX).unwrap(),
+            I64Interval::new(0, i64::MAX).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_bitwise_2() {
+        // Brute force tightness check over small ranges of both signs
+        let values = -6..=6;
+        for a1 in values.clone() {
+            for a2 in a1..=6 {
+                for b1 in values.clone() {
+                    for b2 in b1..=6 {
+                        let (a, b) = (
+                            I64Interval::new(a1, a2).unwrap(),
+                            I64Interval::new(b1, b2).unwrap(),
+                        );
+                        let results = |op: fn(i64, i64) -> i64| {
+                            let mut results =
+                                (a1..=a2).flat_map(|x| (b1..=b2).map(move |y| op(x, y)));
+                            let first = results.next().unwrap();
+                            results.fold(I64Interval::singleton(first), |res, k| {
+                                res.hull(I64Interval::singleton(k))
+                            })
+                        };
+                        assert_eq!(a & b, results(|x, y| x & y));
+                        assert_eq!(a | b, results(|x, y| x | y));
+                        assert_eq!(a ^ b, results(|x, y| x ^ y));
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_shift_1() {
+        assert_eq!(
+            I64Interval::new(-8, 9)
+                .unwrap()
+                .wrapping_shr(I64Interval::new(1, 2).unwrap()),
+            I64Interval::new(-4, 4).unwrap()
+        );
+        assert_eq!(
+            I64Interval::new(-8, 9)
+                .unwrap()
+                .wrapping_shr(I64Interval::new(64, 65).unwrap()),
+            I64Interval::new(-8, 9).unwrap()
+        );
+        assert_eq!(
+            I64Interval::new(1, 1)
+                .unwrap()
+                .wrapping_shl(I64Interval::new(62, 62).unwrap()),
+            I64Interval::new(1 << 62, 1 << 62).unwrap()
+        );
+        assert_eq!(
+            I64Interval::new(1, 1)
+                .unwrap()
+                .wrapping_shl(I64Interval::new(63, 63).unwrap()),
+            I64Interval::new(i64::MIN, i64::MIN).unwrap()
+        );
+        // 2 << 62 wraps to 0 while 1 << 62 doesn't
+        assert_eq!(
+            I64Interval::new(1, 2)
+                .unwrap()
+                .wrapping_shl(I64Interval::new(62, 62).unwrap()),
+            I64Interval::full()
+        );
+        assert_eq!(
+            I64Interval::new(-1, 0)
+                .unwrap()
+                .wrapping_shl(I64Interval::new(63, 64).unwrap()),
+            I64Interval::new(i64::MIN, 0).unwrap()
+        );
+        for x in [-7, -1, 0, 3, i64::MAX] {
+            for s in [0, 1, 63, 64, -1] {
+                let (a, amount) = (
+                    I64Interval::new(x, x.saturating_add(1)).unwrap(),
+                    I64Interval::new(s, s + 1).unwrap(),
+                );
+                assert!(a.wrapping_shl(amount).contains(x.wrapping_shl(s as u32)));
+                assert!(a.wrapping_shr(amount).contains(x.wrapping_shr(s as u32)));
+            }
+        }
+    }
+
+    #[test]
+    fn test_unsigned_1() {
+        let a = U64Interval::new(u64::MAX - 1, u64::MAX).unwrap();
+        let b = U64Interval::new(2, 3).unwrap();
+        assert_eq!(U64Interval::new(2, 1), Err(IntervalError::ReversedBounds));
+        assert_eq!(a.wrapping_add(b), U64Interval::new(0, 2).unwrap());
+        assert_eq!(a.saturating_add(b), U64Interval::singleton(u64::MAX));
+        assert_eq!(b.wrapping_mul(b), U64Interval::new(4, 9).unwrap());
+        assert_eq!(a.wrapping_mul(b), U64Interval::full());
+        assert_eq!(a.saturating_mul(b), U64Interval::singleton(u64::MAX));
+        assert_eq!(
+            b.wrapping_shl(U64Interval::singleton(63)),
+            U64Interval::new(0, 1 << 63).unwrap()
+        );
+        assert_eq!(
+            b.wrapping_shl(U64Interval::new(62, 63).unwrap()),
+            U64Interval::new(0, 3 << 62).unwrap()
+        );
+        let c = U64Interval::new((1 << 63) - 1, 1 << 63).unwrap();
+        assert_eq!(
+            c.wrapping_shl(U64Interval::singleton(1)),
+            U64Interval::full()
+        );
+        assert_eq!(
+            a.wrapping_shr(U64Interval::new(62, 63).unwrap()),
+            U64Interval::new(1, 3).unwrap()
+        );
+        assert_eq!(!b, U64Interval::new(u64::MAX - 3, u64::MAX - 2).unwrap());
+        assert_eq!(
+            b & U64Interval::new(4, 6).unwrap(),
+            U64Interval::new(0, 2).unwrap()
+        );
+        assert_eq!(b | a, U64Interval::new(u64::MAX - 1, u64::MAX).unwrap());
+        assert_eq!(b ^ b, U64Interval::new(0, 1).unwrap());
+    }
+
+    #[test]
+    fn test_unsigned_2() {
+        let a = U64Interval::new(1, u64::MAX).unwrap();
+        assert_eq!(I64Interval::try_from(a), Err(IntervalError::NotContained));
+        assert_eq!(
+            U64Interval::try_from(I64Interval::new(0, 5).unwrap()),
+            Ok(U64Interval::new(0, 5).unwrap())
+        );
+        assert_eq!(
+            U64Interval::try_from(I64Interval::new(-1, 5).unwrap()),
+            Err(IntervalError::NotContained)
+        );
+        let b = U64Interval::new(3, 1 << 40).unwrap();
+        assert_eq!(
+            U64Interval::try_from(I64Interval::try_from(b).unwrap()),
+            Ok(b)
+        );
+    }
+}
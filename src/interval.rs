@@ -1,118 +1,2050 @@
 mod bound;
+mod edge;
 mod left;
 mod right;
 
-use bound::Bound;
+pub use bound::Bound;
+pub(crate) use edge::Edge;
 use left::Left;
 use right::Right;
 
 pub use Bound::{Closed, Open, Unbound};
 
+use super::{IntervalError, IntervalSet, Partition};
+
 use std::cmp::PartialEq;
-use std::fmt::Display;
+use std::fmt::{Display, LowerExp, UpperExp};
+use std::ops::Bound::{Excluded, Included, Unbounded};
+use std::ops::{Range, RangeBounds, RangeInclusive};
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Interval(Left, Right);
+
+/// Rounding applied when snapping endpoints to a grid
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SnapMode {
+    /// Round each endpoint to the nearest grid point
+    Round,
+    /// Move endpoints outward, so that the result contains the original interval
+    Expand,
+    /// Move endpoints inward, so that the result is contained in the original interval
+    Shrink,
+}
+
+/// Direction of a monotone function, see `Interval::map_monotonic`
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Monotonicity {
+    /// `x <= y` implies `f(x) <= f(y)`
+    Increasing,
+    /// `x <= y` implies `f(x) >= f(y)`
+    Decreasing,
+}
+
+/// Side of a split taking the split point, see `Interval::split_at`
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Side {
+    /// Split point belongs to the lower part
+    Lower,
+    /// Split point belongs to the upper part
+    Upper,
+}
+
+/// Shape of an interval, see `Interval::kind`
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IntervalKind {
+    /// The empty set
+    Empty,
+    /// A single point `{k}`
+    Singleton,
+    /// Both endpoints bounded, like `[a, b)`
+    Bounded,
+    /// Only the left endpoint unbound, like `(-∞, b]`
+    LeftUnbounded,
+    /// Only the right endpoint unbound, like `[a, +∞)`
+    RightUnbounded,
+    /// The whole real line
+    Universe,
+}
+
+pub const EMPTY: Interval = Interval(Left(Open(0.)), Right(Open(0.)));
+pub const INFINITY: Interval = Interval(Left(Unbound), Right(Unbound));
+
+impl Display for Interval {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.fmt_endpoints(f, |k| format!("{k:5.2}"))
+    }
+}
+
+impl PartialEq for Interval {
+    fn eq(&self, other: &Self) -> bool {
+        let (Interval(a1, a2), Interval(b1, b2)) = (self, other);
+        a1 == b1 && a2 == b2
+    }
+}
+
+/// Display interval with endpoints in scientific notation, e.g. `[3.2e-9,1.7e12)`
+///
+/// Precision applies to each endpoint; without one, endpoints are written with as many
+/// digits as needed to read them back exactly.
+///
+/// # Example
+///
+/// ```
+/// use interval::{Interval, Closed, Open, Unbound};
+///
+/// let a = Interval::new(Closed(3.2e-9), Open(1.7e12));
+///
+/// assert_eq!(format!("{a:e}"), "[3.2e-9,1.7e12)");
+/// assert_eq!(format!("{a:.2E}"), "[3.20E-9,1.70E12)");
+/// assert_eq!(format!("{:e}", Interval::new(Unbound, Closed(-5e-3))), "(-∞,-5e-3]");
+/// assert_eq!(format!("{:e}", Interval::singleton(42.)), "{4.2e1}");
+/// ```
+///
+impl LowerExp for Interval {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let precision = f.precision();
+        self.fmt_endpoints(f, |k| match precision {
+            Some(p) => format!("{k:.p$e}"),
+            None => format!("{k:e}"),
+        })
+    }
+}
+
+/// Display interval with endpoints in scientific notation, with an uppercase `E`
+///
+impl UpperExp for Interval {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let precision = f.precision();
+        self.fmt_endpoints(f, |k| match precision {
+            Some(p) => format!("{k:.p$E}"),
+            None => format!("{k:E}"),
+        })
+    }
+}
+
+/// Value of a singleton interval
+///
+/// # Example
+///
+/// ```
+/// use interval::{Interval, IntervalError, Closed, Open};
+///
+/// assert_eq!(f64::try_from(Interval::singleton(42.)), Ok(42.));
+/// assert_eq!(
+///     f64::try_from(Interval::new(Closed(42.), Open(43.))),
+///     Err(IntervalError::NotSingleton)
+/// );
+/// ```
+///
+impl TryFrom<Interval> for f64 {
+    type Error = IntervalError;
+
+    fn try_from(value: Interval) -> Result<Self, Self::Error> {
+        match value {
+            Interval(Left(Closed(k1)), Right(Closed(k2))) if k1 == k2 => Ok(k1),
+            _ => Err(IntervalError::NotSingleton),
+        }
+    }
+}
+
+/// Parse interval written as displayed: `[a,b]`, `(a,b)`, `[a,b)`, `(a,b]`, `{k}` or `∅`
+///
+/// Endpoints may be written `-∞` or `-inf` for an unbound left endpoint, `+∞` or `inf` for
+/// an unbound right one, with any bracket. Swapped or NaN endpoints, and empty intervals
+/// other than `∅`, are errors as with `Interval::try_new`: `+∞` on the left or `-∞` on the
+/// right are swapped endpoints. Infinite singletons don't parse.
+///
+/// `Display` rounds endpoints to two decimals, so that only the `{:e}` form, written with as
+/// many digits as needed, reads back exactly.
+///
+/// # Example
+///
+/// ```
+/// use interval::{Interval, Closed, Open, Unbound, EMPTY};
+///
+/// assert_eq!("[0, 8)".parse(), Ok(Interval::new(Closed(0.), Open(8.))));
+/// assert_eq!("(-∞,2.5]".parse(), Ok(Interval::new(Unbound, Closed(2.5))));
+/// assert_eq!("∅".parse(), Ok(EMPTY));
+/// assert!("[0, 8".parse::<Interval>().is_err());
+///
+/// let a = Interval::new(Closed(0.125), Open(1. / 3.));
+///
+/// assert_eq!(format!("{a:e}").parse(), Ok(a));
+/// assert_ne!(a.to_string().parse(), Ok(a));
+/// ```
+///
+impl FromStr for Interval {
+    type Err = IntervalError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = || IntervalError::Parse(s.to_string());
+        let text = s.trim();
+        if text == "∅" {
+            return Ok(EMPTY);
+        }
+        if let Some(k) = text.strip_prefix('{').and_then(|t| t.strip_suffix('}')) {
+            let k: f64 = k.trim().parse().map_err(|_| error())?;
+            if k.is_infinite() {
+                return Err(error());
+            }
+            return Interval::try_new(Closed(k), Closed(k));
+        }
+        let mut chars = text.chars();
+        let (first, last) = (chars.next(), chars.next_back());
+        let (lower, upper) = chars.as_str().split_once(',').ok_or_else(error)?;
+        // Only -∞ may stand for an unbound left endpoint, and +∞ for an unbound right one
+        let endpoint = |k: &str, closed: bool, unbound: f64| -> Result<Bound, IntervalError> {
+            let k = match k.trim() {
+                "-∞" => f64::NEG_INFINITY,
+                "+∞" | "∞" => f64::INFINITY,
+                k => k.parse().map_err(|_| error())?,
+            };
+            match k {
+                _ if k == unbound => Ok(Unbound),
+                _ if k.is_infinite() => Err(IntervalError::ReversedBounds),
+                _ if closed => Ok(Closed(k)),
+                _ => Ok(Open(k)),
+            }
+        };
+        let left = match first {
+            Some('[') => endpoint(lower, true, f64::NEG_INFINITY)?,
+            Some('(') => endpoint(lower, false, f64::NEG_INFINITY)?,
+            _ => return Err(error()),
+        };
+        let right = match last {
+            Some(']') => endpoint(upper, true, f64::INFINITY)?,
+            Some(')') => endpoint(upper, false, f64::INFINITY)?,
+            _ => return Err(error()),
+        };
+        Interval::try_new(left, right)
+    }
+}
+
+impl Interval {
+    /// Build interval from given bounds
+    ///
+    /// # Returns
+    ///
+    /// The empty set if bounds are reversed or if an endpoint is NaN.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Open, Closed, Unbound};
+    ///
+    /// let a = Interval::new(Open(42.), Closed(43.));
+    /// let b = Interval::new(Unbound, Unbound);
+    /// let c = Interval::singleton(42.);
+    ///
+    /// assert_eq!(format!("{a}"), "(42.00,43.00]");
+    /// assert_eq!(format!("{b}"), "(-∞,+∞)");
+    /// assert_eq!(format!("{c}"), "{42.00}");
+    /// ```
+    ///
+    pub fn new(b1: Bound, b2: Bound) -> Self {
+        let b1 = Left(b1);
+        let b2 = Right(b2);
+
+        if b1.0.is_nan() || b2.0.is_nan() || b2 < b1 {
+            EMPTY
+        } else if (b1, b2) == (Left(Unbound), Right(Unbound)) {
+            INFINITY
+        } else {
+            Interval(b1, b2).checked()
+        }
+    }
+
+    /// Build interval from given bounds, failing instead of returning the empty set
+    ///
+    /// Unlike `new`, swapped endpoints are reported as `IntervalError::ReversedBounds`, and
+    /// equal endpoints with an open bound as `IntervalError::Empty`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, IntervalError, Open, Closed};
+    ///
+    /// assert!(Interval::try_new(Closed(0.), Open(1.)).is_ok());
+    /// assert_eq!(Interval::try_new(Closed(1.), Open(0.)), Err(IntervalError::ReversedBounds));
+    /// assert_eq!(Interval::try_new(Closed(1.), Open(1.)), Err(IntervalError::Empty));
+    /// ```
+    ///
+    pub fn try_new(b1: Bound, b2: Bound) -> Result<Self, IntervalError> {
+        if b1.is_nan() || b2.is_nan() {
+            return Err(IntervalError::NanEndpoint);
+        }
+        match (b1, b2) {
+            (Closed(k1) | Open(k1), Closed(k2) | Open(k2)) if k1 > k2 => {
+                Err(IntervalError::ReversedBounds)
+            }
+            _ => match Interval::new(b1, b2) {
+                a if a.is_empty() => Err(IntervalError::Empty),
+                a => Ok(a),
+            },
+        }
+    }
+
+    /// Build closed interval between `a` and `b`, given in either order
+    ///
+    /// Infinite values give unbound endpoints. Unlike `new`, swapped endpoints are put back
+    /// in order rather than giving the empty set.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Closed};
+    ///
+    /// assert_eq!(Interval::spanning(5., 2.), Interval::new(Closed(2.), Closed(5.)));
+    /// assert_eq!(Interval::spanning(2., 5.), Interval::spanning(5., 2.));
+    /// ```
+    ///
+    pub fn spanning(a: f64, b: f64) -> Self {
+        if a <= b {
+            Interval::from_endpoints(a, b)
+        } else {
+            Interval::from_endpoints(b, a)
+        }
+    }
+
+    pub fn singleton(k: f64) -> Self {
+        Interval::new(Closed(k), Closed(k))
+    }
+
+    pub fn is_singleton(&self) -> bool {
+        match self {
+            Interval(Left(Closed(k1)), Right(Closed(k2))) => k1 == k2,
+            _ => false,
+        }
+    }
+
+    pub fn is_empty(self) -> bool {
+        self == EMPTY
+    }
+
+    /// Shape of interval
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, IntervalKind, Closed, Unbound, EMPTY};
+    ///
+    /// let describe = |a: Interval| match a.kind() {
+    ///     IntervalKind::Empty => "nothing",
+    ///     IntervalKind::Singleton => "a point",
+    ///     IntervalKind::Bounded => "a range",
+    ///     IntervalKind::LeftUnbounded | IntervalKind::RightUnbounded => "a ray",
+    ///     IntervalKind::Universe => "everything",
+    /// };
+    ///
+    /// assert_eq!(describe(EMPTY), "nothing");
+    /// assert_eq!(describe(Interval::new(Closed(0.), Unbound)), "a ray");
+    /// ```
+    ///
+    pub fn kind(self) -> IntervalKind {
+        match self {
+            _ if self.is_empty() => IntervalKind::Empty,
+            _ if self.is_singleton() => IntervalKind::Singleton,
+            Interval(Left(Unbound), Right(Unbound)) => IntervalKind::Universe,
+            Interval(Left(Unbound), _) => IntervalKind::LeftUnbounded,
+            Interval(_, Right(Unbound)) => IntervalKind::RightUnbounded,
+            _ => IntervalKind::Bounded,
+        }
+    }
+
+    /// Split interval into its left and right bounds
+    ///
+    /// The empty set gives `(Open(0.), Open(0.))`. `Interval::from_bound_pair` rebuilds the
+    /// interval.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Open, Closed, Unbound};
+    ///
+    /// let a = Interval::new(Closed(0.), Unbound);
+    ///
+    /// match a.into_bounds() {
+    ///     (Closed(k), Unbound) => assert_eq!(k, 0.),
+    ///     _ => unreachable!(),
+    /// }
+    /// assert_eq!(Interval::from_bound_pair(a.into_bounds()), a);
+    /// ```
+    ///
+    pub fn into_bounds(self) -> (Bound, Bound) {
+        let Interval(Left(left), Right(right)) = self;
+        (left, right)
+    }
+
+    /// Left bound of interval, `Open(0.)` for the empty set
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Open, Closed, Unbound};
+    ///
+    /// let a = Interval::new(Closed(0.), Unbound);
+    ///
+    /// assert!(matches!(a.left(), Closed(k) if k == 0.));
+    /// assert!(matches!(a.right(), Unbound));
+    /// ```
+    ///
+    pub fn left(self) -> Bound {
+        self.into_bounds().0
+    }
+
+    /// Right bound of interval, `Open(0.)` for the empty set
+    ///
+    pub fn right(self) -> Bound {
+        self.into_bounds().1
+    }
+
+    /// Interval with left bound replaced by `bound`
+    ///
+    /// Gives the empty set if bounds end up reversed, or if interval is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Open, Closed, EMPTY};
+    ///
+    /// let a = Interval::new(Closed(0.), Open(2.));
+    ///
+    /// assert_eq!(a.with_left(Open(1.)), Interval::new(Open(1.), Open(2.)));
+    /// assert_eq!(a.with_right(Closed(5.)), Interval::new(Closed(0.), Closed(5.)));
+    /// assert_eq!(a.with_left(Closed(3.)), EMPTY);
+    /// ```
+    ///
+    pub fn with_left(self, bound: Bound) -> Interval {
+        match self {
+            _ if self.is_empty() => EMPTY,
+            _ => Interval::new(bound, self.right()),
+        }
+    }
+
+    /// Interval with right bound replaced by `bound`, see `with_left`
+    ///
+    pub fn with_right(self, bound: Bound) -> Interval {
+        match self {
+            _ if self.is_empty() => EMPTY,
+            _ => Interval::new(self.left(), bound),
+        }
+    }
+
+    /// Lower endpoint value, open or closed, `None` if unbound or empty
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Open, Closed, Unbound, EMPTY};
+    ///
+    /// let a = Interval::new(Open(0.), Unbound);
+    ///
+    /// assert_eq!(a.inf(), Some(0.));
+    /// assert_eq!(a.sup(), None);
+    /// assert_eq!(EMPTY.inf(), None);
+    /// ```
+    ///
+    pub fn inf(self) -> Option<f64> {
+        match self {
+            _ if self.is_empty() => None,
+            Interval(Left(Closed(k) | Open(k)), _) => Some(k),
+            Interval(Left(Unbound), _) => None,
+        }
+    }
+
+    /// Upper endpoint value, open or closed, `None` if unbound or empty
+    ///
+    pub fn sup(self) -> Option<f64> {
+        match self {
+            _ if self.is_empty() => None,
+            Interval(_, Right(Closed(k) | Open(k))) => Some(k),
+            Interval(_, Right(Unbound)) => None,
+        }
+    }
+
+    /// Build interval from a pair of bounds, in the same way as `new`
+    ///
+    pub fn from_bound_pair((b1, b2): (Bound, Bound)) -> Self {
+        Interval::new(b1, b2)
+    }
+
+    /// Check if left endpoint is closed
+    ///
+    /// Unbound endpoints are neither closed nor open, and the empty set has no endpoints.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Open, Closed, Unbound};
+    ///
+    /// let a = Interval::new(Closed(0.), Open(1.));
+    /// let b = Interval::new(Unbound, Closed(1.));
+    ///
+    /// assert!(a.is_left_closed() && a.is_right_open());
+    /// assert!(!b.is_left_closed() && !b.is_left_open());
+    /// ```
+    ///
+    pub fn is_left_closed(self) -> bool {
+        !self.is_empty() && matches!(self.0, Left(Closed(_)))
+    }
+
+    /// Check if left endpoint is open, see `is_left_closed`
+    ///
+    pub fn is_left_open(self) -> bool {
+        !self.is_empty() && matches!(self.0, Left(Open(_)))
+    }
+
+    /// Check if right endpoint is closed, see `is_left_closed`
+    ///
+    pub fn is_right_closed(self) -> bool {
+        !self.is_empty() && matches!(self.1, Right(Closed(_)))
+    }
+
+    /// Check if right endpoint is open, see `is_left_closed`
+    ///
+    pub fn is_right_open(self) -> bool {
+        !self.is_empty() && matches!(self.1, Right(Open(_)))
+    }
+
+    /// Check that interval has no NaN endpoint and that its bounds are not reversed
+    ///
+    /// Constructors of the crate only build valid intervals.
+    ///
+    pub fn is_valid(self) -> bool {
+        let Interval(left, right) = self;
+        !left.0.is_nan() && !right.0.is_nan() && (self.is_empty() || right >= left)
+    }
+
+    /// Check if point `x` belongs to interval
+    ///
+    /// NaN belongs to no interval.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Open, Closed};
+    ///
+    /// let a = Interval::new(Open(42.), Closed(43.));
+    ///
+    /// assert!(!a.contains(42.));
+    /// assert!(a.contains(43.));
+    /// ```
+    ///
+    pub fn contains(self, x: f64) -> bool {
+        if self.is_empty() || x.is_nan() {
+            return false;
+        }
+        let Interval(Left(left), Right(right)) = self;
+        let above = match left {
+            Closed(k) => x >= k,
+            Open(k) => x > k,
+            Unbound => true,
+        };
+        let below = match right {
+            Closed(k) => x <= k,
+            Open(k) => x < k,
+            Unbound => true,
+        };
+        above && below
+    }
+
+    /// Nearest point of interval to `x`, `None` if interval is empty or `x` is NaN
+    ///
+    /// Points outside a closed endpoint are moved onto it. An open endpoint doesn't belong
+    /// to interval, so points outside it are moved onto the nearest float inside instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Open, Closed, EMPTY};
+    ///
+    /// let a = Interval::new(Closed(0.), Open(100.));
+    ///
+    /// assert_eq!(a.clamp(42.), Some(42.));
+    /// assert_eq!(a.clamp(-3.), Some(0.));
+    /// assert_eq!(a.clamp(250.), Some(99.99999999999999));
+    /// assert_eq!(EMPTY.clamp(1.), None);
+    /// ```
+    ///
+    pub fn clamp(self, x: f64) -> Option<f64> {
+        if self.is_empty() || x.is_nan() {
+            return None;
+        }
+        let Interval(Left(left), Right(right)) = self;
+        let lower = match left {
+            Closed(k) => k,
+            Open(k) => next_up(k),
+            Unbound => f64::NEG_INFINITY,
+        };
+        let upper = match right {
+            Closed(k) => k,
+            Open(k) => next_down(k),
+            Unbound => f64::INFINITY,
+        };
+        // An open interval between consecutive floats holds no float at all
+        (lower <= upper).then(|| x.clamp(lower, upper))
+    }
+
+    /// Distance from `x` to interval, zero if `x` belongs to it or to its closure
+    ///
+    /// The distance to the empty set is infinite.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Open, Closed};
+    ///
+    /// let window = Interval::new(Closed(9.), Open(17.));
+    ///
+    /// assert_eq!(window.distance_to_point(8.5), 0.5);
+    /// assert_eq!(window.distance_to_point(12.), 0.);
+    /// assert_eq!(window.distance_to_point(20.), 3.);
+    /// ```
+    ///
+    pub fn distance_to_point(self, x: f64) -> f64 {
+        match self.endpoints() {
+            None => f64::INFINITY,
+            Some((lower, _)) if x < lower => lower - x,
+            Some((_, upper)) if x > upper => x - upper,
+            Some(_) if x.is_nan() => f64::NAN,
+            Some(_) => 0.,
+        }
+    }
+
+    /// Width of the gap between intervals, zero if they overlap or touch
+    ///
+    /// The distance to the empty set is infinite.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Open, Closed};
+    ///
+    /// let a = Interval::new(Closed(0.), Open(1.));
+    ///
+    /// assert_eq!(a.distance_to(Interval::new(Closed(3.), Closed(4.))), 2.);
+    /// assert_eq!(a.distance_to(Interval::new(Open(1.), Closed(4.))), 0.);
+    /// ```
+    ///
+    pub fn distance_to(self, other: Interval) -> f64 {
+        match (self.endpoints(), other.endpoints()) {
+            (Some((l1, u1)), Some((l2, u2))) => (l2 - u1).max(l1 - u2).max(0.),
+            _ => f64::INFINITY,
+        }
+    }
+
+    /// Check if every point of `points` belongs to interval, stopping at the first one out
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Open, Closed};
+    ///
+    /// let a = Interval::new(Open(0.), Closed(1.));
+    ///
+    /// assert!(a.contains_all([0.5, 1.]));
+    /// assert!(!a.contains_all([0.5, 0.]));
+    /// assert!(a.contains_any([0., 1.]));
+    /// ```
+    ///
+    pub fn contains_all(self, points: impl IntoIterator<Item = f64>) -> bool {
+        points.into_iter().all(|x| self.contains(x))
+    }
+
+    /// Check if some point of `points` belongs to interval, stopping at the first one in
+    ///
+    pub fn contains_any(self, points: impl IntoIterator<Item = f64>) -> bool {
+        points.into_iter().any(|x| self.contains(x))
+    }
+
+    /// Number of `points` belonging to interval
+    ///
+    /// See `count_contained_sorted` for sorted points.
+    ///
+    pub fn count_contained(self, points: &[f64]) -> usize {
+        points.iter().filter(|&&x| self.contains(x)).count()
+    }
+
+    /// Number of `points`, sorted in increasing order, belonging to interval
+    ///
+    /// Runs in `O(log n)` with two binary searches. The result is unspecified if `points` are
+    /// not sorted.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Open, Closed};
+    ///
+    /// let a = Interval::new(Open(1.), Closed(3.));
+    /// let points = [0., 1., 1.5, 2., 3., 4.];
+    ///
+    /// assert_eq!(a.count_contained_sorted(&points), 3);
+    /// assert_eq!(a.count_contained_sorted(&points), a.count_contained(&points));
+    /// ```
+    ///
+    pub fn count_contained_sorted(self, points: &[f64]) -> usize {
+        if self.is_empty() {
+            return 0;
+        }
+        let Interval(Left(left), Right(right)) = self;
+        let start = match left {
+            Closed(k) => points.partition_point(|&x| x < k),
+            Open(k) => points.partition_point(|&x| x <= k),
+            Unbound => 0,
+        };
+        let end = match right {
+            Closed(k) => points.partition_point(|&x| x <= k),
+            Open(k) => points.partition_point(|&x| x < k),
+            Unbound => points.len(),
+        };
+        end.saturating_sub(start)
+    }
+
+    /// Split `points` into those inside and those outside interval, keeping their order
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Open, Closed};
+    ///
+    /// let a = Interval::new(Closed(0.), Open(1.));
+    ///
+    /// assert_eq!(
+    ///     a.partition_points(&[0.5, 1., -1., 0.]),
+    ///     (vec![0.5, 0.], vec![1., -1.])
+    /// );
+    /// ```
+    ///
+    pub fn partition_points(self, points: &[f64]) -> (Vec<f64>, Vec<f64>) {
+        points.iter().partition(|&&x| self.contains(x))
+    }
+
+    /// Check membership of every point of `points` into `out`
+    ///
+    /// Bounds are turned into a pair of inclusive comparisons applied branchlessly over
+    /// fixed-size chunks, a loop the compiler vectorizes. Results match `contains`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `points` and `out` differ in length.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Open, Closed};
+    ///
+    /// let a = Interval::new(Open(0.), Closed(1.));
+    /// let mut out = [false; 4];
+    ///
+    /// a.contains_simd(&[0., 0.5, 1., 2.], &mut out);
+    /// assert_eq!(out, [false, true, true, false]);
+    /// ```
+    ///
+    #[cfg(feature = "simd")]
+    pub fn contains_simd(&self, points: &[f64], out: &mut [bool]) {
+        const LANES: usize = 8;
+
+        assert_eq!(points.len(), out.len(), "points and out differ in length");
+        let (lower, upper) = match *self {
+            _ if self.is_empty() => (f64::INFINITY, f64::NEG_INFINITY),
+            Interval(Left(left), Right(right)) => {
+                let lower = match left {
+                    Closed(k) => k,
+                    Open(k) => next_up(k),
+                    Unbound => f64::NEG_INFINITY,
+                };
+                let upper = match right {
+                    Closed(k) => k,
+                    Open(k) => next_down(k),
+                    Unbound => f64::INFINITY,
+                };
+                (lower, upper)
+            }
+        };
+
+        let mut chunks = points.chunks_exact(LANES);
+        let mut outs = out.chunks_exact_mut(LANES);
+        for (xs, os) in (&mut chunks).zip(&mut outs) {
+            for i in 0..LANES {
+                os[i] = (xs[i] >= lower) & (xs[i] <= upper);
+            }
+        }
+        for (x, o) in chunks.remainder().iter().zip(outs.into_remainder()) {
+            *o = (*x >= lower) & (*x <= upper);
+        }
+    }
+
+    /// Check if `other` is a subset of interval
+    ///
+    /// The empty set is a subset of every interval.
+    ///
+    pub fn contains_interval(self, other: Interval) -> bool {
+        other.is_empty() || self.intersection(other) == other
+    }
+
+    /// Check if intervals have no point in common
+    ///
+    /// Adjacent intervals such as `[0, 1)` and `[1, 2]` are disjoint, although their union is
+    /// a single interval: see `is_separated_from` to tell them apart. The empty set is
+    /// disjoint from every interval.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Open, Closed};
+    ///
+    /// let a = Interval::new(Closed(0.), Open(1.));
+    ///
+    /// assert!(a.is_disjoint(Interval::new(Closed(1.), Closed(2.))));
+    /// assert!(!a.is_disjoint(Interval::new(Closed(0.5), Closed(2.))));
+    /// ```
+    ///
+    pub fn is_disjoint(self, other: Interval) -> bool {
+        self.intersection(other).is_empty()
+    }
+
+    /// Check if intervals are disjoint and not adjacent, so that a gap lies between them
+    ///
+    /// Unlike `is_disjoint`, touching intervals such as `[0, 1)` and `[1, 2]` are not
+    /// separated, their union being a single interval. The empty set is separated from every
+    /// interval.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Open, Closed};
+    ///
+    /// let a = Interval::new(Closed(0.), Open(1.));
+    ///
+    /// assert!(!a.is_separated_from(Interval::new(Closed(1.), Closed(2.))));
+    /// assert!(a.is_separated_from(Interval::new(Open(1.), Closed(2.))));
+    /// ```
+    ///
+    pub fn is_separated_from(self, other: Interval) -> bool {
+        self.is_disjoint(other) && !self.is_adjacent_to(other)
+    }
+
+    /// Check if std range `r` is a subset of interval
+    ///
+    /// Included, excluded and unbounded range ends map to closed, open and unbound bounds.
+    /// An empty range is a subset of every interval.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Open, Closed};
+    ///
+    /// let a = Interval::new(Closed(0.), Open(1.));
+    ///
+    /// assert!(a.contains_range(0.0..1.0));
+    /// assert!(a.contains_range(0.2..=0.8));
+    /// assert!(!a.contains_range(0.0..=1.0));
+    /// assert!(!a.contains_range(0.5..));
+    /// ```
+    ///
+    pub fn contains_range(self, r: impl RangeBounds<f64>) -> bool {
+        self.contains_interval(Interval::from_range_bounds(r))
+    }
+
+    /// Convert half-open interval `[a, b)` to std range `a..b`
+    ///
+    /// Returns `None` for any other shape of interval.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Open, Closed};
+    ///
+    /// assert_eq!(Interval::new(Closed(0.), Open(1.)).as_range(), Some(0.0..1.0));
+    /// assert_eq!(Interval::new(Closed(0.), Closed(1.)).as_range(), None);
+    /// ```
+    ///
+    pub fn as_range(self) -> Option<Range<f64>> {
+        match self {
+            Interval(Left(Closed(a)), Right(Open(b))) => Some(a..b),
+            _ => None,
+        }
+    }
+
+    /// Convert closed interval `[a, b]` to std range `a..=b`
+    ///
+    /// Returns `None` for any other shape of interval.
+    ///
+    pub fn as_range_inclusive(self) -> Option<RangeInclusive<f64>> {
+        match self {
+            Interval(Left(Closed(a)), Right(Closed(b))) => Some(a..=b),
+            _ => None,
+        }
+    }
+
+    /// Compute union of intervals
+    ///
+    /// Returns a single interval when intervals overlap or are adjacent, both intervals otherwise,
+    /// the lower one first. `IntervalSet` relies on this shape to merge its segments.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Open, Closed};
+    ///
+    /// let a = Interval::new(Closed(0.), Open(1.));
+    /// let b = Interval::new(Closed(1.), Closed(2.));
+    /// let c = Interval::new(Closed(5.), Closed(6.));
+    ///
+    /// assert_eq!(a.union(b), (Interval::new(Closed(0.), Closed(2.)), None));
+    /// assert_eq!(c.union(a), (a, Some(c)));
+    /// ```
+    ///
+    pub fn union(self, other: Interval) -> (Interval, Option<Interval>) {
+        match (self, other) {
+            (a, Interval(Left(Open(k1)), Right(Open(k2))))
+            | (Interval(Left(Open(k1)), Right(Open(k2))), a)
+                if k1 == k2 =>
+            {
+                (a, None)
+            }
+            (Interval(Left(Unbound), Right(Unbound)), _)
+            | (_, Interval(Left(Unbound), Right(Unbound))) => {
+                (Interval(Left(Unbound), Right(Unbound)), None)
+            }
+
+            (Interval(a1, a2), Interval(b1, b2)) => {
+                if self.overlaps(other) || self.is_adjacent_to(other) {
+                    (Interval(a1.min(b1), a2.max(b2)).checked(), None)
+                } else if b1 > a2 {
+                    (self, Some(other))
+                } else {
+                    (other, Some(self))
+                }
+            }
+        }
+    }
+
+    /// Union of any number of intervals, given in any order, as a set
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, IntervalSet, Open, Closed};
+    ///
+    /// let windows = vec![
+    ///     Interval::new(Closed(5.), Closed(6.)),
+    ///     Interval::new(Closed(1.), Open(2.)),
+    ///     Interval::new(Closed(0.), Open(1.)),
+    /// ];
+    ///
+    /// assert_eq!(Interval::union_many(windows), IntervalSet::from(&[
+    ///     Interval::new(Closed(0.), Open(2.)),
+    ///     Interval::new(Closed(5.), Closed(6.)),
+    /// ]));
+    /// ```
+    ///
+    pub fn union_many(intervals: impl IntoIterator<Item = Interval>) -> IntervalSet {
+        IntervalSet::from_unsorted(intervals.into_iter().collect())
+    }
+
+    /// Compute intersection of intervals
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Open, Closed, EMPTY};
+    ///
+    /// let a = Interval::new(Closed(0.), Closed(10.));
+    /// let b = Interval::new(Open(5.), Closed(20.));
+    /// let c = Interval::new(Closed(15.), Closed(20.));
+    ///
+    /// assert_eq!(a.intersection(b), Interval::new(Open(5.), Closed(10.)));
+    /// assert_eq!(a.intersection(c), EMPTY);
+    /// ```
+    ///
+    pub fn intersection(self, other: Interval) -> Interval {
+        if self.is_empty() || other.is_empty() {
+            return EMPTY;
+        }
+        let (Interval(a1, a2), Interval(b1, b2)) = (self, other);
+        let (Left(left), Right(right)) = (a1.max(b1), a2.min(b2));
+        Interval::new(left, right)
+    }
+
+    /// Intersection of any number of intervals, `INFINITY` if there is none
+    ///
+    /// Stops at the first empty intersection.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Open, Closed, Unbound, EMPTY, INFINITY};
+    ///
+    /// let constraints = vec![
+    ///     Interval::new(Closed(0.), Unbound),
+    ///     Interval::new(Unbound, Open(10.)),
+    ///     Interval::new(Closed(2.), Closed(50.)),
+    /// ];
+    ///
+    /// assert_eq!(Interval::intersect_many(constraints), Interval::new(Closed(2.), Open(10.)));
+    /// assert_eq!(Interval::intersect_many([]), INFINITY);
+    /// ```
+    ///
+    pub fn intersect_many(intervals: impl IntoIterator<Item = Interval>) -> Interval {
+        let mut res = INFINITY;
+        for interval in intervals {
+            res = res.intersection(interval);
+            if res.is_empty() {
+                return EMPTY;
+            }
+        }
+        res
+    }
+
+    /// Compute difference of intervals, points of interval not in `other`
+    ///
+    /// Returns a single interval (possibly empty) when `other` doesn't split interval, both
+    /// pieces otherwise, the lower one first, in the same way as `union`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Open, Closed, EMPTY};
+    ///
+    /// let a = Interval::new(Closed(0.), Closed(10.));
+    /// let b = Interval::new(Closed(4.), Open(6.));
+    ///
+    /// assert_eq!(a.difference(b), (
+    ///     Interval::new(Closed(0.), Open(4.)),
+    ///     Some(Interval::new(Closed(6.), Closed(10.))),
+    /// ));
+    /// assert_eq!(a.difference(Interval::new(Open(5.), Closed(20.))), (
+    ///     Interval::new(Closed(0.), Closed(5.)),
+    ///     None,
+    /// ));
+    /// assert_eq!(b.difference(a), (EMPTY, None));
+    /// ```
+    ///
+    pub fn difference(self, other: Interval) -> (Interval, Option<Interval>) {
+        let Some((s1, e1)) = self.edges() else {
+            return (EMPTY, None);
+        };
+        let Some((s2, e2)) = other.edges() else {
+            return (self, None);
+        };
+        let piece = |start: Edge, end: Edge| {
+            if start < end {
+                Interval::from_edges(start, end)
+            } else {
+                EMPTY
+            }
+        };
+        let lower = piece(s1, e1.min(s2));
+        let upper = piece(s1.max(e2), e1);
+        match (lower.is_empty(), upper.is_empty()) {
+            (false, false) => (lower, Some(upper)),
+            (false, true) => (lower, None),
+            (true, _) => (upper, None),
+        }
+    }
+
+    /// Split interval into its parts below and above `x`, `x` itself going to `side`
+    ///
+    /// Either part may be empty. A NaN split point leaves the whole interval below.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Open, Closed, Side, EMPTY};
+    ///
+    /// let a = Interval::new(Closed(0.), Closed(10.));
+    ///
+    /// assert_eq!(a.split_at(4., Side::Upper), (
+    ///     Interval::new(Closed(0.), Open(4.)),
+    ///     Interval::new(Closed(4.), Closed(10.)),
+    /// ));
+    /// assert_eq!(a.split_at(4., Side::Lower), (
+    ///     Interval::new(Closed(0.), Closed(4.)),
+    ///     Interval::new(Open(4.), Closed(10.)),
+    /// ));
+    /// assert_eq!(a.split_at(20., Side::Lower), (a, EMPTY));
+    /// ```
+    ///
+    pub fn split_at(self, x: f64, side: Side) -> (Interval, Interval) {
+        let Some((start, end)) = self.edges() else {
+            return (EMPTY, EMPTY);
+        };
+        let split = Edge {
+            value: x,
+            after: side == Side::Lower,
+        };
+        let piece = |start: Edge, end: Edge| {
+            if start < end {
+                Interval::from_edges(start, end)
+            } else {
+                EMPTY
+            }
+        };
+        (piece(start, end.min(split)), piece(start.max(split), end))
+    }
+
+    /// Interval strictly separating two disjoint intervals, `None` if they overlap or touch
+    ///
+    /// Bounds of the gap are the complements of the facing bounds: it is open next to a
+    /// closed endpoint and closed next to an open one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Open, Closed};
+    ///
+    /// let a = Interval::new(Closed(0.), Closed(10.));
+    /// let b = Interval::new(Closed(15.), Closed(20.));
+    ///
+    /// assert_eq!(a.gap_between(b), Some(Interval::new(Open(10.), Open(15.))));
+    /// assert_eq!(b.gap_between(a), a.gap_between(b));
+    /// assert_eq!(a.gap_between(Interval::new(Open(10.), Closed(20.))), None);
+    /// ```
+    ///
+    pub fn gap_between(self, other: Interval) -> Option<Interval> {
+        if self.is_empty() || other.is_empty() {
+            return None;
+        }
+        match self.union(other) {
+            (lower, Some(upper)) => {
+                let ((_, end), (start, _)) = (lower.edges()?, upper.edges()?);
+                Some(Interval::from_edges(end, start))
+            }
+            (_, None) => None,
+        }
+    }
+
+    /// Check if endpoints of intervals are within `eps` of each other, with the same
+    /// openness
+    ///
+    /// Unbound endpoints only match unbound endpoints, and the empty set only matches
+    /// itself.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Open, Closed};
+    ///
+    /// let a = Interval::new(Closed(0.), Open(0.1 + 0.2));
+    ///
+    /// assert!(a.approx_eq(Interval::new(Closed(0.), Open(0.3)), 1e-9));
+    /// assert!(!a.approx_eq(Interval::new(Closed(0.), Closed(0.3)), 1e-9));
+    /// assert!(a.approx_eq_ignore_openness(Interval::new(Closed(0.), Closed(0.3)), 1e-9));
+    /// ```
+    ///
+    pub fn approx_eq(self, other: Interval, eps: f64) -> bool {
+        self.approx_eq_with(other, eps, true)
+    }
+
+    /// Check if endpoints of intervals are within `eps` of each other, regardless of their
+    /// openness
+    ///
+    /// See `approx_eq`.
+    ///
+    pub fn approx_eq_ignore_openness(self, other: Interval, eps: f64) -> bool {
+        self.approx_eq_with(other, eps, false)
+    }
+
+    fn approx_eq_with(self, other: Interval, eps: f64, same_openness: bool) -> bool {
+        let close = |a: Bound, b: Bound| match (a, b) {
+            (Closed(k1) | Open(k1), Closed(k2) | Open(k2)) => {
+                (!same_openness || std::mem::discriminant(&a) == std::mem::discriminant(&b))
+                    && (k1 == k2 || (k1 - k2).abs() <= eps)
+            }
+            (Unbound, Unbound) => true,
+            _ => false,
+        };
+        match (self.is_empty(), other.is_empty()) {
+            (true, true) => true,
+            (false, false) => {
+                let ((a1, a2), (b1, b2)) = (self.into_bounds(), other.into_bounds());
+                close(a1, b1) && close(a2, b2)
+            }
+            _ => false,
+        }
+    }
+
+    /// Split interval into its parts covered and not covered by `set`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, IntervalSet, Closed, Open};
+    ///
+    /// let day = Interval::new(Closed(0.), Open(24.));
+    /// let tested = IntervalSet::from(&[
+    ///     Interval::new(Closed(-2.), Open(8.)),
+    ///     Interval::new(Closed(12.), Open(14.)),
+    /// ]);
+    ///
+    /// let (covered, uncovered) = day.partition_by(&tested);
+    ///
+    /// assert_eq!(covered, IntervalSet::from(&[
+    ///     Interval::new(Closed(0.), Open(8.)),
+    ///     Interval::new(Closed(12.), Open(14.)),
+    /// ]));
+    /// assert_eq!(uncovered, IntervalSet::from(&[
+    ///     Interval::new(Closed(8.), Open(12.)),
+    ///     Interval::new(Closed(14.), Open(24.)),
+    /// ]));
+    /// ```
+    ///
+    pub fn partition_by(self, set: &IntervalSet) -> (IntervalSet, IntervalSet) {
+        let (mut covered, mut uncovered) = (Vec::new(), Vec::new());
+        if let Some((start, end)) = self.edges() {
+            // Sweep segments from the start of interval, tracking the end of covered parts
+            let segments = set.as_slice();
+            let first = segments.partition_point(|segment| {
+                segment.edges().is_some_and(|(_, upper)| upper <= start)
+            });
+            let mut cursor = start;
+            for segment in &segments[first..] {
+                let (lower, upper) = segment.edges().unwrap();
+                if lower >= end {
+                    break;
+                }
+                let (lower, upper) = (lower.max(start), upper.min(end));
+                if cursor < lower {
+                    uncovered.push(Interval::from_edges(cursor, lower));
+                }
+                covered.push(Interval::from_edges(lower, upper));
+                cursor = upper;
+            }
+            if cursor < end {
+                uncovered.push(Interval::from_edges(cursor, end));
+            }
+        }
+        (
+            IntervalSet::from_unsorted(covered),
+            IntervalSet::from_unsorted(uncovered),
+        )
+    }
+
+    /// Compute the smallest interval containing both intervals
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Open, Closed};
+    ///
+    /// let a = Interval::new(Closed(0.), Closed(10.));
+    /// let b = Interval::new(Open(15.), Open(20.));
+    ///
+    /// assert_eq!(a.hull(b), Interval::new(Closed(0.), Open(20.)));
+    /// ```
+    ///
+    pub fn hull(self, other: Interval) -> Interval {
+        if self.is_empty() {
+            return other;
+        }
+        if other.is_empty() {
+            return self;
+        }
+        let (Interval(a1, a2), Interval(b1, b2)) = (self, other);
+        let (Left(left), Right(right)) = (a1.min(b1), a2.max(b2));
+        Interval::new(left, right)
+    }
+
+    /// Widening of `previous` by `self`, for abstract interpretation fixpoints
+    ///
+    /// Each endpoint of `self` going beyond the matching endpoint of `previous` jumps to the
+    /// nearest threshold (as a closed endpoint) containing it, or to infinity if there is
+    /// none; stable endpoints of `previous` are kept. Repeated widening thus terminates.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Closed, Unbound};
+    ///
+    /// let previous = Interval::new(Closed(0.), Closed(1.));
+    /// let next = Interval::new(Closed(0.), Closed(2.));
+    ///
+    /// assert_eq!(next.widen(previous, &[]), Interval::new(Closed(0.), Unbound));
+    /// assert_eq!(next.widen(previous, &[-1., 100.]), Interval::new(Closed(0.), Closed(100.)));
+    /// ```
+    ///
+    pub fn widen(self, previous: Interval, thresholds: &[f64]) -> Interval {
+        if previous.is_empty() {
+            return self;
+        }
+        if self.is_empty() {
+            return previous;
+        }
+        let thresholds = || thresholds.iter().copied().filter(|t| t.is_finite());
+        let (Interval(p1, p2), Interval(a1, a2)) = (previous, self);
+        let left = if a1 < p1 {
+            thresholds()
+                .filter(|t| Left(Closed(*t)) <= a1)
+                .max_by(f64::total_cmp)
+                .map_or(Unbound, Closed)
+        } else {
+            p1.0
+        };
+        let right = if a2 > p2 {
+            thresholds()
+                .filter(|t| Right(Closed(*t)) >= a2)
+                .min_by(f64::total_cmp)
+                .map_or(Unbound, Closed)
+        } else {
+            p2.0
+        };
+        Interval::new(left, right)
+    }
+
+    /// Narrowing of `self` by `other`, refining the unbound endpoints of a widened interval
+    ///
+    /// Unbound endpoints of `self` are replaced by those of `other`, other endpoints are
+    /// kept.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Closed, Open, Unbound};
+    ///
+    /// let widened = Interval::new(Closed(0.), Unbound);
+    ///
+    /// assert_eq!(
+    ///     widened.narrow(Interval::new(Closed(0.), Open(100.))),
+    ///     Interval::new(Closed(0.), Open(100.))
+    /// );
+    /// ```
+    ///
+    pub fn narrow(self, other: Interval) -> Interval {
+        if self.is_empty() || other.is_empty() {
+            return EMPTY;
+        }
+        let ((a1, a2), (b1, b2)) = (self.into_bounds(), other.into_bounds());
+        let refine = |a: Bound, b: Bound| if matches!(a, Unbound) { b } else { a };
+        Interval::new(refine(a1, b1), refine(a2, b2))
+    }
+
+    /// Split interval into `n` bins of equal width
+    ///
+    /// See `Partition::equal_width`.
+    ///
+    pub fn bins(self, n: usize) -> Result<Partition, IntervalError> {
+        Partition::equal_width(self, n)
+    }
+
+    /// Split bounded interval into `n` contiguous sub-intervals of equal width
+    ///
+    /// Sub-intervals are half-open `[a, b)`, except the first and last ones which keep the
+    /// bounds of interval. Fails if interval can't be split (see `bins`).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Closed, Open};
+    ///
+    /// let a = Interval::new(Closed(0.), Closed(1.));
+    ///
+    /// assert_eq!(a.partition_equal(2), Ok(vec![
+    ///     Interval::new(Closed(0.), Open(0.5)),
+    ///     Interval::new(Closed(0.5), Closed(1.)),
+    /// ]));
+    /// ```
+    ///
+    pub fn partition_equal(self, n: usize) -> Result<Vec<Interval>, IntervalError> {
+        self.bins(n).map(|partition| partition.bins().to_vec())
+    }
+
+    /// Split interval at given breakpoints into contiguous sub-intervals
+    ///
+    /// Sub-intervals are half-open `[a, b)`, except the first and last ones which keep the
+    /// bounds of interval. Breakpoints may be unsorted; those not lying strictly inside
+    /// interval are ignored.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Closed, Open, Unbound};
+    ///
+    /// let a = Interval::new(Closed(0.), Unbound);
+    ///
+    /// assert_eq!(a.partition_at(&[10., 5., 0., -5.]), vec![
+    ///     Interval::new(Closed(0.), Open(5.)),
+    ///     Interval::new(Closed(5.), Open(10.)),
+    ///     Interval::new(Closed(10.), Unbound),
+    /// ]);
+    /// ```
+    ///
+    pub fn partition_at(self, breakpoints: &[f64]) -> Vec<Interval> {
+        let Some((start, end)) = self.edges() else {
+            return Vec::new();
+        };
+        let mut edges: Vec<_> = breakpoints
+            .iter()
+            .filter(|&&value| start.value < value && value < end.value)
+            .map(|&value| Edge {
+                value,
+                after: false,
+            })
+            .collect();
+        edges.sort();
+        edges.dedup();
+        edges.insert(0, start);
+        edges.push(end);
+        edges
+            .windows(2)
+            .map(|w| Interval::from_edges(w[0], w[1]))
+            .collect()
+    }
+
+    /// Move endpoints to multiples of `step`, keeping their openness
+    ///
+    /// `mode` rounds endpoints to the nearest multiple, or moves them outward or inward.
+    /// Interval is returned unchanged unless `step` is positive.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, SnapMode, Closed, Open};
+    ///
+    /// let a = Interval::new(Closed(0.3), Open(1.6));
+    ///
+    /// assert_eq!(a.snap(0.5, SnapMode::Expand), Interval::new(Closed(0.), Open(2.)));
+    /// assert_eq!(a.snap(0.5, SnapMode::Shrink), Interval::new(Closed(0.5), Open(1.5)));
+    /// ```
+    ///
+    pub fn snap(self, step: f64, mode: SnapMode) -> Interval {
+        if self.is_empty() || step.is_nan() || step <= 0. {
+            return self;
+        }
+        let lower: fn(f64) -> f64 = match mode {
+            SnapMode::Round => f64::round,
+            SnapMode::Expand => f64::floor,
+            SnapMode::Shrink => f64::ceil,
+        };
+        let upper: fn(f64) -> f64 = match mode {
+            SnapMode::Round => f64::round,
+            SnapMode::Expand => f64::ceil,
+            SnapMode::Shrink => f64::floor,
+        };
+        let snap = |bound, f: fn(f64) -> f64| match bound {
+            Closed(k) => Closed(f(k / step) * step),
+            Open(k) => Open(f(k / step) * step),
+            Unbound => Unbound,
+        };
+        let Interval(Left(left), Right(right)) = self;
+        Interval::new(snap(left, lower), snap(right, upper))
+    }
+
+    /// Iterate over integers of interval in increasing order, respecting open endpoints
+    ///
+    /// Integers out of the `i64` range are skipped, so that unbound endpoints saturate to
+    /// `i64::MIN` / `i64::MAX`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Closed, Open};
+    ///
+    /// let a = Interval::new(Open(-1.), Closed(2.5));
+    ///
+    /// assert_eq!(a.integers().collect::<Vec<_>>(), vec![0, 1, 2]);
+    /// ```
+    ///
+    pub fn integers(self) -> impl Iterator<Item = i64> {
+        self.integer_range()
+            .into_iter()
+            .flat_map(|(lower, upper)| lower..=upper)
+    }
+
+    /// Iterate over `n` evenly spaced points of bounded interval
+    ///
+    /// Closed endpoints are the first and last points, open endpoints are left out (like
+    /// numpy's `linspace` with `endpoint=False`). Yields nothing for empty or unbounded
+    /// intervals.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Closed, Open};
+    ///
+    /// let a = Interval::new(Closed(0.), Closed(1.));
+    /// let b = Interval::new(Closed(0.), Open(1.));
+    ///
+    /// assert_eq!(a.linspace(5).collect::<Vec<_>>(), vec![0., 0.25, 0.5, 0.75, 1.]);
+    /// assert_eq!(b.linspace(4).collect::<Vec<_>>(), vec![0., 0.25, 0.5, 0.75]);
+    /// ```
+    ///
+    pub fn linspace(self, n: usize) -> impl Iterator<Item = f64> {
+        let (a, b, skip, divisions, len) = match self {
+            Interval(Left(Closed(a) | Open(a)), Right(Closed(b) | Open(b))) if !self.is_empty() => {
+                let skip = matches!(self.0, Left(Open(_))) as usize;
+                let end = matches!(self.1, Right(Open(_))) as usize;
+                (a, b, skip, (n + skip + end).saturating_sub(1), n)
+            }
+            _ => (0., 0., 0, 0, 0),
+        };
+        (0..len).map(move |i| match i + skip {
+            0 => a,
+            k if k == divisions => b,
+            k => a + (b - a) * k as f64 / divisions as f64,
+        })
+    }
+
+    /// Iterate endlessly over quasi-random points of bounded interval (feature
+    /// `low-discrepancy`)
+    ///
+    /// Points follow the additive recurrence `t(k+1) = t(k) + 1/φ (mod 1)`, φ being the
+    /// golden ratio, starting from an offset derived from `seed`. Any prefix of the sequence
+    /// covers interval evenly, and the same seed always gives the same points. Points
+    /// rounded onto an open endpoint are moved inside as `clamp` does. Yields nothing for
+    /// empty or unbounded intervals, nor for open intervals holding no float.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Closed, Open};
+    ///
+    /// let a = Interval::new(Closed(0.), Open(10.));
+    /// let points: Vec<_> = a.quasi_random(42).take(100).collect();
+    ///
+    /// assert!(points.iter().all(|&x| a.contains(x)));
+    /// assert_eq!(points, a.quasi_random(42).take(100).collect::<Vec<_>>());
+    /// ```
+    ///
+    #[cfg(feature = "low-discrepancy")]
+    pub fn quasi_random(self, seed: u64) -> impl Iterator<Item = f64> {
+        const ALPHA: f64 = 0.618_033_988_749_894_9;
+
+        // spread seeds over [0, 1) with a Fibonacci hash
+        let offset = (seed.wrapping_mul(0x9E37_79B9_7F4A_7C15) >> 11) as f64 / (1u64 << 53) as f64;
+        self.endpoints()
+            .filter(|(a, b)| a.is_finite() && b.is_finite())
+            .into_iter()
+            .flat_map(move |(a, b)| {
+                std::iter::successors(Some(offset), |t| Some((t + ALPHA).fract()))
+                    // b - a may overflow, a (1 - t) + b t can't
+                    .map(move |t| a * (1. - t) + b * t)
+            })
+            .map_while(move |x| self.clamp(x))
+    }
+
+    /// Normalized position of `x` within bounded interval, `0` at lower endpoint and `1` at
+    /// upper endpoint
+    ///
+    /// Positions out of interval are not clamped (see `inv_lerp_clamped`). Returns `None`
+    /// for empty, singleton or unbounded intervals.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Closed, Open};
+    ///
+    /// let a = Interval::new(Closed(10.), Open(20.));
+    ///
+    /// assert_eq!(a.inv_lerp(12.5), Some(0.25));
+    /// assert_eq!(a.inv_lerp(30.), Some(2.));
+    /// assert_eq!(a.inv_lerp_clamped(30.), Some(1.));
+    /// ```
+    ///
+    pub fn inv_lerp(self, x: f64) -> Option<f64> {
+        let (a, b) = self.endpoints()?;
+        (a.is_finite() && b.is_finite() && a < b).then(|| (x - a) / (b - a))
+    }
+
+    /// Normalized position of `x` clamped to `[0, 1]`
+    ///
+    /// See `inv_lerp`.
+    ///
+    pub fn inv_lerp_clamped(self, x: f64) -> Option<f64> {
+        self.inv_lerp(x).map(|t| t.clamp(0., 1.))
+    }
+
+    /// Intersection over union: width of intersection divided by width of union
+    ///
+    /// Gives `1` for equal intervals and `0` for disjoint intervals. Intervals of zero width
+    /// give `1` when equal and `0` otherwise; an unbounded intersection gives NaN.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Closed};
+    ///
+    /// let truth = Interval::new(Closed(0.), Closed(10.));
+    /// let detected = Interval::new(Closed(5.), Closed(15.));
+    ///
+    /// assert_eq!(truth.iou(detected), 1. / 3.);
+    /// ```
+    ///
+    pub fn iou(self, other: Interval) -> f64 {
+        let common = self.intersection(other).width();
+        if common.is_infinite() {
+            return f64::NAN;
+        }
+        let union = self.width() + other.width() - common;
+        if union > 0. {
+            common / union
+        } else if self == other && !self.is_empty() {
+            1.
+        } else {
+            0.
+        }
+    }
+
+    /// Probability mass of interval under the cumulative distribution function `cdf`
+    ///
+    /// Gives `cdf(b) - cdf(a)`, `cdf` being taken as `0` at `-∞` and `1` at `+∞` without
+    /// being called there. Openness is ignored, as for a continuous distribution.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Closed, Unbound};
+    ///
+    /// let exponential = |x: f64| if x > 0. { 1. - (-x).exp() } else { 0. };
+    ///
+    /// assert_eq!(Interval::new(Unbound, Closed(1.)).probability(exponential), 1. - (-1f64).exp());
+    /// assert_eq!(Interval::new(Closed(1.), Unbound).probability(exponential), (-1f64).exp());
+    /// ```
+    ///
+    pub fn probability(self, cdf: impl Fn(f64) -> f64) -> f64 {
+        match self.endpoints() {
+            None => 0.,
+            Some((a, b)) => {
+                let upper = if b == f64::INFINITY { 1. } else { cdf(b) };
+                let lower = if a == f64::NEG_INFINITY { 0. } else { cdf(a) };
+                upper - lower
+            }
+        }
+    }
+
+    /// Shrink bounded interval around a root of `f` by bisection, down to width `tol`
+    ///
+    /// `f` must change sign (or vanish) between the endpoints of interval, which are
+    /// evaluated as if closed. The returned bracket is closed; it is narrower than `tol`
+    /// only if floating point precision allows it. Returns `None` if interval is empty or
+    /// unbounded, or if `f` doesn't change sign.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Closed};
+    ///
+    /// let bracket = Interval::new(Closed(1.), Closed(2.))
+    ///     .bisect_root(|x| x * x - 2., 1e-9)
+    ///     .unwrap();
+    ///
+    /// let root = 2f64.sqrt();
+    ///
+    /// assert!(bracket.contains(root));
+    /// assert!(Interval::new(Closed(root - 1e-9), Closed(root + 1e-9)).contains_interval(bracket));
+    /// ```
+    ///
+    pub fn bisect_root(self, f: impl Fn(f64) -> f64, tol: f64) -> Option<Interval> {
+        let (mut lo, mut hi) = self.endpoints()?;
+        if !(lo.is_finite() && hi.is_finite()) {
+            return None;
+        }
+        let (f_lo, f_hi) = (f(lo), f(hi));
+        if f_lo == 0. {
+            return Some(Interval::singleton(lo));
+        }
+        if f_hi == 0. {
+            return Some(Interval::singleton(hi));
+        }
+        if f_lo.is_nan() || f_hi.is_nan() || f_lo.signum() == f_hi.signum() {
+            return None;
+        }
+        while hi - lo > tol {
+            let mid = lo / 2. + hi / 2.;
+            if mid <= lo || mid >= hi {
+                break;
+            }
+            let f_mid = f(mid);
+            if f_mid == 0. {
+                return Some(Interval::singleton(mid));
+            }
+            if f_mid.signum() == f_lo.signum() {
+                lo = mid;
+            } else {
+                hi = mid;
+            }
+        }
+        Some(Interval::new(Closed(lo), Closed(hi)))
+    }
 
-#[derive(Debug, Clone, Copy)]
-pub struct Interval(Left, Right);
+    /// Image of interval by `f`, a strictly monotone function in the given `direction`
+    ///
+    /// Endpoints are mapped through `f`, keeping their openness (and swapped if `f` is
+    /// decreasing). An unbound endpoint maps to `f(±∞)`, taken as open, and an endpoint with
+    /// an infinite image becomes unbound.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Monotonicity, Closed, Open, Unbound};
+    ///
+    /// let a = Interval::new(Closed(1.), Open(2.));
+    /// let b = Interval::new(Unbound, Closed(0.));
+    ///
+    /// assert_eq!(
+    ///     a.map_monotonic(|x| 1. / x, Monotonicity::Decreasing),
+    ///     Interval::new(Open(0.5), Closed(1.))
+    /// );
+    /// assert_eq!(
+    ///     b.map_monotonic(f64::exp, Monotonicity::Increasing),
+    ///     Interval::new(Open(0.), Closed(1.))
+    /// );
+    /// ```
+    ///
+    pub fn map_monotonic(self, f: impl Fn(f64) -> f64, direction: Monotonicity) -> Interval {
+        if self.is_empty() {
+            return EMPTY;
+        }
+        // Image of an endpoint, unbound if it is `side`, the infinity on its side of the
+        // result, and `None` if it is the opposite infinity
+        let map = |bound: Bound, infinity: f64, side: f64| {
+            let k = match bound {
+                Closed(k) | Open(k) => f(k),
+                Unbound => f(infinity),
+            };
+            match bound {
+                _ if k == side => Some(Unbound),
+                _ if k.is_infinite() => None,
+                Closed(_) => Some(Closed(k)),
+                Open(_) | Unbound => Some(Open(k)),
+            }
+        };
+        let (lower, upper) = match direction {
+            Monotonicity::Increasing => (f64::NEG_INFINITY, f64::INFINITY),
+            Monotonicity::Decreasing => (f64::INFINITY, f64::NEG_INFINITY),
+        };
+        let Interval(Left(left), Right(right)) = self;
+        match (
+            map(left, f64::NEG_INFINITY, lower),
+            map(right, f64::INFINITY, upper),
+        ) {
+            (Some(left), Some(right)) => match direction {
+                Monotonicity::Increasing => Interval::new(left, right),
+                Monotonicity::Decreasing => Interval::new(right, left),
+            },
+            _ => EMPTY,
+        }
+    }
 
-pub const EMPTY: Interval = Interval(Left(Open(0.)), Right(Open(0.)));
-pub const INFINITY: Interval = Interval(Left(Unbound), Right(Unbound));
+    /// Enclosure of the image of interval by `f`, an interval extension of some function
+    ///
+    /// Interval is bisected `max_depth` times (unbounded pieces are left whole) and the
+    /// images of the pieces are united, which is tighter than `f(self)` when the function
+    /// isn't monotone. Use `IntervalSet::hull` for a single interval.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Expr, Interval, Closed};
+    ///
+    /// let a = Interval::new(Closed(-1.), Closed(1.));
+    /// let e = Expr::var(0).sqr() - Expr::var(0);
+    /// let f = |x| e.eval(&[x]);
+    ///
+    /// assert_eq!(f(a), Interval::new(Closed(-1.), Closed(2.)));
+    /// assert_eq!(a.image(f, 4).hull(), Interval::new(Closed(-0.375), Closed(2.)));
+    /// ```
+    ///
+    pub fn image(self, f: impl Fn(Interval) -> Interval, max_depth: u32) -> IntervalSet {
+        fn pieces(a: Interval, depth: u32, res: &mut Vec<Interval>) {
+            match a.bisect() {
+                Some((lower, upper)) if depth > 0 => {
+                    pieces(lower, depth - 1, res);
+                    pieces(upper, depth - 1, res);
+                }
+                _ => res.push(a),
+            }
+        }
 
-impl Display for Interval {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut domain = Vec::new();
+        pieces(self, max_depth, &mut domain);
+        IntervalSet::from_unsorted(domain.into_iter().map(f).collect())
+    }
+
+    /// Assert in debug builds that interval is valid (feature `invariant-checks`)
+    ///
+    fn checked(self) -> Self {
+        #[cfg(feature = "invariant-checks")]
+        debug_assert!(self.is_valid(), "invalid interval {self:?}");
+        self
+    }
+
+    /// Build interval from std range bounds
+    ///
+    pub(crate) fn from_range_bounds(r: impl RangeBounds<f64>) -> Interval {
+        let left = match r.start_bound() {
+            Included(&k) => Closed(k),
+            Excluded(&k) => Open(k),
+            Unbounded => Unbound,
+        };
+        let right = match r.end_bound() {
+            Included(&k) => Closed(k),
+            Excluded(&k) => Open(k),
+            Unbounded => Unbound,
+        };
+        Interval::new(left, right)
+    }
+
+    /// Build closed interval from endpoint values, infinite values giving unbound endpoints
+    ///
+    pub(crate) fn from_endpoints(lower: f64, upper: f64) -> Interval {
+        let left = if lower == f64::NEG_INFINITY {
+            Unbound
+        } else {
+            Closed(lower)
+        };
+        let right = if upper == f64::INFINITY {
+            Unbound
+        } else {
+            Closed(upper)
+        };
+        Interval::new(left, right)
+    }
+
+    /// Endpoint values of interval, regardless of their openness
+    ///
+    /// Unbound endpoints are reported as `-f64::INFINITY` / `f64::INFINITY`. Returns `None`
+    /// for the empty set.
+    ///
+    pub(crate) fn endpoints(self) -> Option<(f64, f64)> {
+        if self.is_empty() {
+            return None;
+        }
+        let Interval(Left(left), Right(right)) = self;
+        let lower = match left {
+            Closed(k) | Open(k) => k,
+            Unbound => f64::NEG_INFINITY,
+        };
+        let upper = match right {
+            Closed(k) | Open(k) => k,
+            Unbound => f64::INFINITY,
+        };
+        Some((lower, upper))
+    }
+
+    /// Check if the whole (non-empty) interval lies strictly below `x`
+    ///
+    pub(crate) fn is_below(self, x: f64) -> bool {
         match self {
-            Interval(Left(Open(k1)), Right(Open(k2))) if k1 == k2 => write!(f, "∅"),
-            Interval(Left(Unbound), Right(Unbound)) => write!(f, "(-∞,+∞)"),
-            Interval(Left(Closed(a)), Right(Closed(b))) if a == b => write!(f, "{{{a:5.2}}}"),
-            Interval(a, b) => write!(f, "{a},{b}"),
+            Interval(_, Right(Closed(k))) => k < x,
+            Interval(_, Right(Open(k))) => k <= x,
+            Interval(_, Right(Unbound)) => false,
         }
     }
-}
 
-impl PartialEq for Interval {
-    fn eq(&self, other: &Self) -> bool {
-        let (Interval(a1, a2), Interval(b1, b2)) = (self, other);
-        a1 == b1 && a2 == b2
+    /// Check if right endpoint of interval comes strictly before right endpoint of `other`
+    ///
+    pub(crate) fn ends_before(self, other: Interval) -> bool {
+        let (Interval(_, a2), Interval(_, b2)) = (self, other);
+        a2 < b2
     }
-}
 
-impl Interval {
-    /// Build interval from given bounds
-    ///
-    /// # Returns
+    /// Length (Lebesgue measure) of interval
     ///
+    /// Empty intervals and singletons have a zero width, unbounded intervals an infinite
+    /// one.
     ///
     /// # Example
     ///
     /// ```
-    /// use interval::{Interval, Open, Closed, Unbound};
-    ///
-    /// let a = Interval::new(Open(42.), Closed(43.));
-    /// let b = Interval::new(Unbound, Unbound);
-    /// let c = Interval::singleton(42.);
+    /// use interval::{Interval, Open, Closed, Unbound, EMPTY};
     ///
-    /// assert_eq!(format!("{a}"), "(42.00,43.00]");
-    /// assert_eq!(format!("{b}"), "(-∞,+∞)");
-    /// assert_eq!(format!("{c}"), "{42.00}");
+    /// assert_eq!(Interval::new(Closed(0.), Open(2.5)).width(), 2.5);
+    /// assert_eq!(Interval::singleton(1.).width(), 0.);
+    /// assert_eq!(EMPTY.width(), 0.);
+    /// assert_eq!(Interval::new(Unbound, Closed(0.)).width(), f64::INFINITY);
     /// ```
     ///
-    pub fn new(b1: Bound, b2: Bound) -> Self {
-        let b1 = Left(b1);
-        let b2 = Right(b2);
+    pub fn width(self) -> f64 {
+        match self {
+            _ if self.is_empty() => 0.,
+            Interval(Left(Unbound), _) | Interval(_, Right(Unbound)) => f64::INFINITY,
+            Interval(Left(Closed(a) | Open(a)), Right(Closed(b) | Open(b))) => b - a,
+        }
+    }
 
-        if b2 < b1 {
-            EMPTY
-        } else if (b1, b2) == (Left(Unbound), Right(Unbound)) {
-            INFINITY
-        } else {
-            Interval(b1, b2)
+    /// Split bounded interval in two halves at its midpoint
+    ///
+    /// The midpoint belongs to the upper half. Returns `None` for empty, singleton or
+    /// unbounded intervals.
+    ///
+    pub(crate) fn bisect(self) -> Option<(Interval, Interval)> {
+        match self {
+            _ if self.is_empty() || self.is_singleton() => None,
+            Interval(Left(left @ (Closed(a) | Open(a))), Right(right @ (Closed(b) | Open(b)))) => {
+                let mid = a / 2. + b / 2.;
+                Some((
+                    Interval::new(left, Open(mid)),
+                    Interval::new(Closed(mid), right),
+                ))
+            }
+            _ => None,
         }
     }
 
-    pub fn singleton(k: f64) -> Self {
-        Interval(Left(Closed(k)), Right(Closed(k)))
+    /// Smallest and largest `i64` integers of interval, `None` if there are none
+    ///
+    /// Only integers of the `i64` range count, so that unbound endpoints saturate to
+    /// `i64::MIN` / `i64::MAX` and intervals lying beyond that range have none.
+    ///
+    pub(crate) fn integer_range(self) -> Option<(i64, i64)> {
+        if self.is_empty() {
+            return None;
+        }
+        let Interval(Left(left), Right(right)) = self;
+        // Work in i128 so that moving past an open endpoint is exact, then clip to i64 range
+        let lower = match left {
+            Closed(k) => k.ceil() as i128,
+            Open(k) => (k.floor() as i128).saturating_add(1),
+            Unbound => i128::MIN,
+        };
+        let upper = match right {
+            Closed(k) => k.floor() as i128,
+            Open(k) => (k.ceil() as i128).saturating_sub(1),
+            Unbound => i128::MAX,
+        };
+        let lower = lower.max(i64::MIN as i128);
+        let upper = upper.min(i64::MAX as i128);
+        if lower <= upper {
+            Some((lower as i64, upper as i64))
+        } else {
+            None
+        }
     }
 
-    pub fn is_singleton(&self) -> bool {
+    /// Shift interval by `dx`, each endpoint keeping its bound type
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Open, Closed};
+    ///
+    /// let a = Interval::new(Closed(0.), Open(1.));
+    ///
+    /// assert_eq!(a.translate(2.), Interval::new(Closed(2.), Open(3.)));
+    /// ```
+    ///
+    pub fn translate(self, dx: f64) -> Interval {
+        let shift = |bound| match bound {
+            Closed(k) => Closed(k + dx),
+            Open(k) => Open(k + dx),
+            Unbound => Unbound,
+        };
         match self {
-            Interval(Left(Closed(k1)), Right(Closed(k2))) => k1 == k2,
-            _ => false,
+            _ if self.is_empty() => EMPTY,
+            Interval(Left(left), Right(right)) => Interval::new(shift(left), shift(right)),
         }
     }
 
-    pub fn is_empty(self) -> bool {
-        self == EMPTY
+    /// Mirror image of interval about point `about`
+    ///
+    /// Endpoints swap sides, each one taking its bound type along.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Open, Closed, Unbound};
+    ///
+    /// let a = Interval::new(Closed(1.), Open(3.));
+    ///
+    /// assert_eq!(a.reflect(0.), Interval::new(Open(-3.), Closed(-1.)));
+    /// assert_eq!(a.reflect(2.), Interval::new(Open(1.), Closed(3.)));
+    /// assert_eq!(
+    ///     Interval::new(Unbound, Closed(0.)).reflect(1.),
+    ///     Interval::new(Closed(2.), Unbound)
+    /// );
+    /// ```
+    ///
+    pub fn reflect(self, about: f64) -> Interval {
+        let mirror = |bound| match bound {
+            Closed(k) => Closed(2. * about - k),
+            Open(k) => Open(2. * about - k),
+            Unbound => Unbound,
+        };
+        match self {
+            _ if self.is_empty() => EMPTY,
+            Interval(Left(left), Right(right)) => Interval::new(mirror(right), mirror(left)),
+        }
     }
 
-    pub fn union(self, other: Interval) -> (Interval, Option<Interval>) {
-        match (self, other) {
-            (a, Interval(Left(Open(k1)), Right(Open(k2))))
-            | (Interval(Left(Open(k1)), Right(Open(k2))), a)
-                if k1 == k2 =>
-            {
-                (a, None)
-            }
-            (Interval(Left(Unbound), Right(Unbound)), _)
-            | (_, Interval(Left(Unbound), Right(Unbound))) => {
-                (Interval(Left(Unbound), Right(Unbound)), None)
-            }
-
-            (Interval(a1, a2), Interval(b1, b2)) => {
-                if self.overlap(other) || self.adhere_to(other) {
-                    (Interval(a1.min(b1), a2.max(b2)), None)
-                } else if b1 > a2 {
-                    (self, Some(other))
-                } else {
-                    (other, Some(self))
-                }
+    /// Stretch interval by `factor` about point `anchor`
+    ///
+    /// A negative factor also mirrors the interval about `anchor`, swapping its endpoints. A
+    /// zero factor collapses any non-empty interval to singleton `{anchor}`.
+    ///
+    /// Endpoints sent to infinity, by an infinite factor or an overflow, become unbound, and
+    /// an endpoint equal to `anchor` stays fixed. An interval lying wholly on one side of
+    /// `anchor` is empty once sent to infinity.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Open, Closed, Unbound};
+    ///
+    /// let view = Interval::new(Closed(0.), Open(10.));
+    ///
+    /// assert_eq!(view.scale(2., 5.), Interval::new(Closed(-5.), Open(15.)));
+    /// assert_eq!(view.scale(-0.5, 0.), Interval::new(Open(-5.), Closed(0.)));
+    /// assert_eq!(view.scale(0., 4.), Interval::singleton(4.));
+    /// assert_eq!(view.scale(f64::INFINITY, 0.), Interval::new(Closed(0.), Unbound));
+    /// ```
+    ///
+    pub fn scale(self, factor: f64, anchor: f64) -> Interval {
+        // Bound moved to the side where `unbound` lies, `None` if sent to the other infinity
+        let stretch = |bound, unbound: f64| {
+            let (k, closed) = match bound {
+                Closed(k) => (k, true),
+                Open(k) => (k, false),
+                Unbound => return Some(Unbound),
+            };
+            match if k == anchor {
+                k
+            } else {
+                anchor + factor * (k - anchor)
+            } {
+                k if k == unbound => Some(Unbound),
+                k if k.is_infinite() => None,
+                k if closed => Some(Closed(k)),
+                k => Some(Open(k)),
             }
+        };
+        let (left, right) = match self {
+            _ if self.is_empty() || factor.is_nan() => return EMPTY,
+            _ if factor == 0. => return Interval::singleton(anchor),
+            Interval(Left(left), Right(right)) if factor < 0. => (right, left),
+            Interval(Left(left), Right(right)) => (left, right),
+        };
+        match (
+            stretch(left, f64::NEG_INFINITY),
+            stretch(right, f64::INFINITY),
+        ) {
+            (Some(left), Some(right)) => Interval::new(left, right),
+            _ => EMPTY,
         }
     }
 
-    /// Check if intervals overlap
+    /// Check if intervals overlap, sharing at least one point
+    ///
+    /// The empty set overlaps nothing. This is the negation of `is_disjoint`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Open, Closed, EMPTY};
+    ///
+    /// let a = Interval::new(Closed(0.), Closed(1.));
     ///
-    /// Note that `Interval(Left(Open(0.)),Right(Open(0.)))` overlap nothing.
+    /// assert!(a.overlaps(Interval::new(Closed(1.), Closed(2.))));
+    /// assert!(!a.overlaps(Interval::new(Open(1.), Closed(2.))));
+    /// assert!(!a.overlaps(EMPTY));
+    /// ```
     ///
-    fn overlap(self, other: Interval) -> bool {
+    pub fn overlaps(self, other: Interval) -> bool {
         match (self, other) {
             (_, Interval(Left(Open(k1)), Right(Open(k2))))
             | (Interval(Left(Open(k1)), Right(Open(k2))), _)
@@ -126,9 +2058,25 @@ impl Interval {
         }
     }
 
-    /// Check if interval endpoints could rejoin (ie ]2 and (2, (2 and 2] ...)
+    /// Check if one interval ends where the other starts, with no point missing in between
+    ///
+    /// Endpoints meeting at the same value are adjacent unless both are open, such as `[0, 1)`
+    /// and `[1, 2]`, or `[0, 1]` and `[1, 2]` which also overlap. The union of adjacent
+    /// intervals is an interval.
+    ///
+    /// # Example
     ///
-    fn adhere_to(self, other: Interval) -> bool {
+    /// ```
+    /// use interval::{Interval, Open, Closed};
+    ///
+    /// let a = Interval::new(Closed(0.), Open(1.));
+    ///
+    /// assert!(a.is_adjacent_to(Interval::new(Closed(1.), Closed(2.))));
+    /// assert!(!a.is_adjacent_to(Interval::new(Open(1.), Closed(2.))));
+    /// assert!(!a.is_adjacent_to(Interval::new(Closed(2.), Closed(3.))));
+    /// ```
+    ///
+    pub fn is_adjacent_to(self, other: Interval) -> bool {
         if self.is_empty() || other.is_empty() {
             return false;
         }
@@ -138,6 +2086,54 @@ impl Interval {
             (Interval(a1, a2), Interval(b1, b2)) => a1.closure(b2) || a2.closure(b1),
         }
     }
+
+    /// Write interval with endpoints formatted by `number`
+    ///
+    fn fmt_endpoints(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+        number: impl Fn(f64) -> String,
+    ) -> std::fmt::Result {
+        match *self {
+            Interval(Left(Open(k1)), Right(Open(k2))) if k1 == k2 => write!(f, "∅"),
+            Interval(Left(Unbound), Right(Unbound)) => write!(f, "(-∞,+∞)"),
+            Interval(Left(Closed(a)), Right(Closed(b))) if a == b => {
+                write!(f, "{{{}}}", number(a))
+            }
+            Interval(Left(a), Right(b)) => {
+                match a {
+                    Closed(k) => write!(f, "[{}", number(k))?,
+                    Open(k) => write!(f, "({}", number(k))?,
+                    Unbound => write!(f, "(-∞")?,
+                }
+                match b {
+                    Closed(k) => write!(f, ",{}]", number(k)),
+                    Open(k) => write!(f, ",{})", number(k)),
+                    Unbound => write!(f, ",+∞)"),
+                }
+            }
+        }
+    }
+}
+
+/// Smallest float above `x`, as `f64::next_up` which needs Rust 1.86
+///
+pub(crate) fn next_up(x: f64) -> f64 {
+    if x.is_nan() || x == f64::INFINITY {
+        x
+    } else if x == 0. {
+        f64::from_bits(1)
+    } else if x > 0. {
+        f64::from_bits(x.to_bits() + 1)
+    } else {
+        f64::from_bits(x.to_bits() - 1)
+    }
+}
+
+/// Largest float below `x`, as `f64::next_down` which needs Rust 1.86
+///
+pub(crate) fn next_down(x: f64) -> f64 {
+    -next_up(-x)
 }
 
 #[cfg(test)]
@@ -190,7 +2186,7 @@ mod test {
         let a = Interval::new(Unbound, Unbound);
         let b = Interval::new(Unbound, Unbound);
 
-        assert!(a.overlap(b));
+        assert!(a.overlaps(b));
     }
 
     #[test]
@@ -198,7 +2194,7 @@ mod test {
         let a = Interval::new(Unbound, Unbound);
         let b = EMPTY;
 
-        assert!(!a.overlap(b));
+        assert!(!a.overlaps(b));
     }
 
     #[test]
@@ -206,63 +2202,63 @@ mod test {
         let a = EMPTY;
         let b = Interval::new(Unbound, Unbound);
 
-        assert!(!a.overlap(b));
+        assert!(!a.overlaps(b));
     }
 
     #[test]
     fn test_overlap_4() {
         let a = Interval::new(Closed(42.), Closed(43.));
         let b = Interval::new(Unbound, Unbound);
-        assert!(a.overlap(b));
+        assert!(a.overlaps(b));
     }
 
     #[test]
     fn test_overlap_5() {
         let a = Interval::new(Unbound, Unbound);
         let b = Interval::new(Closed(42.), Closed(43.));
-        assert!(a.overlap(b));
+        assert!(a.overlaps(b));
     }
 
     #[test]
     fn test_overlap_6() {
         let a = Interval::new(Closed(42.), Open(43.));
         let b = Interval::new(Unbound, Unbound);
-        assert!(a.overlap(b));
+        assert!(a.overlaps(b));
     }
 
     #[test]
     fn test_overlap_7() {
         let a = Interval::new(Unbound, Unbound);
         let b = Interval::new(Closed(42.), Open(43.));
-        assert!(a.overlap(b));
+        assert!(a.overlaps(b));
     }
 
     #[test]
     fn test_overlap_8() {
         let a = Interval::new(Open(42.), Open(43.));
         let b = Interval::new(Unbound, Unbound);
-        assert!(a.overlap(b));
+        assert!(a.overlaps(b));
     }
 
     #[test]
     fn test_overlap_9() {
         let a = Interval::new(Unbound, Unbound);
         let b = Interval::new(Open(42.), Open(43.));
-        assert!(a.overlap(b));
+        assert!(a.overlaps(b));
     }
 
     #[test]
     fn test_overlap_10() {
         let a = Interval::new(Unbound, Open(43.));
         let b = Interval::new(Unbound, Unbound);
-        assert!(a.overlap(b));
+        assert!(a.overlaps(b));
     }
 
     #[test]
     fn test_overlap_11() {
         let a = Interval::new(Unbound, Unbound);
         let b = Interval::new(Open(42.), Unbound);
-        assert!(a.overlap(b));
+        assert!(a.overlaps(b));
     }
 
     #[test]
@@ -270,7 +2266,7 @@ mod test {
         let a = EMPTY;
         let b = Interval::new(Unbound, Unbound);
 
-        assert!(!a.overlap(b));
+        assert!(!a.overlaps(b));
     }
 
     #[test]
@@ -278,14 +2274,14 @@ mod test {
         let a = EMPTY;
         let b = EMPTY;
 
-        assert!(!a.overlap(b));
+        assert!(!a.overlaps(b));
     }
 
     #[test]
     fn test_overlap_14() {
         let a = Interval::new(Closed(42.), Closed(52.));
         let b = Interval::new(Closed(42.), Closed(52.));
-        assert!(a.overlap(b));
+        assert!(a.overlaps(b));
     }
 
     #[test]
@@ -293,7 +2289,7 @@ mod test {
         let a = Interval::new(Closed(42.), Closed(52.));
         let b = Interval::new(Open(42.), Open(52.));
 
-        assert!(a.overlap(b));
+        assert!(a.overlaps(b));
     }
 
     #[test]
@@ -301,7 +2297,7 @@ mod test {
         let a = Interval::new(Closed(42.), Closed(52.));
         let b = Interval::new(Open(42.), Open(52.));
 
-        assert!(b.overlap(a));
+        assert!(b.overlaps(a));
     }
 
     #[test]
@@ -309,7 +2305,7 @@ mod test {
         let a = Interval::new(Open(42.), Closed(52.));
         let b = Interval::new(Open(42.), Open(52.));
 
-        assert!(a.overlap(b));
+        assert!(a.overlaps(b));
     }
 
     #[test]
@@ -317,7 +2313,7 @@ mod test {
         let a = Interval::new(Open(42.), Closed(52.));
         let b = Interval::new(Open(42.), Open(52.));
 
-        assert!(b.overlap(a));
+        assert!(b.overlaps(a));
     }
 
     #[test]
@@ -325,7 +2321,7 @@ mod test {
         let a = Interval::new(Closed(42.), Open(52.));
         let b = Interval::new(Open(42.), Open(52.));
 
-        assert!(a.overlap(b));
+        assert!(a.overlaps(b));
     }
 
     #[test]
@@ -333,42 +2329,42 @@ mod test {
         let a = Interval::new(Closed(42.), Open(52.));
         let b = Interval::new(Open(42.), Open(52.));
 
-        assert!(b.overlap(a));
+        assert!(b.overlaps(a));
     }
 
     #[test]
     fn test_overlap_21() {
         let a = Interval::new(Open(42.), Open(52.));
         let b = Interval::new(Open(42.), Open(52.));
-        assert!(a.overlap(b));
+        assert!(a.overlaps(b));
     }
 
     #[test]
     fn test_overlap_22() {
         let a = Interval::new(Unbound, Closed(42.));
         let b = Interval::new(Open(42.), Open(52.));
-        assert!(!a.overlap(b));
+        assert!(!a.overlaps(b));
     }
 
     #[test]
     fn test_overlap_23() {
         let a = Interval::new(Unbound, Open(42.));
         let b = Interval::new(Open(42.), Open(52.));
-        assert!(!a.overlap(b));
+        assert!(!a.overlaps(b));
     }
 
     #[test]
     fn test_overlap_24() {
         let a = Interval::new(Closed(52.), Unbound);
         let b = Interval::new(Open(42.), Open(52.));
-        assert!(!a.overlap(b));
+        assert!(!a.overlaps(b));
     }
 
     #[test]
     fn test_overlap_25() {
         let a = Interval::new(Open(52.), Unbound);
         let b = Interval::new(Open(42.), Open(52.));
-        assert!(!a.overlap(b));
+        assert!(!a.overlaps(b));
     }
 
     #[test]
@@ -376,7 +2372,7 @@ mod test {
         let a = Interval::new(Open(42.), Unbound);
         let b = Interval::new(Unbound, Closed(42.));
 
-        assert!(a.adhere_to(b));
+        assert!(a.is_adjacent_to(b));
     }
 
     #[test]
@@ -384,7 +2380,7 @@ mod test {
         let a = Interval::new(Open(42.), Unbound);
         let b = Interval::new(Unbound, Open(42.));
 
-        assert!(!a.adhere_to(b));
+        assert!(!a.is_adjacent_to(b));
     }
 
     #[test]
@@ -392,7 +2388,7 @@ mod test {
         let a = Interval::new(Unbound, Open(42.));
         let b = Interval::new(Closed(42.), Unbound);
 
-        assert!(a.adhere_to(b));
+        assert!(a.is_adjacent_to(b));
     }
 
     #[test]
@@ -400,7 +2396,7 @@ mod test {
         let a = Interval::new(Unbound, Open(42.));
         let b = Interval::new(Open(42.), Unbound);
 
-        assert!(!a.adhere_to(b));
+        assert!(!a.is_adjacent_to(b));
     }
 
     #[test]
@@ -408,7 +2404,7 @@ mod test {
         let a = INFINITY;
         let b = Interval::new(Open(42.), Unbound);
 
-        assert!(!a.adhere_to(b));
+        assert!(!a.is_adjacent_to(b));
     }
 
     #[test]
@@ -416,7 +2412,7 @@ mod test {
         let a = EMPTY;
         let b = Interval::new(Open(42.), Unbound);
 
-        assert!(!a.adhere_to(b));
+        assert!(!a.is_adjacent_to(b));
     }
 
     #[test]
@@ -693,4 +2689,1185 @@ mod test {
         let i = Interval::new(Unbound, Open(42.));
         assert_eq!(format!("{i}"), "(-∞,42.00)");
     }
+
+    #[test]
+    fn test_contains_1() {
+        let i = Interval::new(Closed(42.), Open(43.));
+        assert!(i.contains(42.));
+        assert!(i.contains(42.5));
+        assert!(!i.contains(43.));
+    }
+
+    #[test]
+    fn test_contains_2() {
+        let i = Interval::new(Unbound, Closed(42.));
+        assert!(i.contains(f64::MIN));
+        assert!(i.contains(42.));
+        assert!(!i.contains(42.1));
+    }
+
+    #[test]
+    fn test_contains_3() {
+        assert!(!EMPTY.contains(0.));
+        assert!(INFINITY.contains(0.));
+    }
+
+    #[test]
+    fn test_contains_interval_1() {
+        let a = Interval::new(Closed(42.), Closed(52.));
+        assert!(a.contains_interval(Interval::new(Open(42.), Closed(52.))));
+        assert!(a.contains_interval(EMPTY));
+        assert!(!a.contains_interval(Interval::new(Closed(41.), Closed(52.))));
+    }
+
+    #[test]
+    fn test_contains_interval_2() {
+        let a = Interval::new(Open(42.), Closed(52.));
+        assert!(!a.contains_interval(Interval::new(Closed(42.), Closed(52.))));
+        assert!(INFINITY.contains_interval(a));
+        assert!(!EMPTY.contains_interval(a));
+    }
+
+    #[test]
+    fn test_intersection_1() {
+        let a = Interval::new(Closed(42.), Closed(52.));
+        let b = Interval::new(Open(45.), Unbound);
+        assert_eq!(a.intersection(b), Interval::new(Open(45.), Closed(52.)));
+        assert_eq!(b.intersection(a), Interval::new(Open(45.), Closed(52.)));
+    }
+
+    #[test]
+    fn test_intersection_2() {
+        let a = Interval::new(Closed(42.), Closed(52.));
+        let b = Interval::new(Closed(52.), Closed(62.));
+        assert_eq!(a.intersection(b), Interval::singleton(52.));
+    }
+
+    #[test]
+    fn test_intersection_3() {
+        let a = Interval::new(Closed(42.), Open(52.));
+        let b = Interval::new(Closed(52.), Closed(62.));
+        assert_eq!(a.intersection(b), EMPTY);
+    }
+
+    #[test]
+    fn test_intersection_4() {
+        let a = Interval::new(Closed(42.), Open(52.));
+        assert_eq!(a.intersection(INFINITY), a);
+        assert_eq!(a.intersection(EMPTY), EMPTY);
+        assert_eq!(INFINITY.intersection(INFINITY), INFINITY);
+    }
+
+    #[test]
+    fn test_hull_1() {
+        let a = Interval::new(Open(42.), Closed(43.));
+        let b = Interval::new(Closed(52.), Open(53.));
+        assert_eq!(a.hull(b), Interval::new(Open(42.), Open(53.)));
+        assert_eq!(b.hull(a), Interval::new(Open(42.), Open(53.)));
+    }
+
+    #[test]
+    fn test_hull_2() {
+        let a = Interval::new(Open(42.), Closed(43.));
+        assert_eq!(a.hull(EMPTY), a);
+        assert_eq!(EMPTY.hull(a), a);
+        assert_eq!(
+            a.hull(Interval::new(Unbound, Closed(0.))),
+            Interval::new(Unbound, Closed(43.))
+        );
+    }
+
+    #[test]
+    fn test_width_1() {
+        assert_eq!(EMPTY.width(), 0.);
+        assert_eq!(Interval::singleton(42.).width(), 0.);
+        assert_eq!(Interval::new(Open(42.), Closed(52.)).width(), 10.);
+        assert_eq!(Interval::new(Unbound, Closed(52.)).width(), f64::INFINITY);
+    }
+
+    #[test]
+    fn test_bisect_1() {
+        let a = Interval::new(Open(42.), Closed(52.));
+        assert_eq!(
+            a.bisect(),
+            Some((
+                Interval::new(Open(42.), Open(47.)),
+                Interval::new(Closed(47.), Closed(52.))
+            ))
+        );
+    }
+
+    #[test]
+    fn test_bisect_2() {
+        assert_eq!(EMPTY.bisect(), None);
+        assert_eq!(Interval::singleton(42.).bisect(), None);
+        assert_eq!(Interval::new(Closed(42.), Unbound).bisect(), None);
+    }
+
+    #[test]
+    fn test_endpoints_1() {
+        assert_eq!(EMPTY.endpoints(), None);
+        assert_eq!(
+            INFINITY.endpoints(),
+            Some((f64::NEG_INFINITY, f64::INFINITY))
+        );
+        assert_eq!(
+            Interval::new(Open(42.), Closed(43.)).endpoints(),
+            Some((42., 43.))
+        );
+    }
+
+    #[test]
+    fn test_is_below_1() {
+        assert!(Interval::new(Closed(0.), Open(42.)).is_below(42.));
+        assert!(!Interval::new(Closed(0.), Closed(42.)).is_below(42.));
+        assert!(Interval::new(Closed(0.), Closed(42.)).is_below(42.5));
+        assert!(!Interval::new(Closed(0.), Unbound).is_below(f64::MAX));
+    }
+
+    #[test]
+    fn test_ends_before_1() {
+        let a = Interval::new(Closed(0.), Open(42.));
+        let b = Interval::new(Closed(10.), Closed(42.));
+        assert!(a.ends_before(b));
+        assert!(!b.ends_before(a));
+        assert!(!a.ends_before(a));
+        assert!(b.ends_before(INFINITY));
+    }
+
+    #[test]
+    fn test_translate_1() {
+        let a = Interval::new(Open(0.), Closed(1.));
+        assert_eq!(a.translate(2.), Interval::new(Open(2.), Closed(3.)));
+        assert_eq!(EMPTY.translate(2.), EMPTY);
+        assert_eq!(INFINITY.translate(2.), INFINITY);
+    }
+
+    #[test]
+    fn test_partition_equal_1() {
+        let a = Interval::new(Open(0.), Open(3.));
+        let b = a.partition_equal(3).unwrap();
+        assert_eq!(b.len(), 3);
+        assert_eq!(b[0], Interval::new(Open(0.), Open(1.)));
+        assert_eq!(b[2], Interval::new(Closed(2.), Open(3.)));
+        assert_eq!(INFINITY.partition_equal(3), Err(IntervalError::Unbounded));
+    }
+
+    #[test]
+    fn test_partition_at_1() {
+        let a = Interval::new(Open(0.), Closed(2.));
+        assert_eq!(
+            a.partition_at(&[1., 2., 1., f64::NAN]),
+            vec![
+                Interval::new(Open(0.), Open(1.)),
+                Interval::new(Closed(1.), Closed(2.)),
+            ]
+        );
+        assert_eq!(a.partition_at(&[]), vec![a]);
+        assert!(EMPTY.partition_at(&[1.]).is_empty());
+    }
+
+    #[test]
+    fn test_snap_1() {
+        let a = Interval::new(Closed(0.4), Open(1.6));
+        assert_eq!(
+            a.snap(1., SnapMode::Round),
+            Interval::new(Closed(0.), Open(2.))
+        );
+        assert_eq!(
+            a.snap(1., SnapMode::Expand),
+            Interval::new(Closed(0.), Open(2.))
+        );
+        assert_eq!(a.snap(1., SnapMode::Shrink), EMPTY);
+        assert_eq!(
+            a.snap(0.5, SnapMode::Shrink),
+            Interval::new(Closed(0.5), Open(1.5))
+        );
+    }
+
+    #[test]
+    fn test_snap_2() {
+        let a = Interval::new(Unbound, Closed(1.2));
+        assert_eq!(
+            a.snap(1., SnapMode::Expand),
+            Interval::new(Unbound, Closed(2.))
+        );
+        assert_eq!(a.snap(0., SnapMode::Expand), a);
+        assert_eq!(EMPTY.snap(1., SnapMode::Expand), EMPTY);
+    }
+
+    #[test]
+    fn test_integer_range_1() {
+        assert_eq!(
+            Interval::new(Closed(1.), Open(3.)).integer_range(),
+            Some((1, 2))
+        );
+        assert_eq!(
+            Interval::new(Open(0.5), Closed(2.5)).integer_range(),
+            Some((1, 2))
+        );
+        assert_eq!(Interval::new(Open(1.), Open(2.)).integer_range(), None);
+        assert_eq!(EMPTY.integer_range(), None);
+        assert_eq!(INFINITY.integer_range(), Some((i64::MIN, i64::MAX)));
+    }
+
+    #[test]
+    fn test_integer_range_2() {
+        // Integers beyond the i64 range don't saturate onto i64::MIN / i64::MAX
+        let a = Interval::new(Closed(1e30), Closed(1e31));
+        assert_eq!(a.integer_range(), None);
+        assert_eq!(a.integers().next(), None);
+        assert_eq!(
+            Interval::new(Closed(-1e31), Open(-1e30)).integer_range(),
+            None
+        );
+        assert_eq!(Interval::new(Closed(9.3e18), Unbound).integer_range(), None);
+        assert_eq!(
+            Interval::new(Closed(-1e30), Closed(1e30)).integer_range(),
+            Some((i64::MIN, i64::MAX))
+        );
+        assert_eq!(
+            Interval::new(Closed(-9223372036854775808.), Unbound).integer_range(),
+            Some((i64::MIN, i64::MAX))
+        );
+        assert_eq!(
+            Interval::new(Unbound, Open(-9223372036854775808.)).integer_range(),
+            None
+        );
+    }
+
+    #[cfg(feature = "simd")]
+    #[test]
+    fn test_contains_simd_1() {
+        let intervals = [
+            Interval::new(Closed(0.), Closed(1.)),
+            Interval::new(Open(0.), Open(1.)),
+            Interval::new(Unbound, Open(0.5)),
+            Interval::singleton(0.5),
+            INFINITY,
+            EMPTY,
+        ];
+        let points: Vec<f64> = (-4..=24)
+            .map(|k| k as f64 / 20.)
+            .chain([f64::INFINITY, f64::NEG_INFINITY, f64::NAN])
+            .collect();
+        let mut out = vec![false; points.len()];
+        for a in intervals {
+            a.contains_simd(&points, &mut out);
+            let expected: Vec<_> = points.iter().map(|&x| a.contains(x)).collect();
+            assert_eq!(out, expected);
+        }
+    }
+
+    #[test]
+    fn test_contains_range_1() {
+        let a = Interval::new(Open(0.), Unbound);
+        assert!(a.contains_range(1.0..2.0));
+        assert!(a.contains_range(1.0..));
+        assert!(!a.contains_range(0.0..2.0));
+        assert!(!a.contains_range(..));
+        assert!(a.contains_range(2.0..1.0));
+        assert!(INFINITY.contains_range(..));
+        assert!(!EMPTY.contains_range(0.0..=0.0));
+    }
+
+    #[test]
+    fn test_as_range_1() {
+        assert_eq!(
+            Interval::new(Closed(1.), Open(2.)).as_range(),
+            Some(1.0..2.0)
+        );
+        assert_eq!(Interval::new(Open(1.), Open(2.)).as_range(), None);
+        assert_eq!(Interval::new(Closed(1.), Unbound).as_range(), None);
+        assert_eq!(EMPTY.as_range(), None);
+        assert_eq!(
+            Interval::new(Closed(1.), Closed(2.)).as_range_inclusive(),
+            Some(1.0..=2.0)
+        );
+        assert_eq!(
+            Interval::singleton(1.).as_range_inclusive(),
+            Some(1.0..=1.0)
+        );
+        assert_eq!(INFINITY.as_range_inclusive(), None);
+    }
+
+    #[test]
+    fn test_inv_lerp_1() {
+        let a = Interval::new(Open(-1.), Closed(1.));
+        assert_eq!(a.inv_lerp(-1.), Some(0.));
+        assert_eq!(a.inv_lerp(0.), Some(0.5));
+        assert_eq!(a.inv_lerp(-3.), Some(-1.));
+        assert_eq!(a.inv_lerp_clamped(-3.), Some(0.));
+        assert_eq!(Interval::singleton(1.).inv_lerp(1.), None);
+        assert_eq!(Interval::new(Closed(0.), Unbound).inv_lerp(1.), None);
+        assert_eq!(EMPTY.inv_lerp_clamped(0.), None);
+    }
+
+    #[test]
+    fn test_iou_1() {
+        let a = Interval::new(Closed(0.), Closed(4.));
+        assert_eq!(a.iou(a), 1.);
+        assert_eq!(a.iou(Interval::new(Open(1.), Open(3.))), 0.5);
+        assert_eq!(a.iou(Interval::new(Closed(4.), Closed(8.))), 0.);
+        assert_eq!(a.iou(EMPTY), 0.);
+        assert_eq!(EMPTY.iou(EMPTY), 0.);
+        assert_eq!(Interval::singleton(1.).iou(Interval::singleton(1.)), 1.);
+        assert_eq!(a.iou(Interval::new(Closed(2.), Unbound)), 0.);
+        assert!(INFINITY.iou(INFINITY).is_nan());
+    }
+
+    #[test]
+    fn test_integers_1() {
+        let a = Interval::new(Closed(-2.), Open(1.));
+        assert_eq!(a.integers().collect::<Vec<_>>(), vec![-2, -1, 0]);
+        assert_eq!(Interval::new(Open(0.), Open(1.)).integers().count(), 0);
+        assert_eq!(EMPTY.integers().count(), 0);
+        assert_eq!(
+            Interval::singleton(3.).integers().collect::<Vec<_>>(),
+            vec![3]
+        );
+        let b = Interval::new(Open(5.), Unbound);
+        assert_eq!(b.integers().take(2).collect::<Vec<_>>(), vec![6, 7]);
+    }
+
+    #[test]
+    fn test_linspace_1() {
+        let points = |a: Interval, n| a.linspace(n).collect::<Vec<_>>();
+        assert_eq!(
+            points(Interval::new(Open(0.), Closed(1.)), 2),
+            vec![0.5, 1.]
+        );
+        assert_eq!(
+            points(Interval::new(Open(0.), Open(1.)), 3),
+            vec![0.25, 0.5, 0.75]
+        );
+        assert_eq!(points(Interval::new(Closed(0.), Closed(1.)), 1), vec![0.]);
+        assert_eq!(points(Interval::new(Closed(0.), Closed(1.)), 0), vec![]);
+        assert_eq!(points(Interval::singleton(2.), 2), vec![2., 2.]);
+        assert_eq!(points(Interval::new(Closed(0.), Unbound), 2), vec![]);
+        assert_eq!(points(EMPTY, 2), vec![]);
+    }
+
+    #[test]
+    fn test_linspace_2() {
+        let a = Interval::new(Closed(0.1), Closed(0.7));
+        assert_eq!(a.linspace(7).last(), Some(0.7));
+    }
+
+    #[cfg(feature = "low-discrepancy")]
+    #[test]
+    fn test_quasi_random_1() {
+        let a = Interval::new(Closed(0.), Closed(10.));
+        let mut counts = [0; 10];
+        for x in a.quasi_random(7).take(1000) {
+            counts[(x as usize).min(9)] += 1;
+        }
+        assert!(counts.iter().all(|&count| (95..=105).contains(&count)));
+        assert_ne!(a.quasi_random(7).next(), a.quasi_random(8).next());
+        assert_eq!(EMPTY.quasi_random(7).next(), None);
+        assert_eq!(INFINITY.quasi_random(7).next(), None);
+        assert_eq!(Interval::singleton(1.).quasi_random(7).next(), Some(1.));
+    }
+
+    #[cfg(feature = "low-discrepancy")]
+    #[test]
+    fn test_quasi_random_2() {
+        // Width overflows f64
+        let a = Interval::new(Closed(-f64::MAX), Closed(f64::MAX));
+        let points: Vec<_> = a.quasi_random(0).take(100).collect();
+        assert_eq!(points.len(), 100);
+        assert!(points.iter().all(|&x| a.contains(x)));
+        // No float lies strictly between 0 and the smallest subnormal
+        let b = Interval::new(Open(0.), Open(f64::from_bits(1)));
+        assert_eq!(b.quasi_random(0).next(), None);
+        let c = Interval::new(Open(1.), Open(1. + 2. * f64::EPSILON));
+        assert!(c.quasi_random(3).take(10).all(|x| x == 1. + f64::EPSILON));
+    }
+
+    #[test]
+    fn test_nan_1() {
+        assert_eq!(Interval::new(Closed(f64::NAN), Closed(1.)), EMPTY);
+        assert_eq!(Interval::new(Unbound, Open(f64::NAN)), EMPTY);
+        assert_eq!(Interval::singleton(f64::NAN), EMPTY);
+        assert!(!INFINITY.contains(f64::NAN));
+        assert!(!Interval::new(Closed(0.), Unbound).contains(f64::NAN));
+    }
+
+    #[test]
+    fn test_is_valid_1() {
+        assert!(EMPTY.is_valid());
+        assert!(INFINITY.is_valid());
+        assert!(Interval::singleton(1.).is_valid());
+        assert!(!Interval(Left(Closed(f64::NAN)), Right(Closed(1.))).is_valid());
+        assert!(!Interval(Left(Closed(2.)), Right(Closed(1.))).is_valid());
+        assert!(!Interval(Left(Open(1.)), Right(Closed(1.))).is_valid());
+    }
+
+    #[test]
+    fn test_try_new_1() {
+        assert_eq!(
+            Interval::try_new(Unbound, Closed(1.)),
+            Ok(Interval::new(Unbound, Closed(1.)))
+        );
+        assert_eq!(Interval::try_new(Unbound, Unbound), Ok(INFINITY));
+        assert_eq!(
+            Interval::try_new(Closed(1.), Closed(1.)),
+            Ok(Interval::singleton(1.))
+        );
+        assert_eq!(
+            Interval::try_new(Open(2.), Closed(1.)),
+            Err(IntervalError::ReversedBounds)
+        );
+        assert_eq!(
+            Interval::try_new(Open(1.), Open(1.)),
+            Err(IntervalError::Empty)
+        );
+        assert_eq!(
+            Interval::try_new(Closed(f64::NAN), Unbound),
+            Err(IntervalError::NanEndpoint)
+        );
+    }
+
+    #[test]
+    fn test_spanning_1() {
+        assert_eq!(Interval::spanning(1., 1.), Interval::singleton(1.));
+        assert_eq!(
+            Interval::spanning(f64::INFINITY, 0.),
+            Interval::new(Closed(0.), Unbound)
+        );
+        assert_eq!(Interval::spanning(f64::NAN, 0.), EMPTY);
+        assert_eq!(Interval::spanning(0., f64::NAN), EMPTY);
+    }
+
+    #[test]
+    fn test_gap_between_1() {
+        let a = Interval::new(Unbound, Open(1.));
+        let b = Interval::new(Open(1.), Unbound);
+        assert_eq!(a.gap_between(b), Some(Interval::singleton(1.)));
+        let c = Interval::new(Closed(3.), Unbound);
+        assert_eq!(a.gap_between(c), Some(Interval::new(Closed(1.), Open(3.))));
+        assert_eq!(b.gap_between(c), None);
+        assert_eq!(a.gap_between(EMPTY), None);
+        assert_eq!(EMPTY.gap_between(EMPTY), None);
+    }
+
+    #[test]
+    fn test_is_left_closed_1() {
+        let a = Interval::new(Open(0.), Closed(1.));
+        assert!(a.is_left_open() && !a.is_left_closed());
+        assert!(a.is_right_closed() && !a.is_right_open());
+        assert!(Interval::singleton(1.).is_left_closed());
+        assert!(!EMPTY.is_left_open() && !EMPTY.is_right_open());
+        assert!(!INFINITY.is_right_open() && !INFINITY.is_right_closed());
+    }
+
+    #[test]
+    fn test_into_bounds_1() {
+        for a in [
+            EMPTY,
+            INFINITY,
+            Interval::singleton(1.),
+            Interval::new(Open(0.), Closed(1.)),
+            Interval::new(Unbound, Open(1.)),
+        ] {
+            assert_eq!(Interval::from_bound_pair(a.into_bounds()), a);
+        }
+        assert_eq!(EMPTY.into_bounds(), (Open(0.), Open(0.)));
+        assert_eq!(Interval::from_bound_pair((Closed(2.), Closed(1.))), EMPTY);
+    }
+
+    #[test]
+    fn test_kind_1() {
+        assert_eq!(EMPTY.kind(), IntervalKind::Empty);
+        assert_eq!(INFINITY.kind(), IntervalKind::Universe);
+        assert_eq!(Interval::singleton(0.).kind(), IntervalKind::Singleton);
+        assert_eq!(
+            Interval::new(Open(0.), Closed(1.)).kind(),
+            IntervalKind::Bounded
+        );
+        assert_eq!(
+            Interval::new(Unbound, Closed(1.)).kind(),
+            IntervalKind::LeftUnbounded
+        );
+        assert_eq!(
+            Interval::new(Open(0.), Unbound).kind(),
+            IntervalKind::RightUnbounded
+        );
+    }
+
+    #[test]
+    fn test_approx_eq_1() {
+        let a = Interval::new(Closed(0.), Open(1.));
+        assert!(a.approx_eq(Interval::new(Closed(1e-10), Open(1.)), 1e-9));
+        assert!(!a.approx_eq(Interval::new(Closed(1e-8), Open(1.)), 1e-9));
+        assert!(!a.approx_eq(Interval::new(Closed(0.), Closed(1.)), 1e-9));
+        assert!(a.approx_eq_ignore_openness(Interval::new(Open(0.), Closed(1.)), 0.));
+        assert!(!a.approx_eq_ignore_openness(Interval::new(Unbound, Closed(1.)), 1.));
+        assert!(!a.approx_eq(EMPTY, 1.));
+        assert!(EMPTY.approx_eq(EMPTY, 0.));
+        assert!(INFINITY.approx_eq(INFINITY, 0.));
+    }
+
+    #[test]
+    fn test_try_from_1() {
+        assert_eq!(f64::try_from(Interval::singleton(-1.)), Ok(-1.));
+        assert_eq!(f64::try_from(EMPTY), Err(IntervalError::NotSingleton));
+        assert_eq!(f64::try_from(INFINITY), Err(IntervalError::NotSingleton));
+        let x: Result<f64, _> = Interval::new(Closed(1.), Closed(1.)).try_into();
+        assert_eq!(x, Ok(1.));
+    }
+
+    #[test]
+    fn test_contains_all_1() {
+        let a = Interval::new(Closed(0.), Open(1.));
+        assert!(a.contains_all([]));
+        assert!(!a.contains_any([]));
+        assert!(!a.contains_all([0., f64::NAN]));
+        let mut visited = 0;
+        assert!(!a.contains_all([2., 0.5, 0.5].into_iter().inspect(|_| visited += 1)));
+        assert_eq!(visited, 1);
+        assert!(!EMPTY.contains_any([0.]));
+    }
+
+    #[test]
+    fn test_count_contained_1() {
+        let points: Vec<f64> = (0..100).map(|k| k as f64 / 10.).collect();
+        for a in [
+            Interval::new(Closed(1.), Open(2.)),
+            Interval::new(Open(1.), Closed(2.)),
+            Interval::new(Unbound, Open(5.)),
+            Interval::new(Closed(9.5), Unbound),
+            Interval::singleton(3.),
+            Interval::new(Closed(20.), Closed(30.)),
+            INFINITY,
+            EMPTY,
+        ] {
+            assert_eq!(
+                a.count_contained_sorted(&points),
+                a.count_contained(&points)
+            );
+        }
+        assert_eq!(
+            Interval::new(Closed(1.), Open(2.)).count_contained(&points),
+            10
+        );
+        assert_eq!(INFINITY.count_contained_sorted(&[]), 0);
+    }
+
+    #[test]
+    fn test_partition_points_1() {
+        let a = Interval::new(Open(0.), Unbound);
+        let (inside, outside) = a.partition_points(&[1., f64::NAN, 0., 2.]);
+        assert_eq!(inside, vec![1., 2.]);
+        assert_eq!(outside.len(), 2);
+        assert!(outside[0].is_nan());
+        assert_eq!(EMPTY.partition_points(&[1.]), (vec![], vec![1.]));
+    }
+
+    #[test]
+    fn test_probability_1() {
+        let uniform = |x: f64| x.clamp(0., 1.);
+        assert_eq!(EMPTY.probability(uniform), 0.);
+        assert_eq!(INFINITY.probability(|_| f64::NAN), 1.);
+        assert_eq!(
+            Interval::new(Open(0.25), Closed(0.75)).probability(uniform),
+            0.5
+        );
+        assert_eq!(Interval::new(Unbound, Open(0.5)).probability(uniform), 0.5);
+        assert_eq!(Interval::singleton(0.5).probability(uniform), 0.);
+    }
+
+    #[test]
+    fn test_bisect_root_1() {
+        let f = |x: f64| x.cos() - x;
+        assert_eq!(EMPTY.bisect_root(f, 1e-6), None);
+        assert_eq!(
+            Interval::new(Closed(0.), Unbound).bisect_root(f, 1e-6),
+            None
+        );
+        assert_eq!(
+            Interval::new(Closed(2.), Closed(3.)).bisect_root(f, 1e-6),
+            None
+        );
+        assert_eq!(
+            Interval::new(Open(0.), Closed(1.)).bisect_root(|x| x, 1e-6),
+            Some(Interval::singleton(0.))
+        );
+        let a = Interval::new(Closed(0.), Closed(1.))
+            .bisect_root(f, 1e-12)
+            .unwrap();
+        assert!(a.contains(0.739_085_133_215_160_6));
+        assert!(a.width() <= 1e-12);
+    }
+
+    #[test]
+    fn test_bisect_root_2() {
+        let a = Interval::new(Closed(1.), Closed(2.))
+            .bisect_root(|x| x - 1.5, 0.)
+            .unwrap();
+        assert_eq!(a, Interval::singleton(1.5));
+        let b = Interval::new(Closed(0.), Closed(3.))
+            .bisect_root(|x| x * x - 2., 0.)
+            .unwrap();
+        assert!(b.contains(2f64.sqrt()));
+        assert!(b.width() <= 2. * f64::EPSILON);
+    }
+
+    #[test]
+    fn test_map_monotonic_1() {
+        let neg = |x: f64| -x;
+        assert_eq!(EMPTY.map_monotonic(neg, Monotonicity::Decreasing), EMPTY);
+        assert_eq!(
+            INFINITY.map_monotonic(neg, Monotonicity::Decreasing),
+            INFINITY
+        );
+        assert_eq!(
+            Interval::new(Open(1.), Unbound).map_monotonic(neg, Monotonicity::Decreasing),
+            Interval::new(Unbound, Open(-1.))
+        );
+        assert_eq!(
+            Interval::new(Closed(0.), Open(1.))
+                .map_monotonic(|x| 2. * x + 1., Monotonicity::Increasing),
+            Interval::new(Closed(1.), Open(3.))
+        );
+    }
+
+    #[test]
+    fn test_map_monotonic_2() {
+        let a =
+            Interval::new(Closed(0.), Unbound).map_monotonic(f64::atan, Monotonicity::Increasing);
+        assert_eq!(
+            a,
+            Interval::new(Closed(0.), Open(std::f64::consts::FRAC_PI_2))
+        );
+        let b =
+            Interval::new(Unbound, Closed(-1.)).map_monotonic(f64::sqrt, Monotonicity::Increasing);
+        assert_eq!(b, EMPTY);
+        assert_eq!(
+            Interval::singleton(4.).map_monotonic(f64::sqrt, Monotonicity::Increasing),
+            Interval::singleton(2.)
+        );
+    }
+
+    #[test]
+    fn test_map_monotonic_3() {
+        let a = Interval::new(Closed(0.), Closed(1.));
+        assert_eq!(
+            a.map_monotonic(f64::ln, Monotonicity::Increasing),
+            Interval::new(Unbound, Closed(0.))
+        );
+        assert_eq!(
+            a.map_monotonic(|x| 1. / x, Monotonicity::Decreasing),
+            Interval::new(Closed(1.), Unbound)
+        );
+        assert_eq!(
+            Interval::new(Open(0.), Closed(1000.))
+                .map_monotonic(f64::exp, Monotonicity::Increasing),
+            Interval::new(Open(1.), Unbound)
+        );
+        assert_eq!(
+            Interval::singleton(0.).map_monotonic(f64::ln, Monotonicity::Increasing),
+            EMPTY
+        );
+    }
+
+    #[test]
+    fn test_image_1() {
+        let f = crate::arith::sqr;
+        assert!(EMPTY.image(f, 3).is_empty());
+        assert_eq!(
+            INFINITY.image(f, 3),
+            IntervalSet::from(&[Interval::new(Closed(0.), Unbound)])
+        );
+        let a = Interval::new(Closed(-2.), Closed(2.));
+        assert_eq!(a.image(f, 0).hull(), Interval::new(Closed(0.), Closed(4.)));
+        let g = |x: Interval| crate::arith::sub(crate::arith::sqr(x), x);
+        assert_eq!(a.image(g, 0).hull(), Interval::new(Closed(-2.), Closed(6.)));
+        assert_eq!(
+            a.image(g, 3).hull(),
+            Interval::new(Closed(-0.75), Closed(6.))
+        );
+    }
+
+    #[test]
+    fn test_image_2() {
+        let f = |x: Interval| match x.endpoints() {
+            Some((a, _)) if a < 0. => Interval::singleton(-1.),
+            Some(_) => Interval::singleton(1.),
+            None => EMPTY,
+        };
+        let a = Interval::new(Closed(-1.), Closed(1.));
+        assert_eq!(
+            a.image(f, 1),
+            IntervalSet::from(&[Interval::singleton(-1.), Interval::singleton(1.)])
+        );
+    }
+
+    #[test]
+    fn test_from_str_1() {
+        assert_eq!("{42}".parse(), Ok(Interval::singleton(42.)));
+        assert_eq!(
+            " ( 1 , inf ] ".parse(),
+            Ok(Interval::new(Open(1.), Unbound))
+        );
+        assert_eq!("(-inf,+∞)".parse(), Ok(INFINITY));
+        assert_eq!(
+            "[2,1]".parse::<Interval>(),
+            Err(IntervalError::ReversedBounds)
+        );
+        assert_eq!("[1,1)".parse::<Interval>(), Err(IntervalError::Empty));
+        assert_eq!(
+            "[NaN,1]".parse::<Interval>(),
+            Err(IntervalError::NanEndpoint)
+        );
+        for text in ["", "[", "[1]", "<1,2>", "[a,2]", "[1,2,3]", "[1,2]x"] {
+            assert_eq!(
+                text.parse::<Interval>(),
+                Err(IntervalError::Parse(text.to_string()))
+            );
+        }
+    }
+
+    #[test]
+    fn test_from_str_2() {
+        for a in [
+            EMPTY,
+            INFINITY,
+            Interval::singleton(-3.),
+            Interval::new(Closed(0.), Open(8.)),
+            Interval::new(Unbound, Closed(2.)),
+            Interval::new(Open(-1.), Unbound),
+        ] {
+            assert_eq!(a.to_string().parse(), Ok(a));
+        }
+        let a = Interval::new(Open(0.125), Closed(1. / 3.));
+        assert_eq!(format!("{a:e}").parse(), Ok(a));
+        assert_eq!(format!("{a:E}").parse(), Ok(a));
+    }
+
+    #[test]
+    fn test_from_str_3() {
+        for text in [
+            "[inf,1]",
+            "[0,-inf]",
+            "(+∞,+∞)",
+            "[-∞,-∞]",
+            "(∞,0)",
+            "[-1,-∞)",
+        ] {
+            assert_eq!(text.parse::<Interval>(), Err(IntervalError::ReversedBounds));
+        }
+        assert_eq!("[-inf,+inf]".parse(), Ok(INFINITY));
+        assert_eq!(
+            "{inf}".parse::<Interval>(),
+            Err(IntervalError::Parse("{inf}".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_union_many_1() {
+        assert!(Interval::union_many([]).is_empty());
+        assert!(Interval::union_many([EMPTY, EMPTY]).is_empty());
+        let a = Interval::new(Closed(0.), Open(1.));
+        let b = Interval::new(Open(1.), Closed(2.));
+        assert_eq!(Interval::union_many([b, a]), IntervalSet::from(&[a, b]));
+        assert_eq!(
+            Interval::union_many([b, INFINITY, a]),
+            IntervalSet::from(&[INFINITY])
+        );
+    }
+
+    #[test]
+    fn test_intersect_many_1() {
+        let a = Interval::new(Closed(0.), Closed(10.));
+        let b = Interval::new(Open(5.), Closed(20.));
+        assert_eq!(
+            Interval::intersect_many([a, b, a]),
+            Interval::new(Open(5.), Closed(10.))
+        );
+        let mut calls = 0;
+        let res = Interval::intersect_many(
+            [a, Interval::singleton(15.), b, a]
+                .into_iter()
+                .inspect(|_| calls += 1),
+        );
+        assert_eq!(res, EMPTY);
+        assert_eq!(calls, 2);
+        assert_eq!(Interval::intersect_many([EMPTY]), EMPTY);
+    }
+
+    #[test]
+    fn test_exp_1() {
+        let a = Interval::new(Open(-1.5e-300), Unbound);
+        assert_eq!(format!("{a:e}"), "(-1.5e-300,+∞)");
+        assert_eq!(format!("{a:.0E}"), "(-2E-300,+∞)");
+        assert_eq!(format!("{EMPTY:e}"), "∅");
+        assert_eq!(format!("{INFINITY:E}"), "(-∞,+∞)");
+        let b = Interval::new(Closed(1e-9), Closed(2.5e12));
+        assert_eq!(format!("{b:e}").parse(), Ok(b));
+    }
+
+    #[test]
+    fn test_widen_1() {
+        let a = Interval::new(Closed(0.), Closed(1.));
+        assert_eq!(a.widen(a, &[]), a);
+        assert_eq!(a.widen(EMPTY, &[]), a);
+        assert_eq!(EMPTY.widen(a, &[]), a);
+        let b = Interval::new(Open(-1.), Closed(0.5));
+        assert_eq!(b.widen(a, &[]), Interval::new(Unbound, Closed(1.)));
+        assert_eq!(
+            b.widen(a, &[-10., -1., f64::NAN, -0.5]),
+            Interval::new(Closed(-1.), Closed(1.))
+        );
+        let c = Interval::new(Closed(0.), Open(10.));
+        assert_eq!(
+            c.widen(a, &[5., 10., 20.]),
+            Interval::new(Closed(0.), Closed(10.))
+        );
+    }
+
+    #[test]
+    fn test_widen_2() {
+        // Fixpoint of x = [0, 0] U (x + 1) with x < 100, widening then narrowing
+        let step = |x: Interval| {
+            let shifted = x.map_monotonic(|k| k + 1., Monotonicity::Increasing);
+            Interval::singleton(0.).hull(shifted.intersection(Interval::new(Unbound, Open(100.))))
+        };
+        let mut x = Interval::singleton(0.);
+        loop {
+            let next = step(x).widen(x, &[]);
+            if next == x {
+                break;
+            }
+            x = next;
+        }
+        assert_eq!(x, Interval::new(Closed(0.), Unbound));
+        assert_eq!(x.narrow(step(x)), Interval::new(Closed(0.), Open(100.)));
+    }
+
+    #[test]
+    fn test_narrow_1() {
+        let a = Interval::new(Closed(0.), Closed(1.));
+        assert_eq!(a.narrow(Interval::new(Closed(0.5), Closed(0.7))), a);
+        assert_eq!(INFINITY.narrow(a), a);
+        assert_eq!(a.narrow(EMPTY), EMPTY);
+        assert_eq!(
+            Interval::new(Unbound, Closed(1.)).narrow(Interval::new(Open(-2.), Unbound)),
+            Interval::new(Open(-2.), Closed(1.))
+        );
+    }
+
+    #[test]
+    fn test_partition_by_1() {
+        let a = Interval::new(Open(0.), Closed(10.));
+        let set = IntervalSet::from(&[
+            Interval::new(Unbound, Closed(0.)),
+            Interval::singleton(5.),
+            Interval::new(Open(10.), Unbound),
+        ]);
+        let (covered, uncovered) = a.partition_by(&set);
+        assert_eq!(covered, IntervalSet::from(&[Interval::singleton(5.)]));
+        assert_eq!(
+            uncovered,
+            IntervalSet::from(&[
+                Interval::new(Open(0.), Open(5.)),
+                Interval::new(Open(5.), Closed(10.)),
+            ])
+        );
+        let (covered, uncovered) = a.partition_by(&IntervalSet::new());
+        assert!(covered.is_empty());
+        assert_eq!(uncovered, IntervalSet::from(&[a]));
+        let (covered, uncovered) = EMPTY.partition_by(&set);
+        assert!(covered.is_empty() && uncovered.is_empty());
+        let (covered, uncovered) = a.partition_by(&IntervalSet::from(&[INFINITY]));
+        assert_eq!(covered, IntervalSet::from(&[a]));
+        assert!(uncovered.is_empty());
+    }
+
+    #[test]
+    fn test_difference_1() {
+        let a = Interval::new(Closed(0.), Closed(10.));
+        assert_eq!(a.difference(EMPTY), (a, None));
+        assert_eq!(EMPTY.difference(a), (EMPTY, None));
+        assert_eq!(a.difference(INFINITY), (EMPTY, None));
+        assert_eq!(a.difference(Interval::singleton(20.)), (a, None));
+        assert_eq!(
+            a.difference(Interval::singleton(10.)),
+            (Interval::new(Closed(0.), Open(10.)), None)
+        );
+        assert_eq!(a.difference(Interval::new(Unbound, Open(0.))), (a, None));
+        assert_eq!(
+            a.difference(Interval::new(Unbound, Closed(0.))),
+            (Interval::new(Open(0.), Closed(10.)), None)
+        );
+        assert_eq!(
+            INFINITY.difference(Interval::singleton(0.)),
+            (
+                Interval::new(Unbound, Open(0.)),
+                Some(Interval::new(Open(0.), Unbound))
+            )
+        );
+    }
+
+    #[test]
+    fn test_difference_2() {
+        let a = Interval::new(Closed(0.), Unbound);
+        assert_eq!(
+            a.difference(Interval::new(Closed(1.), Unbound)),
+            (Interval::new(Closed(0.), Open(1.)), None)
+        );
+        assert_eq!(
+            a.difference(Interval::new(Unbound, Closed(1.))),
+            (Interval::new(Open(1.), Unbound), None)
+        );
+        assert_eq!(a.difference(INFINITY), (EMPTY, None));
+        assert_eq!(INFINITY.difference(INFINITY), (EMPTY, None));
+        assert_eq!(
+            INFINITY.difference(a),
+            (Interval::new(Unbound, Open(0.)), None)
+        );
+        assert_eq!(
+            Interval::new(Unbound, Closed(3.)).difference(INFINITY),
+            (EMPTY, None)
+        );
+        assert_eq!(
+            INFINITY.difference(Interval::new(Closed(0.), Closed(1.))),
+            (
+                Interval::new(Unbound, Open(0.)),
+                Some(Interval::new(Open(1.), Unbound))
+            )
+        );
+    }
+
+    #[test]
+    fn test_is_disjoint_1() {
+        let a = Interval::new(Closed(0.), Closed(1.));
+        assert!(!a.is_disjoint(Interval::new(Closed(1.), Closed(2.))));
+        assert!(a.is_disjoint(Interval::new(Open(1.), Closed(2.))));
+        assert!(a.is_disjoint(EMPTY) && EMPTY.is_disjoint(EMPTY));
+        assert!(!a.is_disjoint(INFINITY));
+        assert!(Interval::new(Unbound, Open(0.)).is_disjoint(Interval::new(Closed(0.), Unbound)));
+    }
+
+    #[test]
+    fn test_overlaps_1() {
+        let intervals = [
+            EMPTY,
+            INFINITY,
+            Interval::singleton(1.),
+            Interval::new(Closed(0.), Open(1.)),
+            Interval::new(Open(1.), Closed(2.)),
+            Interval::new(Closed(1.), Unbound),
+            Interval::new(Unbound, Open(0.)),
+        ];
+        for a in intervals {
+            for b in intervals {
+                assert_eq!(a.overlaps(b), !a.is_disjoint(b), "{a} {b}");
+                assert_eq!(a.is_adjacent_to(b), b.is_adjacent_to(a), "{a} {b}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_inf_sup_1() {
+        let a = Interval::new(Closed(-1.), Open(2.));
+        assert_eq!((a.inf(), a.sup()), (Some(-1.), Some(2.)));
+        assert_eq!((INFINITY.inf(), INFINITY.sup()), (None, None));
+        assert_eq!(EMPTY.sup(), None);
+        let b = Interval::new(Unbound, Closed(3.));
+        assert_eq!((b.inf(), b.sup()), (None, Some(3.)));
+        assert_eq!(Interval::singleton(5.).inf(), Some(5.));
+    }
+
+    #[test]
+    fn test_split_at_1() {
+        let a = Interval::new(Open(0.), Unbound);
+        assert_eq!(a.split_at(0., Side::Lower), (EMPTY, a));
+        assert_eq!(a.split_at(0., Side::Upper), (EMPTY, a));
+        assert_eq!(a.split_at(f64::INFINITY, Side::Upper), (a, EMPTY));
+        assert_eq!(a.split_at(f64::NAN, Side::Upper), (a, EMPTY));
+        assert_eq!(
+            INFINITY.split_at(1., Side::Upper),
+            (
+                Interval::new(Unbound, Open(1.)),
+                Interval::new(Closed(1.), Unbound)
+            )
+        );
+        let b = Interval::singleton(1.);
+        assert_eq!(b.split_at(1., Side::Lower), (b, EMPTY));
+        assert_eq!(b.split_at(1., Side::Upper), (EMPTY, b));
+        assert_eq!(EMPTY.split_at(1., Side::Upper), (EMPTY, EMPTY));
+    }
+
+    #[test]
+    fn test_clamp_1() {
+        let a = Interval::new(Open(0.), Closed(1.));
+        assert_eq!(a.clamp(0.), Some(next_up(0.)));
+        assert!(a.contains(a.clamp(-1.).unwrap()));
+        assert_eq!(a.clamp(f64::INFINITY), Some(1.));
+        assert_eq!(a.clamp(f64::NAN), None);
+        assert_eq!(INFINITY.clamp(-1e300), Some(-1e300));
+        assert_eq!(Interval::singleton(2.).clamp(5.), Some(2.));
+        assert_eq!(Interval::new(Open(1.), Open(next_up(1.))).clamp(1.), None);
+    }
+
+    #[test]
+    fn test_next_up_1() {
+        assert_eq!(next_up(1.), 1. + f64::EPSILON);
+        assert_eq!(next_down(1.), 1. - f64::EPSILON / 2.);
+        assert_eq!(next_up(0.), f64::from_bits(1));
+        assert_eq!(next_up(-0.), f64::from_bits(1));
+        assert_eq!(next_down(0.), -f64::from_bits(1));
+        assert_eq!(next_up(-f64::from_bits(1)), 0.);
+        assert_eq!(next_up(f64::MAX), f64::INFINITY);
+        assert_eq!(next_up(f64::NEG_INFINITY), -f64::MAX);
+        assert_eq!(next_up(f64::INFINITY), f64::INFINITY);
+        assert_eq!(next_down(f64::NEG_INFINITY), f64::NEG_INFINITY);
+        assert!(next_up(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_distance_1() {
+        let a = Interval::new(Unbound, Closed(0.));
+        assert_eq!(a.distance_to_point(-1e300), 0.);
+        assert_eq!(a.distance_to_point(2.), 2.);
+        assert_eq!(a.distance_to_point(f64::INFINITY), f64::INFINITY);
+        assert!(a.distance_to_point(f64::NAN).is_nan());
+        assert_eq!(EMPTY.distance_to_point(0.), f64::INFINITY);
+        assert_eq!(INFINITY.distance_to_point(5.), 0.);
+    }
+
+    #[test]
+    fn test_distance_2() {
+        let a = Interval::new(Unbound, Closed(0.));
+        let b = Interval::new(Open(2.), Unbound);
+        assert_eq!(a.distance_to(b), 2.);
+        assert_eq!(b.distance_to(a), 2.);
+        assert_eq!(a.distance_to(INFINITY), 0.);
+        assert_eq!(a.distance_to(EMPTY), f64::INFINITY);
+        assert_eq!(
+            Interval::singleton(1.).distance_to(Interval::singleton(-1.)),
+            2.
+        );
+    }
+
+    #[test]
+    fn test_translate_2() {
+        let a = Interval::new(Open(-1.), Unbound);
+        assert_eq!(a.translate(-1.), Interval::new(Open(-2.), Unbound));
+        assert_eq!(INFINITY.translate(5.), INFINITY);
+        assert_eq!(EMPTY.translate(5.), EMPTY);
+        assert_eq!(
+            Interval::singleton(1.).translate(0.5),
+            Interval::singleton(1.5)
+        );
+    }
+
+    #[test]
+    fn test_reflect_1() {
+        let a = Interval::new(Closed(-2.), Open(5.));
+        assert_eq!(a.reflect(1.), Interval::new(Open(-3.), Closed(4.)));
+        assert_eq!(a.reflect(1.).reflect(1.), a);
+        assert_eq!(INFINITY.reflect(3.), INFINITY);
+        assert_eq!(EMPTY.reflect(3.), EMPTY);
+        assert_eq!(
+            Interval::new(Open(0.), Unbound).reflect(0.),
+            Interval::new(Unbound, Open(0.))
+        );
+    }
+
+    #[test]
+    fn test_scale_1() {
+        let a = Interval::new(Open(1.), Closed(2.));
+        assert_eq!(a.scale(1., 7.), a);
+        assert_eq!(a.scale(-1., 0.), a.reflect(0.));
+        assert_eq!(a.scale(3., 1.), Interval::new(Open(1.), Closed(4.)));
+        assert_eq!(a.scale(f64::NAN, 1.), EMPTY);
+        assert_eq!(EMPTY.scale(0., 1.), EMPTY);
+        assert_eq!(INFINITY.scale(0., 1.), Interval::singleton(1.));
+        assert_eq!(
+            Interval::new(Unbound, Open(0.)).scale(-2., 1.),
+            Interval::new(Open(3.), Unbound)
+        );
+    }
+
+    #[test]
+    fn test_scale_2() {
+        let inf = f64::INFINITY;
+        let a = Interval::new(Closed(0.), Closed(10.));
+        assert_eq!(a.scale(inf, 0.), Interval::new(Closed(0.), Unbound));
+        assert_eq!(a.scale(inf, 5.), INFINITY);
+        assert_eq!(a.scale(-inf, 10.), Interval::new(Closed(10.), Unbound));
+        assert_eq!(a.scale(inf, 20.), EMPTY);
+        assert_eq!(
+            Interval::new(Open(0.), Open(10.)).scale(inf, 0.),
+            Interval::new(Open(0.), Unbound)
+        );
+        assert_eq!(
+            Interval::singleton(3.).scale(inf, 3.),
+            Interval::singleton(3.)
+        );
+        assert_eq!(
+            Interval::new(Closed(-f64::MAX), Closed(1.)).scale(2., 0.),
+            Interval::new(Unbound, Closed(2.))
+        );
+    }
+
+    #[test]
+    fn test_left_right_1() {
+        let a = Interval::new(Unbound, Open(2.));
+        assert_eq!((a.left(), a.right()), a.into_bounds());
+        assert!(matches!(a.left(), Unbound));
+        assert!(matches!(a.right(), Open(k) if k == 2.));
+        assert!(matches!(EMPTY.left(), Open(k) if k == 0.));
+        assert!(matches!(INFINITY.right(), Unbound));
+    }
+
+    #[test]
+    fn test_with_left_1() {
+        let a = Interval::new(Open(0.), Closed(1.));
+        assert_eq!(a.with_left(Unbound), Interval::new(Unbound, Closed(1.)));
+        assert_eq!(a.with_left(Closed(1.)), Interval::singleton(1.));
+        assert_eq!(a.with_left(Open(1.)), EMPTY);
+        assert_eq!(a.with_left(Closed(f64::NAN)), EMPTY);
+        assert_eq!(EMPTY.with_left(Unbound), EMPTY);
+    }
+
+    #[test]
+    fn test_with_right_1() {
+        let a = Interval::new(Unbound, Closed(1.));
+        assert_eq!(a.with_right(Unbound), INFINITY);
+        assert_eq!(a.with_right(Open(-1.)), Interval::new(Unbound, Open(-1.)));
+        assert_eq!(
+            INFINITY.with_right(Closed(0.)),
+            Interval::new(Unbound, Closed(0.))
+        );
+        assert_eq!(EMPTY.with_right(Unbound), EMPTY);
+    }
+
+    #[test]
+    fn test_is_separated_from_1() {
+        let a = Interval::new(Unbound, Closed(0.));
+        assert!(!a.is_separated_from(Interval::new(Open(0.), Closed(1.))));
+        assert!(a.is_separated_from(Interval::new(Open(0.5), Closed(1.))));
+        assert!(!a.is_separated_from(Interval::singleton(0.)));
+        assert!(!a.is_separated_from(INFINITY));
+        assert!(a.is_separated_from(EMPTY) && EMPTY.is_separated_from(EMPTY));
+        for (b, c) in [
+            (
+                Interval::new(Closed(0.), Open(1.)),
+                Interval::new(Closed(1.), Closed(2.)),
+            ),
+            (
+                Interval::new(Closed(0.), Open(1.)),
+                Interval::new(Open(1.), Closed(2.)),
+            ),
+            (
+                Interval::new(Closed(0.), Closed(1.)),
+                Interval::new(Closed(3.), Closed(4.)),
+            ),
+        ] {
+            assert_eq!(b.is_separated_from(c), b.union(c).1.is_some());
+        }
+    }
 }
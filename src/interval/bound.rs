@@ -1,4 +1,7 @@
+/// Endpoint of an interval
+///
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Bound {
     Open(f64),
     Closed(f64),
@@ -7,6 +10,12 @@ pub enum Bound {
 
 use Bound::*;
 
+impl Bound {
+    pub(crate) fn is_nan(self) -> bool {
+        matches!(self, Open(k) | Closed(k) if k.is_nan())
+    }
+}
+
 impl PartialEq for Bound {
     fn eq(&self, other: &Self) -> bool {
         match (self, other) {
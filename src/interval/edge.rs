@@ -0,0 +1,175 @@
+use std::cmp::Ordering;
+
+use super::bound::Bound::{Closed, Open, Unbound};
+use super::{Interval, Left, Right};
+
+/// Position on the real line between two consecutive points
+///
+/// `Edge { value: k, after: false }` lies just before `k` and `Edge { value: k, after: true }`
+/// just after it, so that any non-empty interval is the half-open range `[start, end)` of
+/// its edges. Sweeping sorted edges gives exact boolean operations on open and closed
+/// bounds alike.
+///
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Edge {
+    pub value: f64,
+    pub after: bool,
+}
+
+impl Edge {
+    fn key(self) -> (f64, bool) {
+        // adding 0. turns -0. into 0.
+        (self.value + 0., self.after)
+    }
+}
+
+impl PartialEq for Edge {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Edge {}
+
+impl PartialOrd for Edge {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Edge {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let ((k1, after1), (k2, after2)) = (self.key(), other.key());
+        k1.total_cmp(&k2).then(after1.cmp(&after2))
+    }
+}
+
+impl Interval {
+    /// Start and end edges of interval, `None` for the empty set
+    ///
+    pub(crate) fn edges(self) -> Option<(Edge, Edge)> {
+        if self.is_empty() {
+            return None;
+        }
+        let Interval(Left(left), Right(right)) = self;
+        let start = match left {
+            Closed(k) => Edge {
+                value: k,
+                after: false,
+            },
+            Open(k) => Edge {
+                value: k,
+                after: true,
+            },
+            Unbound => Edge {
+                value: f64::NEG_INFINITY,
+                after: false,
+            },
+        };
+        let end = match right {
+            Closed(k) => Edge {
+                value: k,
+                after: true,
+            },
+            Open(k) => Edge {
+                value: k,
+                after: false,
+            },
+            Unbound => Edge {
+                value: f64::INFINITY,
+                after: false,
+            },
+        };
+        Some((start, end))
+    }
+
+    /// Build interval spanning edges `[start, end)`
+    ///
+    pub(crate) fn from_edges(start: Edge, end: Edge) -> Interval {
+        let left = match start {
+            Edge { value, .. } if value == f64::NEG_INFINITY => Unbound,
+            Edge { value, after: true } => Open(value),
+            Edge {
+                value,
+                after: false,
+            } => Closed(value),
+        };
+        let right = match end {
+            Edge { value, .. } if value == f64::INFINITY => Unbound,
+            Edge { value, after: true } => Closed(value),
+            Edge {
+                value,
+                after: false,
+            } => Open(value),
+        };
+        Interval::new(left, right)
+    }
+
+    /// Check if `other` starts exactly where interval ends, leaving neither gap nor overlap
+    ///
+    pub(crate) fn is_continued_by(self, other: Interval) -> bool {
+        match (self.edges(), other.edges()) {
+            (Some((_, end)), Some((start, _))) => end == start,
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::interval::EMPTY;
+
+    #[test]
+    fn test_edges_1() {
+        let intervals = [
+            Interval::new(Closed(1.), Closed(2.)),
+            Interval::new(Open(1.), Closed(2.)),
+            Interval::new(Closed(1.), Open(2.)),
+            Interval::new(Unbound, Open(2.)),
+            Interval::new(Open(1.), Unbound),
+            Interval::singleton(1.),
+            crate::INFINITY,
+        ];
+        for a in intervals {
+            let (start, end) = a.edges().unwrap();
+            assert!(start < end);
+            assert_eq!(Interval::from_edges(start, end), a);
+        }
+        assert!(EMPTY.edges().is_none());
+    }
+
+    #[test]
+    fn test_edges_2() {
+        let (_, a2) = Interval::new(Closed(0.), Open(1.)).edges().unwrap();
+        let (b1, _) = Interval::new(Closed(1.), Closed(2.)).edges().unwrap();
+        let (c1, _) = Interval::new(Open(1.), Closed(2.)).edges().unwrap();
+        assert_eq!(a2, b1);
+        assert!(b1 < c1);
+    }
+
+    #[test]
+    fn test_edges_3() {
+        let a = Edge {
+            value: -0.,
+            after: false,
+        };
+        let b = Edge {
+            value: 0.,
+            after: false,
+        };
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn test_is_continued_by_1() {
+        let a = Interval::new(Closed(0.), Open(1.));
+        let b = Interval::new(Closed(1.), Closed(2.));
+        let c = Interval::new(Open(1.), Closed(2.));
+        assert!(a.is_continued_by(b));
+        assert!(!a.is_continued_by(c));
+        assert!(!b.is_continued_by(a));
+        assert!(Interval::new(Closed(0.), Closed(1.)).is_continued_by(c));
+        assert!(!a.is_continued_by(EMPTY));
+    }
+}
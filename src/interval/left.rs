@@ -95,8 +95,10 @@ impl PartialOrd for Left {
             Some(Ordering::Greater)
         } else if self < other {
             Some(Ordering::Less)
-        } else {
+        } else if self == other {
             Some(Ordering::Equal)
+        } else {
+            None
         }
     }
 }
@@ -130,9 +132,10 @@ impl PartialOrd<Right> for Left {
             Some(Ordering::Greater)
         } else if self < other {
             Some(Ordering::Less)
-        } else {
-            assert!(self == other);
+        } else if self == other {
             Some(Ordering::Equal)
+        } else {
+            None
         }
     }
 }
@@ -575,4 +578,12 @@ mod test {
             assert!(!b1.gt(&bound));
         }
     }
+
+    #[test]
+    fn test_partial_cmp_nan_1() {
+        let nan = Left(Closed(f64::NAN));
+        assert_eq!(nan.partial_cmp(&Left(Closed(1.))), None);
+        assert_eq!(nan.partial_cmp(&Right(Open(1.))), None);
+        assert_eq!(Right(Open(1.)).partial_cmp(&nan), None);
+    }
 }
@@ -94,8 +94,10 @@ impl PartialOrd for Right {
             Some(Ordering::Greater)
         } else if self < other {
             Some(Ordering::Less)
-        } else {
+        } else if self == other {
             Some(Ordering::Equal)
+        } else {
+            None
         }
     }
 }
@@ -130,9 +132,10 @@ impl PartialOrd<Left> for Right {
             Some(Ordering::Greater)
         } else if self < other {
             Some(Ordering::Less)
-        } else {
-            assert!(self == other);
+        } else if self == other {
             Some(Ordering::Equal)
+        } else {
+            None
         }
     }
 }
@@ -0,0 +1,255 @@
+use super::{Interval, EMPTY};
+use std::fmt::Display;
+
+/// Cartesian product of `N` intervals
+///
+/// A box with an empty component is the empty box: all its components are then set to
+/// `EMPTY`, so that every empty box compares equal.
+///
+/// # Example
+///
+/// ```
+/// use interval::{Interval, IntervalBox, Closed};
+///
+/// let b = IntervalBox::new([
+///     Interval::new(Closed(0.), Closed(4.)),
+///     Interval::new(Closed(0.), Closed(1.)),
+/// ]);
+///
+/// assert!(b.contains(&[2., 0.5]));
+/// assert_eq!(b.volume(), 4.);
+/// ```
+///
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct IntervalBox<const N: usize>([Interval; N]);
+
+impl<const N: usize> Display for IntervalBox<N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_empty() {
+            return write!(f, "∅");
+        }
+        for (i, interval) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, " × ")?;
+            }
+            write!(f, "{interval}")?;
+        }
+        Ok(())
+    }
+}
+
+impl<const N: usize> IntervalBox<N> {
+    /// Build box from its components
+    ///
+    pub fn new(intervals: [Interval; N]) -> Self {
+        if intervals.iter().any(|i| i.is_empty()) {
+            IntervalBox([EMPTY; N])
+        } else {
+            IntervalBox(intervals)
+        }
+    }
+
+    /// Components of box
+    ///
+    pub fn intervals(&self) -> &[Interval; N] {
+        &self.0
+    }
+
+    pub fn is_empty(&self) -> bool {
+        N > 0 && self.0[0].is_empty()
+    }
+
+    /// Check if point belongs to box
+    ///
+    pub fn contains(&self, point: &[f64; N]) -> bool {
+        self.0.iter().zip(point).all(|(i, x)| i.contains(*x))
+    }
+
+    /// Check if `other` is a subset of box
+    ///
+    pub fn contains_box(&self, other: &IntervalBox<N>) -> bool {
+        other.is_empty()
+            || self
+                .0
+                .iter()
+                .zip(&other.0)
+                .all(|(a, b)| a.contains_interval(*b))
+    }
+
+    pub fn intersection(&self, other: &IntervalBox<N>) -> Self {
+        let mut res = self.0;
+        for (a, b) in res.iter_mut().zip(&other.0) {
+            *a = a.intersection(*b);
+        }
+        IntervalBox::new(res)
+    }
+
+    /// Compute the smallest box containing both boxes
+    ///
+    pub fn hull(&self, other: &IntervalBox<N>) -> Self {
+        let mut res = self.0;
+        for (a, b) in res.iter_mut().zip(&other.0) {
+            *a = a.hull(*b);
+        }
+        IntervalBox::new(res)
+    }
+
+    /// Product of component widths
+    ///
+    /// Returns `0.` for the empty box and `f64::INFINITY` if some component is unbounded.
+    ///
+    pub fn volume(&self) -> f64 {
+        if self.is_empty() {
+            0.
+        } else {
+            self.0.iter().map(|i| i.width()).product()
+        }
+    }
+
+    /// Index of the component with the largest width
+    ///
+    /// Returns `None` for the empty box (or `N == 0`).
+    ///
+    pub fn widest_dimension(&self) -> Option<usize> {
+        if self.is_empty() {
+            return None;
+        }
+        let mut widest = None;
+        for (i, interval) in self.0.iter().enumerate() {
+            match widest {
+                Some((_, w)) if interval.width() <= w => {}
+                _ => widest = Some((i, interval.width())),
+            }
+        }
+        widest.map(|(i, _)| i)
+    }
+
+    /// Split box in two halves along its widest dimension
+    ///
+    /// The midpoint of the split dimension belongs to the upper half. Returns `None` if
+    /// the widest dimension is unbounded or reduced to a singleton.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, IntervalBox, Closed, Open};
+    ///
+    /// let b = IntervalBox::new([
+    ///     Interval::new(Closed(0.), Closed(1.)),
+    ///     Interval::new(Closed(0.), Closed(4.)),
+    /// ]);
+    /// let (lo, hi) = b.bisect().unwrap();
+    ///
+    /// assert_eq!(lo.intervals()[1], Interval::new(Closed(0.), Open(2.)));
+    /// assert_eq!(hi.intervals()[1], Interval::new(Closed(2.), Closed(4.)));
+    /// ```
+    ///
+    pub fn bisect(&self) -> Option<(Self, Self)> {
+        let dim = self.widest_dimension()?;
+        let (lo, hi) = self.0[dim].bisect()?;
+        let (mut a, mut b) = (self.0, self.0);
+        a[dim] = lo;
+        b[dim] = hi;
+        Some((IntervalBox(a), IntervalBox(b)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Closed, Open, Unbound};
+
+    #[test]
+    fn test_empty_1() {
+        let b = IntervalBox::new([Interval::new(Closed(0.), Closed(1.)), EMPTY]);
+        assert!(b.is_empty());
+        assert_eq!(b, IntervalBox::new([EMPTY, Interval::singleton(42.)]));
+        assert_eq!(b.volume(), 0.);
+    }
+
+    #[test]
+    fn test_contains_1() {
+        let b = IntervalBox::new([
+            Interval::new(Closed(0.), Open(1.)),
+            Interval::new(Unbound, Closed(1.)),
+        ]);
+        assert!(b.contains(&[0., -1e9]));
+        assert!(!b.contains(&[1., 0.]));
+        assert!(!b.contains(&[0.5, 1.5]));
+    }
+
+    #[test]
+    fn test_contains_box_1() {
+        let a = IntervalBox::new([Interval::new(Closed(0.), Closed(10.)); 2]);
+        let b = IntervalBox::new([Interval::new(Closed(2.), Closed(3.)); 2]);
+        assert!(a.contains_box(&b));
+        assert!(!b.contains_box(&a));
+        assert!(b.contains_box(&IntervalBox::new([EMPTY, EMPTY])));
+    }
+
+    #[test]
+    fn test_intersection_1() {
+        let a = IntervalBox::new([Interval::new(Closed(0.), Closed(10.)); 2]);
+        let b = IntervalBox::new([Interval::new(Closed(5.), Closed(15.)); 2]);
+        let c = IntervalBox::new([Interval::new(Closed(20.), Closed(30.)); 2]);
+        assert_eq!(
+            a.intersection(&b),
+            IntervalBox::new([Interval::new(Closed(5.), Closed(10.)); 2])
+        );
+        assert!(a.intersection(&c).is_empty());
+    }
+
+    #[test]
+    fn test_hull_1() {
+        let a = IntervalBox::new([Interval::new(Closed(0.), Closed(1.)); 2]);
+        let b = IntervalBox::new([Interval::new(Closed(5.), Closed(15.)); 2]);
+        assert_eq!(
+            a.hull(&b),
+            IntervalBox::new([Interval::new(Closed(0.), Closed(15.)); 2])
+        );
+        assert_eq!(a.hull(&IntervalBox::new([EMPTY, EMPTY])), a);
+    }
+
+    #[test]
+    fn test_volume_1() {
+        let a = IntervalBox::new([Interval::new(Closed(0.), Closed(3.)); 2]);
+        assert_eq!(a.volume(), 9.);
+        let b = IntervalBox::new([Interval::singleton(1.); 2]);
+        assert_eq!(b.volume(), 0.);
+        let b = IntervalBox::new([Interval::new(Closed(0.), Unbound)]);
+        assert_eq!(b.volume(), f64::INFINITY);
+    }
+
+    #[test]
+    fn test_bisect_1() {
+        let b = IntervalBox::new([
+            Interval::new(Closed(0.), Closed(1.)),
+            Interval::new(Open(0.), Closed(4.)),
+        ]);
+        let (lo, hi) = b.bisect().unwrap();
+        assert_eq!(lo.intervals()[0], b.intervals()[0]);
+        assert_eq!(lo.intervals()[1], Interval::new(Open(0.), Open(2.)));
+        assert_eq!(hi.intervals()[1], Interval::new(Closed(2.), Closed(4.)));
+    }
+
+    #[test]
+    fn test_bisect_2() {
+        let b = IntervalBox::new([
+            Interval::new(Closed(0.), Closed(1.)),
+            Interval::new(Closed(0.), Unbound),
+        ]);
+        assert_eq!(b.bisect(), None);
+        assert_eq!(
+            IntervalBox::new([Interval::singleton(1.); 2]).bisect(),
+            None
+        );
+        assert_eq!(IntervalBox::new([EMPTY]).bisect(), None);
+    }
+
+    #[test]
+    fn test_display_1() {
+        let a = IntervalBox::new([Interval::new(Closed(0.), Closed(1.)); 2]);
+        assert_eq!(format!("{a}"), "[ 0.00, 1.00] × [ 0.00, 1.00]");
+        assert_eq!(format!("{}", IntervalBox::new([EMPTY, EMPTY])), "∅");
+    }
+}
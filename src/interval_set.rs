@@ -1,12 +1,30 @@
-use super::{Interval, INFINITY};
+use super::interval::Edge;
+use super::{Closed, Interval, IntervalError, Open, SnapMode, Unbound, EMPTY, INFINITY};
 use auto_ops::impl_op_ex;
-use std::fmt::Display;
+use std::fmt::{Display, LowerExp, UpperExp};
+use std::str::FromStr;
 
-#[derive(Default, Clone)]
+#[derive(Debug, Default, Clone)]
 pub struct IntervalSet {
     union: Vec<Interval>,
 }
 
+/// Error returned by `IntervalSet::try_insert`, holding the segment overlapped by the
+/// rejected interval
+///
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Conflict {
+    pub segment: Interval,
+}
+
+impl Display for Conflict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "overlaps {}", self.segment)
+    }
+}
+
+impl std::error::Error for Conflict {}
+
 impl Display for IntervalSet {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         if self.is_empty() {
@@ -22,6 +40,86 @@ impl Display for IntervalSet {
     }
 }
 
+/// Display set with endpoints in scientific notation, see `LowerExp` for `Interval`
+///
+/// # Example
+///
+/// ```
+/// use interval::{Interval, IntervalSet, Closed, Open};
+///
+/// let a = IntervalSet::from(&[
+///     Interval::new(Closed(0.), Open(3.2e-9)),
+///     Interval::new(Closed(1.7e12), Closed(2e12)),
+/// ]);
+///
+/// assert_eq!(format!("{a:e}"), "[0e0,3.2e-9) U [1.7e12,2e12]");
+/// assert_eq!(format!("{a:.1E}"), "[0.0E0,3.2E-9) U [1.7E12,2.0E12]");
+/// ```
+///
+impl LowerExp for IntervalSet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.fmt_segments(f, LowerExp::fmt)
+    }
+}
+
+/// Display set with endpoints in scientific notation, with an uppercase `E`
+///
+impl UpperExp for IntervalSet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.fmt_segments(f, UpperExp::fmt)
+    }
+}
+
+impl IntervalSet {
+    /// Write segments separated by `U`, each one formatted by `segment`
+    ///
+    fn fmt_segments(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+        segment: fn(&Interval, &mut std::fmt::Formatter<'_>) -> std::fmt::Result,
+    ) -> std::fmt::Result {
+        if self.is_empty() {
+            return write!(f, "∅");
+        }
+        for (i, interval) in self.union.iter().enumerate() {
+            if i > 0 {
+                write!(f, " U ")?;
+            }
+            segment(interval, f)?;
+        }
+        Ok(())
+    }
+}
+
+/// Parse set written as displayed, intervals separated by `U` (or `∪`) in any order
+///
+/// See `Interval::from_str` for the syntax of intervals.
+///
+/// # Example
+///
+/// ```
+/// use interval::{Interval, IntervalSet, Closed, Open};
+///
+/// let a: IntervalSet = "[20, 24) U [0, 8)".parse().unwrap();
+///
+/// assert_eq!(a, IntervalSet::from(&[
+///     Interval::new(Closed(0.), Open(8.)),
+///     Interval::new(Closed(20.), Open(24.)),
+/// ]));
+/// ```
+///
+impl FromStr for IntervalSet {
+    type Err = IntervalError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let intervals = s
+            .split(['U', '∪'])
+            .map(str::parse)
+            .collect::<Result<_, _>>()?;
+        Ok(IntervalSet::from_unsorted(intervals))
+    }
+}
+
 impl IntervalSet {
     pub fn new() -> Self {
         IntervalSet { union: Vec::new() }
@@ -35,10 +133,94 @@ impl IntervalSet {
         i
     }
 
+    /// Union of closed windows `[p - radius, p + radius]` around each of `points`
+    ///
+    /// Points may be unsorted; overlapping or touching windows are merged. A negative
+    /// `radius` gives the empty set.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, IntervalSet, Closed};
+    ///
+    /// let activity = IntervalSet::from_points(&[10., 1., 2.5], 1.);
+    ///
+    /// assert_eq!(activity, IntervalSet::from(&[
+    ///     Interval::new(Closed(0.), Closed(3.5)),
+    ///     Interval::new(Closed(9.), Closed(11.)),
+    /// ]));
+    /// ```
+    ///
+    pub fn from_points(points: &[f64], radius: f64) -> Self {
+        IntervalSet::from_unsorted(
+            points
+                .iter()
+                .map(|&p| Interval::from_endpoints(p - radius, p + radius))
+                .collect(),
+        )
+    }
+
+    /// Build set from intervals in any order, sorting them first
+    ///
+    /// Runs in `O(n log n)`, where repeated `union_interval` is quadratic.
+    ///
+    pub(crate) fn from_unsorted(mut intervals: Vec<Interval>) -> Self {
+        intervals.retain(|a| !a.is_empty());
+        intervals.sort_by_key(|a| a.edges().map(|(start, _)| start));
+
+        let mut res = IntervalSet::new();
+        for a in intervals {
+            match res.union.last_mut() {
+                Some(last) => match last.union(a) {
+                    (merged, None) => *last = merged,
+                    (_, Some(_)) => res.union.push(a),
+                },
+                None => res.union.push(a),
+            }
+        }
+        res.checked()
+    }
+
     pub fn is_empty(&self) -> bool {
         self.union.len() == 0
     }
 
+    /// Segments of set, in increasing order
+    ///
+    /// Segments are guaranteed to be non-empty, sorted, disjoint and non-adjacent: between
+    /// two consecutive segments lies at least one point out of set, so that `[0, 1)` and
+    /// `[1, 2]` are always stored as `[0, 2]`. Every set has a single such representation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, IntervalSet, Closed, Open};
+    ///
+    /// let a = IntervalSet::from(&[
+    ///     Interval::new(Closed(5.), Closed(6.)),
+    ///     Interval::new(Closed(1.), Open(2.)),
+    ///     Interval::new(Closed(0.), Open(1.)),
+    /// ]);
+    ///
+    /// assert_eq!(a.as_slice(), &[
+    ///     Interval::new(Closed(0.), Open(2.)),
+    ///     Interval::new(Closed(5.), Closed(6.)),
+    /// ]);
+    /// ```
+    ///
+    pub fn as_slice(&self) -> &[Interval] {
+        &self.union
+    }
+
+    /// Smallest interval containing set
+    ///
+    pub fn hull(&self) -> Interval {
+        match (self.union.first(), self.union.last()) {
+            (Some(first), Some(last)) => first.hull(*last),
+            _ => EMPTY,
+        }
+    }
+
     pub fn is_infinity(&self) -> bool {
         self.union.len() == 1 && self.union[0] == INFINITY
     }
@@ -52,7 +234,7 @@ impl IntervalSet {
                 (a, Some(b)) if a == current && b == *segment => {
                     res.union.push(current);
                     res.union.extend_from_slice(&self.union[i..]);
-                    return res;
+                    return res.checked();
                 }
                 (_, Some(_)) => {
                     res.union.push(*segment);
@@ -66,7 +248,7 @@ impl IntervalSet {
         if !current.is_empty() {
             res.union.push(current);
         }
-        res
+        res.checked()
     }
 
     pub fn union_intervals(&self, intervals: &IntervalSet) -> Self {
@@ -76,6 +258,882 @@ impl IntervalSet {
         }
         res
     }
+
+    /// Insert `interval` unless it overlaps a segment of set
+    ///
+    /// Unlike union, overlapping is an error reporting the first conflicting segment.
+    /// Intervals merely touching a segment are merged with it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, IntervalSet, Closed, Open};
+    ///
+    /// let mut bookings = IntervalSet::new();
+    /// let a = Interval::new(Closed(9.), Open(10.));
+    ///
+    /// assert!(bookings.try_insert(a).is_ok());
+    /// assert!(bookings.try_insert(Interval::new(Closed(10.), Open(11.))).is_ok());
+    /// assert_eq!(
+    ///     bookings.try_insert(Interval::new(Closed(10.5), Open(12.))).unwrap_err().segment,
+    ///     Interval::new(Closed(9.), Open(11.))
+    /// );
+    /// ```
+    ///
+    pub fn try_insert(&mut self, interval: Interval) -> Result<(), Conflict> {
+        let conflict = self
+            .union
+            .iter()
+            .find(|segment| !segment.intersection(interval).is_empty());
+        match conflict {
+            Some(segment) => Err(Conflict { segment: *segment }),
+            None => {
+                *self = self.union_interval(&interval);
+                Ok(())
+            }
+        }
+    }
+
+    /// Compute intersection of sets
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, IntervalSet, Closed, Open};
+    ///
+    /// let a = IntervalSet::from(&[Interval::new(Closed(0.), Closed(10.))]);
+    /// let b = IntervalSet::from(&[
+    ///     Interval::new(Closed(-5.), Open(2.)),
+    ///     Interval::new(Open(8.), Closed(20.)),
+    /// ]);
+    /// let c = IntervalSet::from(&[
+    ///     Interval::new(Closed(0.), Open(2.)),
+    ///     Interval::new(Open(8.), Closed(10.)),
+    /// ]);
+    ///
+    /// assert_eq!(a & b, c);
+    /// ```
+    ///
+    pub fn intersection(&self, other: &IntervalSet) -> Self {
+        self.combine(other, |a, b| a && b)
+    }
+
+    /// Points of set not belonging to `other`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, IntervalSet, Closed, Open};
+    ///
+    /// let allocated = IntervalSet::from(&[Interval::new(Closed(0.), Open(100.))]);
+    /// let reserved = IntervalSet::from(&[
+    ///     Interval::new(Closed(0.), Open(10.)),
+    ///     Interval::new(Closed(50.), Closed(60.)),
+    /// ]);
+    /// let free = IntervalSet::from(&[
+    ///     Interval::new(Closed(10.), Open(50.)),
+    ///     Interval::new(Open(60.), Open(100.)),
+    /// ]);
+    ///
+    /// assert_eq!(allocated.difference(&reserved), free);
+    /// assert_eq!(allocated - reserved, free);
+    /// ```
+    ///
+    pub fn difference(&self, other: &IntervalSet) -> Self {
+        self.combine(other, |a, b| a && !b)
+    }
+
+    /// Points belonging to exactly one of both sets
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, IntervalSet, Closed, Open};
+    ///
+    /// let a = IntervalSet::from(&[Interval::new(Closed(0.), Open(10.))]);
+    /// let b = IntervalSet::from(&[Interval::new(Closed(5.), Open(15.))]);
+    /// let c = IntervalSet::from(&[
+    ///     Interval::new(Closed(0.), Open(5.)),
+    ///     Interval::new(Closed(10.), Open(15.)),
+    /// ]);
+    ///
+    /// assert_eq!(a.symmetric_difference(&b), c);
+    /// assert_eq!(a ^ b, c);
+    /// ```
+    ///
+    pub fn symmetric_difference(&self, other: &IntervalSet) -> Self {
+        self.combine(other, |a, b| a != b)
+    }
+
+    /// Check if every point of set belongs to `other`
+    ///
+    /// Sets are compared as sets of points, whatever the way they are split into segments.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, IntervalSet, Closed, Open};
+    ///
+    /// let contract = IntervalSet::from(&[Interval::new(Closed(8.), Open(18.))]);
+    /// let available = IntervalSet::from(&[
+    ///     Interval::new(Closed(9.), Open(12.)),
+    ///     Interval::new(Closed(14.), Open(18.)),
+    /// ]);
+    ///
+    /// assert!(available.is_subset_of(&contract));
+    /// assert!(contract.is_superset_of(&available));
+    /// assert!(!contract.is_subset_of(&available));
+    /// ```
+    ///
+    pub fn is_subset_of(&self, other: &IntervalSet) -> bool {
+        self.difference(other).is_empty()
+    }
+
+    /// Check if every point of `other` belongs to set, see `is_subset_of`
+    ///
+    pub fn is_superset_of(&self, other: &IntervalSet) -> bool {
+        other.is_subset_of(self)
+    }
+
+    /// Total length of set, infinite if set is unbounded
+    ///
+    /// Lengths are added with compensated summation, so that the total doesn't drift over
+    /// many small segments.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, IntervalSet, Closed, Open};
+    ///
+    /// let a = IntervalSet::from(&[
+    ///     Interval::new(Closed(0.), Open(8.)),
+    ///     Interval::new(Closed(20.), Open(24.)),
+    /// ]);
+    ///
+    /// assert_eq!(a.measure(), 12.);
+    /// ```
+    ///
+    pub fn measure(&self) -> f64 {
+        let mut measure = CompensatedSum::default();
+        for segment in &self.union {
+            measure.add(segment.width());
+        }
+        measure.value()
+    }
+
+    /// Integral of `f` over set, by adaptive Simpson quadrature on each segment
+    ///
+    /// The absolute tolerance `tol` is shared among segments in proportion to their length.
+    /// Returns `None` if set is unbounded.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, IntervalSet, Closed, Open};
+    ///
+    /// let on_duty = IntervalSet::from(&[
+    ///     Interval::new(Closed(0.), Open(1.)),
+    ///     Interval::new(Closed(2.), Open(3.)),
+    /// ]);
+    /// let load = on_duty.integrate(|t| t * t, 1e-9).unwrap();
+    ///
+    /// assert!((load - 20. / 3.).abs() < 1e-9);
+    /// ```
+    ///
+    pub fn integrate(&self, f: impl Fn(f64) -> f64, tol: f64) -> Option<f64> {
+        let measure = self.measure();
+        if !measure.is_finite() {
+            return None;
+        }
+        let mut integral = 0.;
+        for (a, b) in self.union.iter().filter_map(|segment| segment.endpoints()) {
+            if a < b {
+                integral += adaptive_simpson(&f, a, b, tol * (b - a) / measure, 48);
+            }
+        }
+        Some(integral)
+    }
+
+    /// Probability mass of set under the cumulative distribution function `cdf`
+    ///
+    /// Sum of `Interval::probability` over segments.
+    ///
+    pub fn probability(&self, cdf: impl Fn(f64) -> f64) -> f64 {
+        let mut probability = CompensatedSum::default();
+        for segment in &self.union {
+            probability.add(segment.probability(&cdf));
+        }
+        probability.value()
+    }
+
+    /// Mean of a point drawn uniformly over set
+    ///
+    /// Returns `None` unless set is bounded with a positive measure.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, IntervalSet, Closed};
+    ///
+    /// let a = IntervalSet::from(&[
+    ///     Interval::new(Closed(0.), Closed(1.)),
+    ///     Interval::new(Closed(3.), Closed(6.)),
+    /// ]);
+    ///
+    /// let b = IntervalSet::from(&[Interval::new(Closed(0.), Closed(1.))]);
+    ///
+    /// assert_eq!(a.uniform_mean(), Some(3.5));
+    /// assert_eq!(b.uniform_variance(), Some(1. / 12.));
+    /// ```
+    ///
+    pub fn uniform_mean(&self) -> Option<f64> {
+        self.uniform_moments().map(|(mean, _)| mean)
+    }
+
+    /// Variance of a point drawn uniformly over set
+    ///
+    /// See `uniform_mean`.
+    ///
+    pub fn uniform_variance(&self) -> Option<f64> {
+        self.uniform_moments().map(|(_, variance)| variance)
+    }
+
+    /// Mean and variance over set, combining those of each segment
+    ///
+    fn uniform_moments(&self) -> Option<(f64, f64)> {
+        let measure = self.measure();
+        if !(measure.is_finite() && measure > 0.) {
+            return None;
+        }
+        let segments: Vec<_> = self
+            .union
+            .iter()
+            .filter_map(|segment| segment.endpoints())
+            .map(|(a, b)| (b - a, (a + b) / 2.))
+            .collect();
+        let mean = segments.iter().map(|(w, m)| w * m).sum::<f64>() / measure;
+        let variance = segments
+            .iter()
+            .map(|(w, m)| w * (w * w / 12. + (m - mean) * (m - mean)))
+            .sum::<f64>()
+            / measure;
+        Some((mean, variance))
+    }
+
+    /// Total length of the intersection of sets
+    ///
+    /// Computed in a single merge pass, without building the intersection.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, IntervalSet, Closed, Open};
+    ///
+    /// let alice = IntervalSet::from(&[
+    ///     Interval::new(Closed(0.), Open(8.)),
+    ///     Interval::new(Closed(20.), Open(24.)),
+    /// ]);
+    /// let bob = IntervalSet::from(&[Interval::new(Closed(6.), Open(22.))]);
+    ///
+    /// assert_eq!(alice.overlap_measure(&bob), 4.);
+    /// ```
+    ///
+    pub fn overlap_measure(&self, other: &IntervalSet) -> f64 {
+        let mut measure = CompensatedSum::default();
+        let (mut i, mut j) = (0, 0);
+
+        while let (Some(a), Some(b)) = (self.union.get(i), other.union.get(j)) {
+            measure.add(a.intersection(*b).width());
+            if a.ends_before(*b) {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        measure.value()
+    }
+
+    /// Compute complement of set in the real line
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, IntervalSet, Closed, Open, Unbound};
+    ///
+    /// let a = IntervalSet::from(&[Interval::new(Closed(0.), Open(10.))]);
+    /// let b = IntervalSet::from(&[
+    ///     Interval::new(Unbound, Open(0.)),
+    ///     Interval::new(Closed(10.), Unbound),
+    /// ]);
+    ///
+    /// assert_eq!(a.complement(), b);
+    /// ```
+    ///
+    pub fn complement(&self) -> Self {
+        self.combine(&IntervalSet::new(), |a, _| !a)
+    }
+
+    /// Widest segment of set, the first one on ties, `None` if set is empty
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, IntervalSet, Closed, Open};
+    ///
+    /// let outages = IntervalSet::from(&[
+    ///     Interval::new(Closed(1.), Open(2.)),
+    ///     Interval::new(Closed(5.), Open(9.)),
+    ///     Interval::new(Closed(12.), Open(12.5)),
+    /// ]);
+    ///
+    /// assert_eq!(outages.largest(), Some(Interval::new(Closed(5.), Open(9.))));
+    /// assert_eq!(outages.smallest(), Some(Interval::new(Closed(12.), Open(12.5))));
+    /// ```
+    ///
+    pub fn largest(&self) -> Option<Interval> {
+        self.union
+            .iter()
+            .rev()
+            .max_by(|a, b| a.width().total_cmp(&b.width()))
+            .copied()
+    }
+
+    /// Narrowest segment of set, the first one on ties, `None` if set is empty
+    ///
+    pub fn smallest(&self) -> Option<Interval> {
+        self.union
+            .iter()
+            .min_by(|a, b| a.width().total_cmp(&b.width()))
+            .copied()
+    }
+
+    /// Remove segments shorter than `min_len`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, IntervalSet, Closed, Open};
+    ///
+    /// let mut on = IntervalSet::from(&[
+    ///     Interval::new(Closed(0.), Open(10.)),
+    ///     Interval::new(Closed(12.), Open(12.01)),
+    ///     Interval::new(Closed(20.), Open(30.)),
+    /// ]);
+    /// on.drop_smaller_than(0.1);
+    ///
+    /// assert_eq!(on, IntervalSet::from(&[
+    ///     Interval::new(Closed(0.), Open(10.)),
+    ///     Interval::new(Closed(20.), Open(30.)),
+    /// ]));
+    /// ```
+    ///
+    pub fn drop_smaller_than(&mut self, min_len: f64) {
+        self.union.retain(|segment| segment.width() >= min_len);
+        *self = std::mem::take(self).checked();
+    }
+
+    /// Remove segments shorter than `min_len`, merging the neighbours of a removed segment
+    /// when the hole left between them is also shorter than `min_len`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, IntervalSet, Closed, Open};
+    ///
+    /// let mut on = IntervalSet::from(&[
+    ///     Interval::new(Closed(0.), Open(10.)),
+    ///     Interval::new(Closed(10.02), Open(10.03)),
+    ///     Interval::new(Closed(10.05), Open(20.)),
+    ///     Interval::new(Closed(25.), Open(25.01)),
+    ///     Interval::new(Closed(30.), Open(40.)),
+    /// ]);
+    /// on.drop_smaller_than_merging(0.1);
+    ///
+    /// assert_eq!(on, IntervalSet::from(&[
+    ///     Interval::new(Closed(0.), Open(20.)),
+    ///     Interval::new(Closed(30.), Open(40.)),
+    /// ]));
+    /// ```
+    ///
+    pub fn drop_smaller_than_merging(&mut self, min_len: f64) {
+        let mut res: Vec<Interval> = Vec::new();
+        let mut dropped = false;
+        for segment in self.union.drain(..) {
+            if segment.width() < min_len {
+                dropped = true;
+                continue;
+            }
+            match res.last_mut() {
+                Some(last)
+                    if dropped
+                        && last
+                            .gap_between(segment)
+                            .is_some_and(|gap| gap.width() < min_len) =>
+                {
+                    *last = last.hull(segment);
+                }
+                _ => res.push(segment),
+            }
+            dropped = false;
+        }
+        *self = IntervalSet { union: res }.checked();
+    }
+
+    /// Over-approximation of set by at most `k` segments, filling the smallest holes
+    ///
+    /// Holes are filled in increasing width, earlier ones first among holes of the same
+    /// width. The set can't be covered by no segment at all, so `k = 0` gives the same
+    /// result as `k = 1`, the hull of the set.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, IntervalSet, Closed, Open};
+    ///
+    /// let on = IntervalSet::from(&[
+    ///     Interval::new(Closed(0.), Open(10.)),
+    ///     Interval::new(Closed(11.), Open(20.)),
+    ///     Interval::new(Closed(30.), Open(40.)),
+    ///     Interval::new(Closed(40.5), Open(50.)),
+    /// ]);
+    ///
+    /// assert_eq!(on.coarsen(2), IntervalSet::from(&[
+    ///     Interval::new(Closed(0.), Open(20.)),
+    ///     Interval::new(Closed(30.), Open(50.)),
+    /// ]));
+    /// ```
+    ///
+    pub fn coarsen(&self, k: usize) -> Self {
+        let n = self.union.len();
+        let k = k.max(1);
+        if n <= k {
+            return self.clone();
+        }
+        let gaps: Vec<f64> = self
+            .union
+            .windows(2)
+            .map(|pair| pair[0].gap_between(pair[1]).map_or(0., Interval::width))
+            .collect();
+        let mut order: Vec<usize> = (0..gaps.len()).collect();
+        order.sort_by(|&i, &j| gaps[i].total_cmp(&gaps[j]).then(i.cmp(&j)));
+        let mut filled = vec![false; gaps.len()];
+        for &i in &order[..n - k] {
+            filled[i] = true;
+        }
+        let mut res: Vec<Interval> = Vec::with_capacity(k);
+        for (i, segment) in self.union.iter().enumerate() {
+            match res.last_mut() {
+                Some(last) if filled[i - 1] => *last = last.hull(*segment),
+                _ => res.push(*segment),
+            }
+        }
+        IntervalSet { union: res }.checked()
+    }
+
+    /// First hole of set at least `min_len` long, not starting before `after`
+    ///
+    /// The hole is clipped so that it does not start before `after`. The hole extending to
+    /// `+∞` is infinitely long.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, IntervalSet, Closed, Open};
+    ///
+    /// let busy = IntervalSet::from(&[
+    ///     Interval::new(Closed(9.), Open(10.)),
+    ///     Interval::new(Closed(10.25), Open(11.)),
+    ///     Interval::new(Closed(12.), Open(13.)),
+    /// ]);
+    ///
+    /// assert_eq!(
+    ///     busy.first_gap_at_least(0.5, 9.),
+    ///     Some(Interval::new(Closed(11.), Open(12.)))
+    /// );
+    /// ```
+    ///
+    pub fn first_gap_at_least(&self, min_len: f64, after: f64) -> Option<Interval> {
+        let from = Interval::new(Closed(after), Unbound);
+        self.complement()
+            .union
+            .into_iter()
+            .map(|hole| hole.intersection(from))
+            .find(|hole| !hole.is_empty() && hole.width() >= min_len)
+    }
+
+    /// Smallest point not lower than `t` that is not covered by set
+    ///
+    /// When `t` lies in a segment closed on the right, no such smallest point exists and the
+    /// right endpoint of the segment (their infimum) is returned. Returns `None` if the set
+    /// covers everything from `t` onward.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, IntervalSet, Closed, Open, Unbound};
+    ///
+    /// let blackout = IntervalSet::from(&[
+    ///     Interval::new(Closed(1.), Open(2.)),
+    ///     Interval::new(Closed(5.), Unbound),
+    /// ]);
+    ///
+    /// assert_eq!(blackout.next_outside(0.5), Some(0.5));
+    /// assert_eq!(blackout.next_outside(1.5), Some(2.));
+    /// assert_eq!(blackout.next_outside(6.), None);
+    /// ```
+    ///
+    pub fn next_outside(&self, t: f64) -> Option<f64> {
+        match self.union.get(self.position(t)) {
+            Some(segment) if segment.contains(t) => match segment.edges() {
+                Some((_, end)) if end.value < f64::INFINITY => Some(end.value),
+                _ => None,
+            },
+            _ => Some(t),
+        }
+    }
+
+    /// Smallest point not lower than `t` that is covered by set
+    ///
+    /// When the next segment is open on the left, no such smallest point exists and its
+    /// left endpoint (their infimum) is returned. Returns `None` if nothing from `t` onward
+    /// is covered.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, IntervalSet, Closed, Open};
+    ///
+    /// let a = IntervalSet::from(&[Interval::new(Closed(1.), Open(2.))]);
+    ///
+    /// assert_eq!(a.next_inside(0.5), Some(1.));
+    /// assert_eq!(a.next_inside(1.5), Some(1.5));
+    /// assert_eq!(a.next_inside(2.), None);
+    /// ```
+    ///
+    pub fn next_inside(&self, t: f64) -> Option<f64> {
+        match self.union.get(self.position(t)) {
+            Some(segment) if segment.contains(t) => Some(t),
+            Some(segment) => segment.edges().map(|(start, _)| start.value),
+            None => None,
+        }
+    }
+
+    /// Check if point `x` belongs to set
+    ///
+    /// Segments are binary searched, in `O(log n)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, IntervalSet, Closed, Open};
+    ///
+    /// let a = IntervalSet::from(&[
+    ///     Interval::new(Closed(0.), Open(1.)),
+    ///     Interval::new(Closed(2.), Open(3.)),
+    /// ]);
+    ///
+    /// assert!(a.contains(0.) && a.contains(2.5));
+    /// assert!(!a.contains(1.) && !a.contains(1.5));
+    /// ```
+    ///
+    pub fn contains(&self, x: f64) -> bool {
+        matches!(self.union.get(self.position(x)), Some(segment) if segment.contains(x))
+    }
+
+    /// Membership of each of `points` to set
+    ///
+    /// Each point costs a binary search; see `contains_sorted` for sorted points.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, IntervalSet, Closed, Open};
+    ///
+    /// let a = IntervalSet::from(&[Interval::new(Closed(0.), Open(1.))]);
+    ///
+    /// assert_eq!(a.contains_many(&[0.5, 1., 0.]), vec![true, false, true]);
+    /// ```
+    ///
+    pub fn contains_many(&self, points: &[f64]) -> Vec<bool> {
+        points.iter().map(|&x| self.contains(x)).collect()
+    }
+
+    /// Check if every point of `points` belongs to set, stopping at the first one out
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, IntervalSet, Closed, Open};
+    ///
+    /// let a = IntervalSet::from(&[
+    ///     Interval::new(Closed(0.), Open(1.)),
+    ///     Interval::new(Closed(2.), Open(3.)),
+    /// ]);
+    ///
+    /// assert!(a.contains_all([0., 2.5]));
+    /// assert!(!a.contains_all([0., 1.5]));
+    /// assert!(a.contains_any([1.5, 2.]));
+    /// ```
+    ///
+    pub fn contains_all(&self, points: impl IntoIterator<Item = f64>) -> bool {
+        points.into_iter().all(|x| self.contains(x))
+    }
+
+    /// Check if some point of `points` belongs to set, stopping at the first one in
+    ///
+    pub fn contains_any(&self, points: impl IntoIterator<Item = f64>) -> bool {
+        points.into_iter().any(|x| self.contains(x))
+    }
+
+    /// Membership of each of `points`, sorted in increasing order, to set
+    ///
+    /// Points and segments are walked together in a single merge pass. The result is
+    /// unspecified if `points` are not sorted.
+    ///
+    pub fn contains_sorted(&self, points: &[f64]) -> Vec<bool> {
+        let mut i = 0;
+        points
+            .iter()
+            .map(|&x| {
+                while self.union.get(i).is_some_and(|segment| segment.is_below(x)) {
+                    i += 1;
+                }
+                self.union.get(i).is_some_and(|segment| segment.contains(x))
+            })
+            .collect()
+    }
+
+    /// Check that every segment is valid and non-empty, and that segments are sorted and
+    /// separated by gaps
+    ///
+    /// Operations of the crate only build valid sets.
+    ///
+    pub fn is_valid(&self) -> bool {
+        self.union
+            .iter()
+            .all(|segment| segment.is_valid() && !segment.is_empty())
+            && self
+                .union
+                .windows(2)
+                .all(|w| w[0].union(w[1]) == (w[0], Some(w[1])))
+    }
+
+    /// Index of the first segment not lying strictly below `x`
+    ///
+    fn position(&self, x: f64) -> usize {
+        self.union.partition_point(|segment| segment.is_below(x))
+    }
+
+    /// Align every endpoint of set to a multiple of `step`
+    ///
+    /// Each segment is snapped with `Interval::snap`. Segments vanishing are dropped and
+    /// segments becoming adjacent or overlapping are merged. Set is returned unchanged
+    /// unless `step` is positive.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, IntervalSet, SnapMode, Closed, Open};
+    ///
+    /// let a = IntervalSet::from(&[
+    ///     Interval::new(Closed(1.), Open(4.)),
+    ///     Interval::new(Closed(6.), Open(9.)),
+    /// ]);
+    ///
+    /// assert_eq!(
+    ///     a.quantize(5., SnapMode::Expand),
+    ///     IntervalSet::from(&[Interval::new(Closed(0.), Open(10.))])
+    /// );
+    /// assert!(a.quantize(5., SnapMode::Shrink).is_empty());
+    /// ```
+    ///
+    pub fn quantize(&self, step: f64, mode: SnapMode) -> Self {
+        let segments: Vec<_> = self
+            .union
+            .iter()
+            .map(|segment| segment.snap(step, mode))
+            .collect();
+        IntervalSet::from_unsorted(segments)
+    }
+
+    /// Sample coverage of set at the centers of `n` equal cells of `domain`
+    ///
+    /// Cells are the bins of `domain.bins(n)`. Returns an empty mask if `domain` can't be
+    /// split into `n` cells.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, IntervalSet, Closed, Open};
+    ///
+    /// let a = IntervalSet::from(&[Interval::new(Closed(1.), Open(3.))]);
+    /// let domain = Interval::new(Closed(0.), Open(4.));
+    ///
+    /// assert_eq!(a.rasterize(domain, 4), vec![false, true, true, false]);
+    /// assert_eq!(IntervalSet::from_mask(domain, &[false, true, true, false]), a);
+    /// ```
+    ///
+    pub fn rasterize(&self, domain: Interval, n: usize) -> Vec<bool> {
+        let Ok(cells) = domain.bins(n) else {
+            return Vec::new();
+        };
+        cells
+            .bins()
+            .iter()
+            .map(|cell| {
+                cell.endpoints()
+                    .is_some_and(|(a, b)| self.contains(a / 2. + b / 2.))
+            })
+            .collect()
+    }
+
+    /// Union of the cells of `domain` flagged in `mask`
+    ///
+    /// Cells are the bins of `domain.bins(mask.len())`, so that this is the reverse of
+    /// `rasterize`. Returns an empty set if `domain` can't be split into `mask.len()` cells.
+    ///
+    pub fn from_mask(domain: Interval, mask: &[bool]) -> Self {
+        let Ok(cells) = domain.bins(mask.len()) else {
+            return IntervalSet::new();
+        };
+        let covered: Vec<_> = cells
+            .bins()
+            .iter()
+            .zip(mask)
+            .filter(|(_, &flag)| flag)
+            .map(|(cell, _)| *cell)
+            .collect();
+        IntervalSet::from_unsorted(covered)
+    }
+
+    /// Run-length encoding `(start, len)` of the integers covered by set
+    ///
+    /// Runs are maximal and sorted. Integers out of the `i64` range are left out.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, IntervalSet, Closed, Open};
+    ///
+    /// let a = IntervalSet::from(&[
+    ///     Interval::new(Closed(0.), Closed(2.5)),
+    ///     Interval::new(Closed(2.7), Open(4.)),
+    ///     Interval::new(Open(9.), Closed(10.)),
+    /// ]);
+    ///
+    /// assert_eq!(a.to_runs(), vec![(0, 4), (10, 1)]);
+    /// ```
+    ///
+    pub fn to_runs(&self) -> Vec<(i64, u64)> {
+        let mut runs: Vec<(i64, i64)> = Vec::new();
+        for (lower, upper) in self.union.iter().filter_map(|a| a.integer_range()) {
+            match runs.last_mut() {
+                Some((_, last)) if last.checked_add(1) == Some(lower) => *last = upper,
+                _ => runs.push((lower, upper)),
+            }
+        }
+        runs.into_iter()
+            .map(|(lower, upper)| {
+                let len = upper as i128 - lower as i128 + 1;
+                (lower, len.min(u64::MAX as i128) as u64)
+            })
+            .collect()
+    }
+
+    /// Set covering the runs `[start, start + len)`, reverse of `to_runs`
+    ///
+    /// Runs may be unsorted or overlapping; empty runs are ignored.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, IntervalSet, Closed, Open};
+    ///
+    /// assert_eq!(
+    ///     IntervalSet::from_runs(&[(0, 2), (2, 3)]),
+    ///     IntervalSet::from(&[Interval::new(Closed(0.), Open(5.))])
+    /// );
+    /// ```
+    ///
+    pub fn from_runs(runs: &[(i64, u64)]) -> Self {
+        let segments: Vec<_> = runs
+            .iter()
+            .filter(|(_, len)| *len > 0)
+            .map(|&(start, len)| {
+                let end = start as f64 + len as f64;
+                Interval::new(Closed(start as f64), Open(end))
+            })
+            .collect();
+        IntervalSet::from_unsorted(segments)
+    }
+
+    /// Boolean combination of sets
+    ///
+    fn combine(&self, other: &IntervalSet, op: impl Fn(bool, bool) -> bool) -> Self {
+        IntervalSet::sweep(&[self, other], |inside| op(inside[0], inside[1]))
+    }
+
+    /// Boolean combination of any number of sets
+    ///
+    /// Sweep over sorted edges of all sets at once and keep the ranges where `op` holds,
+    /// given membership to each of `sets`.
+    ///
+    pub(crate) fn sweep(sets: &[&IntervalSet], op: impl Fn(&[bool]) -> bool) -> Self {
+        let mut events = Vec::new();
+        for (set, intervals) in sets.iter().enumerate() {
+            for interval in intervals.union.iter() {
+                if let Some((start, end)) = interval.edges() {
+                    events.push((start, set, 1));
+                    events.push((end, set, -1));
+                }
+            }
+        }
+        events.sort_by_key(|event| event.0);
+
+        let mut res = IntervalSet::new();
+        let mut depth = vec![0; sets.len()];
+        let mut membership = vec![false; sets.len()];
+        let mut start = Edge {
+            value: f64::NEG_INFINITY,
+            after: false,
+        };
+        let mut inside = op(&membership);
+        let mut i = 0;
+        while i < events.len() {
+            let edge = events[i].0;
+            while i < events.len() && events[i].0 == edge {
+                let (_, set, delta) = events[i];
+                depth[set] += delta;
+                membership[set] = depth[set] > 0;
+                i += 1;
+            }
+            let now = op(&membership);
+            match (inside, now) {
+                (false, true) => start = edge,
+                (true, false) if start < edge => {
+                    res.union.push(Interval::from_edges(start, edge));
+                }
+                _ => {}
+            }
+            inside = now;
+        }
+        let end = Edge {
+            value: f64::INFINITY,
+            after: false,
+        };
+        if inside && start < end {
+            res.union.push(Interval::from_edges(start, end));
+        }
+        res.checked()
+    }
+
+    /// Assert in debug builds that set is valid (feature `invariant-checks`)
+    ///
+    pub(crate) fn checked(self) -> Self {
+        #[cfg(feature = "invariant-checks")]
+        debug_assert!(self.is_valid(), "invalid interval set {:?}", self.union);
+        self
+    }
 }
 
 impl PartialEq for IntervalSet {
@@ -114,10 +1172,82 @@ impl_op_ex!(| |lhs: &IntervalSet, rhs: &IntervalSet| -> IntervalSet {
     lhs.union_intervals(rhs)
 });
 
+impl_op_ex!(&|lhs: &IntervalSet, rhs: &IntervalSet| -> IntervalSet { lhs.intersection(rhs) });
+
+impl_op_ex!(-|lhs: &IntervalSet, rhs: &IntervalSet| -> IntervalSet { lhs.difference(rhs) });
+
+impl_op_ex!(^|lhs: &IntervalSet, rhs: &IntervalSet| -> IntervalSet {
+    lhs.symmetric_difference(rhs)
+});
+
+/// Integral of `f` over `[a, b]` within `tol`, halving `[a, b]` at most `depth` times
+///
+fn adaptive_simpson(f: &impl Fn(f64) -> f64, a: f64, b: f64, tol: f64, depth: u32) -> f64 {
+    fn simpson(fa: f64, fm: f64, fb: f64, a: f64, b: f64) -> f64 {
+        (b - a) / 6. * (fa + 4. * fm + fb)
+    }
+
+    fn refine(
+        f: &impl Fn(f64) -> f64,
+        (a, fa): (f64, f64),
+        (m, fm): (f64, f64),
+        (b, fb): (f64, f64),
+        whole: f64,
+        tol: f64,
+        depth: u32,
+    ) -> f64 {
+        let (lm, rm) = ((a + m) / 2., (m + b) / 2.);
+        let (flm, frm) = (f(lm), f(rm));
+        let left = simpson(fa, flm, fm, a, m);
+        let right = simpson(fm, frm, fb, m, b);
+        let delta = left + right - whole;
+        if depth == 0 || delta.abs() <= 15. * tol {
+            left + right + delta / 15.
+        } else {
+            refine(f, (a, fa), (lm, flm), (m, fm), left, tol / 2., depth - 1)
+                + refine(f, (m, fm), (rm, frm), (b, fb), right, tol / 2., depth - 1)
+        }
+    }
+
+    let m = (a + b) / 2.;
+    let (fa, fm, fb) = (f(a), f(m), f(b));
+    let whole = simpson(fa, fm, fb, a, b);
+    refine(f, (a, fa), (m, fm), (b, fb), whole, tol, depth)
+}
+
+/// Neumaier compensated sum, carrying apart the rounding error of each addition
+///
+#[derive(Default)]
+struct CompensatedSum {
+    sum: f64,
+    compensation: f64,
+}
+
+impl CompensatedSum {
+    fn add(&mut self, x: f64) {
+        let t = self.sum + x;
+        if self.sum.abs() >= x.abs() {
+            self.compensation += (self.sum - t) + x;
+        } else {
+            self.compensation += (x - t) + self.sum;
+        }
+        self.sum = t;
+    }
+
+    fn value(&self) -> f64 {
+        // compensation is meaningless (NaN) once the sum overflowed
+        if self.sum.is_infinite() {
+            self.sum
+        } else {
+            self.sum + self.compensation
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::{Closed, EMPTY};
+    use crate::EMPTY;
 
     #[test]
     fn test_empty_1() {
@@ -172,4 +1302,633 @@ mod test {
 
         assert!((a | b).is_infinity());
     }
+
+    #[test]
+    fn test_intersection_1() {
+        let a = IntervalSet::from(&[
+            Interval::new(Closed(0.), Closed(10.)),
+            Interval::new(Closed(20.), Closed(30.)),
+        ]);
+        let b = IntervalSet::from(&[Interval::new(Closed(10.), Open(25.))]);
+        let c = IntervalSet::from(&[
+            Interval::singleton(10.),
+            Interval::new(Closed(20.), Open(25.)),
+        ]);
+        assert_eq!(a.intersection(&b), c);
+        assert_eq!(&a & &b, c);
+    }
+
+    #[test]
+    fn test_intersection_2() {
+        let a = IntervalSet::from(&[Interval::new(Closed(0.), Open(10.))]);
+        let b = IntervalSet::from(&[Interval::new(Closed(10.), Closed(20.))]);
+        assert!((&a & b).is_empty());
+        assert!((&a & IntervalSet::new()).is_empty());
+        assert_eq!(&a & (IntervalSet::new() | INFINITY), a);
+    }
+
+    #[test]
+    fn test_difference_1() {
+        let a = IntervalSet::from(&[
+            Interval::new(Closed(0.), Closed(10.)),
+            Interval::new(Closed(20.), Closed(30.)),
+        ]);
+        let b = IntervalSet::from(&[Interval::new(Closed(10.), Open(25.))]);
+        let c = IntervalSet::from(&[
+            Interval::new(Closed(0.), Open(10.)),
+            Interval::new(Closed(25.), Closed(30.)),
+        ]);
+        assert_eq!(a.difference(&b), c);
+        assert_eq!(&a - &b, c);
+        assert_eq!(&a - IntervalSet::new(), a);
+        assert!((&a - &a).is_empty());
+        assert!((IntervalSet::new() - &a).is_empty());
+        assert_eq!((IntervalSet::new() | INFINITY) - &a, a.complement());
+    }
+
+    #[test]
+    fn test_symmetric_difference_1() {
+        let a = IntervalSet::from(&[
+            Interval::new(Closed(0.), Closed(10.)),
+            Interval::new(Closed(20.), Closed(30.)),
+        ]);
+        let b = IntervalSet::from(&[Interval::new(Closed(10.), Open(25.))]);
+        let c = IntervalSet::from(&[
+            Interval::new(Closed(0.), Open(10.)),
+            Interval::new(Open(10.), Open(20.)),
+            Interval::new(Closed(25.), Closed(30.)),
+        ]);
+        assert_eq!(a.symmetric_difference(&b), c);
+        assert_eq!(&a ^ &b, &b ^ &a);
+        assert_eq!(&a ^ &b, (&a - &b) | (&b - &a));
+        assert!((&a ^ &a).is_empty());
+        assert_eq!(&a ^ IntervalSet::new(), a);
+    }
+
+    #[test]
+    fn test_is_subset_of_1() {
+        let a = IntervalSet::from(&[
+            Interval::new(Closed(0.), Open(1.)),
+            Interval::new(Closed(1.), Closed(2.)),
+        ]);
+        let b = IntervalSet::from(&[Interval::new(Closed(0.), Closed(2.))]);
+        assert!(a.is_subset_of(&b) && b.is_subset_of(&a));
+        assert!(IntervalSet::new().is_subset_of(&a));
+        assert!(!a.is_subset_of(&IntervalSet::new()));
+        assert!((IntervalSet::new() | INFINITY).is_superset_of(&a));
+        let c = IntervalSet::from(&[Interval::new(Closed(0.), Open(2.))]);
+        assert!(c.is_subset_of(&b) && !b.is_subset_of(&c));
+    }
+
+    #[test]
+    fn test_complement_1() {
+        assert!(IntervalSet::new().complement().is_infinity());
+        assert!((IntervalSet::new() | INFINITY).complement().is_empty());
+    }
+
+    #[test]
+    fn test_complement_2() {
+        let a = IntervalSet::from(&[
+            Interval::new(Unbound, Closed(0.)),
+            Interval::singleton(5.),
+            Interval::new(Open(10.), Unbound),
+        ]);
+        let b = IntervalSet::from(&[
+            Interval::new(Open(0.), Open(5.)),
+            Interval::new(Open(5.), Closed(10.)),
+        ]);
+        assert_eq!(a.complement(), b);
+        assert_eq!(b.complement(), a);
+    }
+
+    #[test]
+    fn test_first_gap_at_least_1() {
+        let a = IntervalSet::from(&[
+            Interval::new(Closed(0.), Open(10.)),
+            Interval::new(Closed(12.), Open(20.)),
+        ]);
+        assert_eq!(
+            a.first_gap_at_least(1., 0.),
+            Some(Interval::new(Closed(10.), Open(12.)))
+        );
+        assert_eq!(
+            a.first_gap_at_least(1., 11.),
+            Some(Interval::new(Closed(11.), Open(12.)))
+        );
+        assert_eq!(
+            a.first_gap_at_least(1., 11.5),
+            Some(Interval::new(Closed(20.), Unbound))
+        );
+        assert_eq!(
+            a.first_gap_at_least(1., -5.),
+            Some(Interval::new(Closed(-5.), Open(0.)))
+        );
+        assert_eq!(
+            a.first_gap_at_least(3., 0.),
+            Some(Interval::new(Closed(20.), Unbound))
+        );
+    }
+
+    #[test]
+    fn test_first_gap_at_least_2() {
+        let a = IntervalSet::new() | INFINITY;
+        assert_eq!(a.first_gap_at_least(0., 0.), None);
+        assert_eq!(
+            IntervalSet::new().first_gap_at_least(1., 0.),
+            Some(Interval::new(Closed(0.), Unbound))
+        );
+    }
+
+    #[test]
+    fn test_next_outside_1() {
+        let a = IntervalSet::from(&[
+            Interval::new(Unbound, Closed(0.)),
+            Interval::new(Open(1.), Open(2.)),
+        ]);
+        assert_eq!(a.next_outside(-5.), Some(0.));
+        assert_eq!(a.next_outside(0.5), Some(0.5));
+        assert_eq!(a.next_outside(1.), Some(1.));
+        assert_eq!(a.next_outside(1.5), Some(2.));
+        assert_eq!(a.next_outside(2.), Some(2.));
+        assert_eq!((IntervalSet::new() | INFINITY).next_outside(0.), None);
+    }
+
+    #[test]
+    fn test_next_inside_1() {
+        let a = IntervalSet::from(&[
+            Interval::new(Open(1.), Closed(2.)),
+            Interval::new(Closed(3.), Unbound),
+        ]);
+        assert_eq!(a.next_inside(0.), Some(1.));
+        assert_eq!(a.next_inside(1.), Some(1.));
+        assert_eq!(a.next_inside(2.), Some(2.));
+        assert_eq!(a.next_inside(2.5), Some(3.));
+        assert_eq!(a.next_inside(5.), Some(5.));
+        assert_eq!(IntervalSet::new().next_inside(0.), None);
+    }
+
+    #[test]
+    fn test_try_insert_1() {
+        let mut a = IntervalSet::from(&[
+            Interval::new(Closed(0.), Closed(1.)),
+            Interval::new(Closed(5.), Closed(6.)),
+        ]);
+        assert_eq!(
+            a.try_insert(Interval::new(Closed(6.), Closed(7.))),
+            Err(Conflict {
+                segment: Interval::new(Closed(5.), Closed(6.))
+            })
+        );
+        assert_eq!(a.try_insert(Interval::new(Open(1.), Open(5.))), Ok(()));
+        assert_eq!(
+            a,
+            IntervalSet::from(&[Interval::new(Closed(0.), Closed(6.))])
+        );
+    }
+
+    #[test]
+    fn test_try_insert_2() {
+        let mut a = IntervalSet::new();
+        assert_eq!(a.try_insert(EMPTY), Ok(()));
+        assert!(a.is_empty());
+        assert_eq!(a.try_insert(INFINITY), Ok(()));
+        assert!(a.try_insert(EMPTY).is_ok());
+        assert!(a.try_insert(Interval::singleton(0.)).is_err());
+    }
+
+    #[test]
+    fn test_overlap_measure_1() {
+        let a = IntervalSet::from(&[
+            Interval::new(Closed(0.), Closed(2.)),
+            Interval::new(Closed(4.), Closed(6.)),
+            Interval::new(Closed(8.), Closed(10.)),
+        ]);
+        let b = IntervalSet::from(&[
+            Interval::new(Closed(1.), Closed(5.)),
+            Interval::new(Closed(5.5), Closed(9.)),
+        ]);
+        assert_eq!(a.overlap_measure(&b), 3.5);
+        assert_eq!(b.overlap_measure(&a), 3.5);
+        assert_eq!(a.overlap_measure(&IntervalSet::new()), 0.);
+    }
+
+    #[test]
+    fn test_overlap_measure_2() {
+        let a = IntervalSet::from(&[Interval::new(Closed(0.), Unbound)]);
+        let b = IntervalSet::from(&[Interval::new(Closed(1.), Unbound)]);
+        let c = IntervalSet::from(&[Interval::new(Unbound, Closed(0.))]);
+        assert_eq!(a.overlap_measure(&b), f64::INFINITY);
+        assert_eq!(a.overlap_measure(&c), 0.);
+    }
+
+    #[test]
+    fn test_quantize_1() {
+        let a = IntervalSet::from(&[
+            Interval::new(Closed(0.2), Closed(0.9)),
+            Interval::new(Closed(1.1), Closed(2.7)),
+        ]);
+        assert_eq!(
+            a.quantize(1., SnapMode::Round),
+            IntervalSet::from(&[Interval::new(Closed(0.), Closed(3.))])
+        );
+        assert_eq!(
+            a.quantize(1., SnapMode::Shrink),
+            IntervalSet::from(&[Interval::new(Closed(2.), Closed(2.))])
+        );
+        assert_eq!(a.quantize(-1., SnapMode::Round), a);
+    }
+
+    #[test]
+    fn test_rasterize_1() {
+        let a = IntervalSet::from(&[Interval::new(Unbound, Closed(0.4)), Interval::singleton(1.)]);
+        let domain = Interval::new(Closed(0.), Closed(2.));
+        assert_eq!(a.rasterize(domain, 2), vec![false, false]);
+        assert_eq!(a.rasterize(domain, 4), vec![true, false, false, false]);
+        assert!(a.rasterize(INFINITY, 4).is_empty());
+    }
+
+    #[test]
+    fn test_from_mask_1() {
+        let domain = Interval::new(Closed(0.), Closed(3.));
+        assert_eq!(
+            IntervalSet::from_mask(domain, &[true, false, true]),
+            IntervalSet::from(&[
+                Interval::new(Closed(0.), Open(1.)),
+                Interval::new(Closed(2.), Closed(3.)),
+            ])
+        );
+        assert!(IntervalSet::from_mask(domain, &[]).is_empty());
+        assert!(IntervalSet::from_mask(domain, &[false; 3]).is_empty());
+    }
+
+    #[test]
+    fn test_to_runs_1() {
+        let a = IntervalSet::from(&[
+            Interval::new(Open(0.), Open(1.)),
+            Interval::new(Open(1.), Closed(3.)),
+            Interval::new(Closed(-3.), Closed(-2.)),
+        ]);
+        assert_eq!(a.to_runs(), vec![(-3, 2), (2, 2)]);
+        assert!(IntervalSet::new().to_runs().is_empty());
+    }
+
+    #[test]
+    fn test_to_runs_2() {
+        let a = IntervalSet::from(&[Interval::new(Closed(0.), Unbound)]);
+        assert_eq!(a.to_runs(), vec![(0, i64::MAX as u64 + 1)]);
+        assert_eq!(
+            (IntervalSet::new() | INFINITY).to_runs(),
+            vec![(i64::MIN, u64::MAX)]
+        );
+        let b = IntervalSet::from(&[
+            Interval::new(Closed(1.), Closed(2.)),
+            Interval::new(Closed(1e30), Closed(1e31)),
+        ]);
+        assert_eq!(b.to_runs(), vec![(1, 2)]);
+    }
+
+    #[test]
+    fn test_from_runs_1() {
+        let runs = [(5, 1), (0, 3), (1, 1), (10, 0)];
+        let a = IntervalSet::from_runs(&runs);
+        assert_eq!(
+            a,
+            IntervalSet::from(&[
+                Interval::new(Closed(0.), Open(3.)),
+                Interval::new(Closed(5.), Open(6.)),
+            ])
+        );
+        assert_eq!(a.to_runs(), vec![(0, 3), (5, 1)]);
+    }
+
+    #[test]
+    fn test_from_unsorted_1() {
+        let a = [
+            Interval::new(Closed(5.), Closed(6.)),
+            EMPTY,
+            Interval::new(Open(2.), Open(5.)),
+            Interval::new(Closed(0.), Closed(1.)),
+            Interval::new(Closed(0.5), Closed(0.7)),
+        ];
+        assert_eq!(
+            IntervalSet::from_unsorted(a.to_vec()),
+            IntervalSet::from(&a)
+        );
+        assert!(IntervalSet::from_unsorted(vec![]).is_empty());
+    }
+
+    #[test]
+    fn test_contains_1() {
+        let a = IntervalSet::from(&[
+            Interval::new(Unbound, Open(-1.)),
+            Interval::singleton(0.),
+            Interval::new(Open(1.), Closed(2.)),
+        ]);
+        assert!(a.contains(-5.) && !a.contains(-1.));
+        assert!(a.contains(0.) && !a.contains(0.5));
+        assert!(!a.contains(1.) && a.contains(2.) && !a.contains(2.5));
+        assert!(!a.contains(f64::NAN));
+        assert!(!IntervalSet::new().contains(0.));
+        assert!((IntervalSet::new() | INFINITY).contains(1e300));
+    }
+
+    #[test]
+    fn test_contains_many_1() {
+        let a = IntervalSet::from(&[
+            Interval::new(Unbound, Open(0.)),
+            Interval::new(Closed(1.), Closed(2.)),
+            Interval::new(Open(3.), Open(4.)),
+        ]);
+        let points = [-1., 0., 0.5, 1., 2., 2.5, 3., 3.5, 4., f64::NAN];
+        let expected = vec![
+            true, false, false, true, true, false, false, true, false, false,
+        ];
+        assert_eq!(a.contains_many(&points), expected);
+        assert_eq!(a.contains_sorted(&points), expected);
+    }
+
+    #[test]
+    fn test_contains_many_2() {
+        let a = IntervalSet::new();
+        assert_eq!(a.contains_many(&[0., 1.]), vec![false, false]);
+        assert_eq!(a.contains_sorted(&[0., 1.]), vec![false, false]);
+        assert!(a.contains_sorted(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_measure_1() {
+        assert_eq!(IntervalSet::new().measure(), 0.);
+        assert_eq!((IntervalSet::new() | INFINITY).measure(), f64::INFINITY);
+        let a = IntervalSet::from(&[
+            Interval::new(Closed(0.), Closed(1.)),
+            Interval::singleton(5.),
+            Interval::new(Open(7.), Unbound),
+        ]);
+        assert_eq!(a.measure(), f64::INFINITY);
+    }
+
+    #[test]
+    fn test_measure_2() {
+        // adding 1 to 1e16 is lost without compensation
+        let mut segments = vec![Interval::new(Closed(-1e16), Closed(0.))];
+        segments.extend((0..1000).map(|k| {
+            let a = 2. * k as f64 + 1.;
+            Interval::new(Closed(a), Closed(a + 1.))
+        }));
+        let a = IntervalSet::from(&segments[..]);
+        assert_eq!(a.measure(), 1e16 + 1000.);
+    }
+
+    #[test]
+    fn test_is_valid_1() {
+        let a = Interval::new(Closed(0.), Open(1.));
+        let b = Interval::new(Closed(1.), Closed(2.));
+        let c = Interval::new(Open(2.), Closed(3.));
+        assert!(IntervalSet::new().is_valid());
+        assert!(IntervalSet::from(&[a, c]).is_valid());
+        assert!(IntervalSet { union: vec![a, c] }.is_valid());
+        assert!(!IntervalSet { union: vec![c, a] }.is_valid());
+        assert!(!IntervalSet { union: vec![a, b] }.is_valid());
+        assert!(!IntervalSet {
+            union: vec![a, EMPTY]
+        }
+        .is_valid());
+    }
+
+    #[cfg(all(debug_assertions, feature = "invariant-checks"))]
+    #[test]
+    #[should_panic(expected = "invalid interval set")]
+    fn test_checked_1() {
+        let a = Interval::new(Closed(0.), Open(1.));
+        let b = Interval::new(Closed(1.), Closed(2.));
+        IntervalSet { union: vec![b, a] }.checked();
+    }
+
+    #[test]
+    fn test_contains_all_1() {
+        let a = IntervalSet::from(&[Interval::new(Closed(0.), Open(1.))]);
+        assert!(IntervalSet::new().contains_all([]));
+        assert!(!IntervalSet::new().contains_any([0.]));
+        assert!(a.contains_all(vec![0., 0.5]));
+        assert!(!a.contains_any(vec![1., -1.]));
+    }
+
+    #[test]
+    fn test_from_points_1() {
+        assert!(IntervalSet::from_points(&[], 1.).is_empty());
+        assert!(IntervalSet::from_points(&[1., 2.], -1.).is_empty());
+        assert_eq!(
+            IntervalSet::from_points(&[2., 0., f64::NAN], 0.),
+            IntervalSet::from(&[Interval::singleton(0.), Interval::singleton(2.)])
+        );
+        assert_eq!(
+            IntervalSet::from_points(&[0., 2.], 1.),
+            IntervalSet::from(&[Interval::new(Closed(-1.), Closed(3.))])
+        );
+    }
+
+    #[test]
+    fn test_from_points_2() {
+        assert_eq!(
+            IntervalSet::from_points(&[0.], f64::INFINITY),
+            IntervalSet::from(&[INFINITY])
+        );
+        assert_eq!(
+            IntervalSet::from_points(&[2., 0.], f64::INFINITY),
+            IntervalSet::from(&[INFINITY])
+        );
+    }
+
+    #[test]
+    fn test_uniform_mean_1() {
+        assert_eq!(IntervalSet::new().uniform_mean(), None);
+        assert_eq!(
+            IntervalSet::from(&[Interval::singleton(1.)]).uniform_mean(),
+            None
+        );
+        assert_eq!(
+            IntervalSet::from(&[Interval::new(Closed(0.), Unbound)]).uniform_variance(),
+            None
+        );
+        let a = IntervalSet::from(&[
+            Interval::new(Closed(-2.), Open(-1.)),
+            Interval::singleton(0.),
+            Interval::new(Open(1.), Closed(2.)),
+        ]);
+        assert_eq!(a.uniform_mean(), Some(0.));
+        // E[x²] = ∫ x² over [1, 2], doubled, divided by 2
+        assert!((a.uniform_variance().unwrap() - 7. / 3.).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_integrate_1() {
+        assert_eq!(IntervalSet::new().integrate(|_| 1., 1e-9), Some(0.));
+        assert_eq!(
+            IntervalSet::from(&[Interval::new(Closed(0.), Unbound)]).integrate(|_| 0., 1e-9),
+            None
+        );
+        let a = IntervalSet::from(&[
+            Interval::new(Closed(0.), Closed(std::f64::consts::PI)),
+            Interval::singleton(5.),
+        ]);
+        let integral = a.integrate(f64::sin, 1e-10).unwrap();
+        assert!((integral - 2.).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_integrate_2() {
+        let a = IntervalSet::from(&[Interval::new(Open(0.), Closed(1.))]);
+        let integral = a.integrate(f64::sqrt, 1e-8).unwrap();
+        assert!((integral - 2. / 3.).abs() < 1e-8);
+    }
+
+    #[test]
+    fn test_probability_1() {
+        let uniform = |x: f64| x.clamp(0., 1.);
+        assert_eq!(IntervalSet::new().probability(uniform), 0.);
+        let a = IntervalSet::from(&[
+            Interval::new(Unbound, Open(0.25)),
+            Interval::new(Closed(0.5), Closed(0.75)),
+            Interval::new(Closed(2.), Unbound),
+        ]);
+        assert_eq!(a.probability(uniform), 0.5);
+    }
+
+    #[test]
+    fn test_hull_1() {
+        assert_eq!(IntervalSet::new().hull(), EMPTY);
+        let a = IntervalSet::from(&[
+            Interval::new(Open(0.), Closed(1.)),
+            Interval::new(Closed(2.), Closed(3.)),
+            Interval::new(Closed(5.), Open(6.)),
+        ]);
+        assert_eq!(a.hull(), Interval::new(Open(0.), Open(6.)));
+    }
+
+    #[test]
+    fn test_from_str_1() {
+        assert_eq!("∅".parse(), Ok(IntervalSet::new()));
+        assert_eq!(
+            "[0,1] ∪ [1,2)".parse(),
+            Ok(IntervalSet::from(&[Interval::new(Closed(0.), Open(2.))]))
+        );
+        assert_eq!(
+            "[0,1] U".parse::<IntervalSet>(),
+            Err(IntervalError::Parse("".to_string()))
+        );
+        let a = IntervalSet::from(&[
+            Interval::new(Unbound, Open(-1.)),
+            Interval::singleton(0.),
+            Interval::new(Closed(2.), Unbound),
+        ]);
+        assert_eq!(a.to_string().parse(), Ok(a));
+        assert_eq!(
+            "[0,1] U [inf,2]".parse::<IntervalSet>(),
+            Err(IntervalError::ReversedBounds)
+        );
+        assert_eq!(
+            "[0,1] U (2,-∞)".parse::<IntervalSet>(),
+            Err(IntervalError::ReversedBounds)
+        );
+    }
+
+    #[test]
+    fn test_drop_smaller_than_1() {
+        let mut a = IntervalSet::from(&[
+            Interval::singleton(-1.),
+            Interval::new(Closed(0.), Open(1.)),
+            Interval::new(Closed(2.), Unbound),
+        ]);
+        a.drop_smaller_than(1.);
+        assert_eq!(
+            a,
+            IntervalSet::from(&[
+                Interval::new(Closed(0.), Open(1.)),
+                Interval::new(Closed(2.), Unbound),
+            ])
+        );
+        a.drop_smaller_than(f64::INFINITY);
+        assert_eq!(a, IntervalSet::from(&[Interval::new(Closed(2.), Unbound)]));
+    }
+
+    #[test]
+    fn test_drop_smaller_than_merging_1() {
+        // Neighbours without a removed segment in between stay apart
+        let mut a = IntervalSet::from(&[
+            Interval::new(Closed(0.), Open(1.)),
+            Interval::new(Closed(1.05), Open(2.)),
+            Interval::singleton(2.05),
+            Interval::new(Open(2.1), Closed(3.)),
+            Interval::singleton(5.),
+        ]);
+        a.drop_smaller_than_merging(0.2);
+        assert_eq!(
+            a,
+            IntervalSet::from(&[
+                Interval::new(Closed(0.), Open(1.)),
+                Interval::new(Closed(1.05), Closed(3.)),
+            ])
+        );
+        let mut b = IntervalSet::from(&[Interval::singleton(0.)]);
+        b.drop_smaller_than_merging(1.);
+        assert!(b.is_empty());
+    }
+
+    #[test]
+    fn test_largest_1() {
+        assert_eq!(IntervalSet::new().largest(), None);
+        assert_eq!(IntervalSet::new().smallest(), None);
+        let a = IntervalSet::from(&[
+            Interval::new(Closed(0.), Open(1.)),
+            Interval::singleton(2.),
+            Interval::singleton(3.),
+            Interval::new(Closed(4.), Open(5.)),
+        ]);
+        assert_eq!(a.largest(), Some(Interval::new(Closed(0.), Open(1.))));
+        assert_eq!(a.smallest(), Some(Interval::singleton(2.)));
+        let b = a.union_interval(&Interval::new(Closed(10.), Unbound));
+        assert_eq!(b.largest(), Some(Interval::new(Closed(10.), Unbound)));
+    }
+
+    #[test]
+    fn test_exp_1() {
+        assert_eq!(format!("{:e}", IntervalSet::new()), "∅");
+        let a = IntervalSet::from(&[
+            Interval::new(Unbound, Open(-1e-20)),
+            Interval::singleton(3e8),
+        ]);
+        assert_eq!(format!("{a:E}"), "(-∞,-1E-20) U {3E8}");
+        assert_eq!(format!("{a:e}").parse::<IntervalSet>().unwrap(), a);
+    }
+
+    #[test]
+    fn test_coarsen_1() {
+        assert!(IntervalSet::new().coarsen(0).is_empty());
+        let a = IntervalSet::from(&[
+            Interval::new(Unbound, Open(0.)),
+            Interval::new(Open(0.), Closed(1.)),
+            Interval::new(Closed(3.), Closed(4.)),
+            Interval::new(Closed(6.), Closed(7.)),
+        ]);
+        assert_eq!(a.coarsen(4), a);
+        assert_eq!(a.coarsen(10), a);
+        assert_eq!(
+            a.coarsen(3).as_slice(),
+            [
+                Interval::new(Unbound, Closed(1.)),
+                Interval::new(Closed(3.), Closed(4.)),
+                Interval::new(Closed(6.), Closed(7.)),
+            ]
+        );
+        // Ties go to the earlier hole
+        assert_eq!(
+            a.coarsen(2).as_slice(),
+            [
+                Interval::new(Unbound, Closed(4.)),
+                Interval::new(Closed(6.), Closed(7.)),
+            ]
+        );
+        assert_eq!(a.coarsen(0), IntervalSet::from(&[a.hull()]));
+        assert_eq!(a.coarsen(1), a.coarsen(0));
+    }
 }
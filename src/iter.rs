@@ -0,0 +1,152 @@
+use std::iter::{FromIterator, Product, Sum};
+
+use super::{Interval, IntervalSet, INFINITY};
+
+/// Folding operations over iterators of intervals
+///
+/// # Example
+///
+/// ```
+/// use interval::{Interval, IntervalIteratorExt, Closed};
+///
+/// let events = [(0., 2.), (1., 3.), (5., 6.)];
+/// let busy = events
+///     .iter()
+///     .map(|&(a, b)| Interval::new(Closed(a), Closed(b)))
+///     .union_all();
+///
+/// assert_eq!(format!("{busy}"), "[ 0.00, 3.00] U [ 5.00, 6.00]");
+/// ```
+///
+pub trait IntervalIteratorExt: Iterator<Item = Interval> + Sized {
+    /// Union of all intervals
+    ///
+    fn union_all(self) -> IntervalSet {
+        IntervalSet::from_unsorted(self.collect())
+    }
+
+    /// Intersection of all intervals, `INFINITY` for an empty iterator
+    ///
+    fn intersect_all(self) -> Interval {
+        self.fold(INFINITY, Interval::intersection)
+    }
+}
+
+impl<I: Iterator<Item = Interval>> IntervalIteratorExt for I {}
+
+/// Union of collected intervals
+///
+/// # Example
+///
+/// ```
+/// use interval::{Interval, IntervalSet, Closed};
+///
+/// let a: IntervalSet = (0..3)
+///     .map(|k| Interval::new(Closed(k as f64), Closed(k as f64 + 0.5)))
+///     .collect();
+///
+/// assert_eq!(format!("{a}"), "[ 0.00, 0.50] U [ 1.00, 1.50] U [ 2.00, 2.50]");
+/// ```
+///
+impl FromIterator<Interval> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = Interval>>(iter: I) -> Self {
+        iter.into_iter().union_all()
+    }
+}
+
+impl Sum<Interval> for IntervalSet {
+    fn sum<I: Iterator<Item = Interval>>(iter: I) -> Self {
+        iter.union_all()
+    }
+}
+
+impl<'a> Sum<&'a Interval> for IntervalSet {
+    fn sum<I: Iterator<Item = &'a Interval>>(iter: I) -> Self {
+        iter.copied().union_all()
+    }
+}
+
+/// Intersection of intervals, `INFINITY` for an empty iterator
+///
+/// # Example
+///
+/// ```
+/// use interval::{Interval, Closed};
+///
+/// let a = [
+///     Interval::new(Closed(0.), Closed(10.)),
+///     Interval::new(Closed(5.), Closed(20.)),
+/// ];
+///
+/// assert_eq!(a.iter().product::<Interval>(), Interval::new(Closed(5.), Closed(10.)));
+/// ```
+///
+impl Product<Interval> for Interval {
+    fn product<I: Iterator<Item = Interval>>(iter: I) -> Self {
+        iter.intersect_all()
+    }
+}
+
+impl<'a> Product<&'a Interval> for Interval {
+    fn product<I: Iterator<Item = &'a Interval>>(iter: I) -> Self {
+        iter.copied().intersect_all()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Closed, Open, EMPTY};
+
+    #[test]
+    fn test_union_all_1() {
+        let a = [
+            Interval::new(Closed(2.), Open(3.)),
+            Interval::new(Closed(0.), Open(1.)),
+            Interval::new(Closed(1.), Open(2.)),
+        ];
+        assert_eq!(
+            a.into_iter().union_all(),
+            IntervalSet::from(&[Interval::new(Closed(0.), Open(3.))])
+        );
+        assert!(std::iter::empty().union_all().is_empty());
+    }
+
+    #[test]
+    fn test_intersect_all_1() {
+        let a = [
+            Interval::new(Closed(0.), Closed(10.)),
+            Interval::new(Open(2.), Closed(20.)),
+            Interval::new(Closed(-5.), Open(5.)),
+        ];
+        assert_eq!(
+            a.into_iter().intersect_all(),
+            Interval::new(Open(2.), Open(5.))
+        );
+        assert_eq!(a.into_iter().chain([EMPTY]).intersect_all(), EMPTY);
+        assert_eq!(std::iter::empty().intersect_all(), INFINITY);
+    }
+
+    #[test]
+    fn test_sum_1() {
+        let a = [
+            Interval::new(Closed(1.), Closed(2.)),
+            Interval::new(Closed(0.), Open(1.)),
+        ];
+        let b = IntervalSet::from(&[Interval::new(Closed(0.), Closed(2.))]);
+        assert_eq!(a.iter().sum::<IntervalSet>(), b);
+        assert_eq!(a.into_iter().sum::<IntervalSet>(), b);
+        assert_eq!(a.into_iter().collect::<IntervalSet>(), b);
+    }
+
+    #[test]
+    fn test_product_1() {
+        let a = [
+            Interval::new(Closed(1.), Closed(2.)),
+            Interval::new(Closed(0.), Open(1.)),
+        ];
+        assert_eq!(a.iter().product::<Interval>(), EMPTY);
+        assert_eq!(a[..1].iter().product::<Interval>(), a[0]);
+        assert_eq!(a[..0].iter().product::<Interval>(), INFINITY);
+    }
+}
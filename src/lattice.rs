@@ -0,0 +1,118 @@
+use super::{Interval, IntervalSet, EMPTY, INFINITY};
+
+/// Bounded lattice, as used by dataflow and abstract interpretation frameworks
+///
+/// `join` and `meet` are the least upper bound and the greatest lower bound for the partial
+/// order `leq`, `bottom` and `top` its least and greatest elements.
+///
+/// Intervals are ordered by inclusion, the join of two intervals being their hull since
+/// their union may not be an interval. Sets are ordered by inclusion too, with union and
+/// intersection as join and meet, making a bounded distributive lattice; it is not
+/// complete, as infinite joins and meets may not be finite unions of intervals.
+///
+/// # Example
+///
+/// ```
+/// use interval::{Interval, IntervalSet, Lattice, Closed};
+///
+/// fn join_all<L: Lattice>(values: &[L]) -> L {
+///     values.iter().fold(L::bottom(), |res, x| res.join(x))
+/// }
+///
+/// let a = Interval::new(Closed(0.), Closed(1.));
+/// let b = Interval::new(Closed(5.), Closed(6.));
+///
+/// assert_eq!(join_all(&[a, b]), Interval::new(Closed(0.), Closed(6.)));
+/// assert_eq!(
+///     join_all(&[IntervalSet::from(&[a]), IntervalSet::from(&[b])]),
+///     IntervalSet::from(&[a, b])
+/// );
+/// ```
+///
+pub trait Lattice: Sized {
+    fn join(&self, other: &Self) -> Self;
+
+    fn meet(&self, other: &Self) -> Self;
+
+    fn bottom() -> Self;
+
+    fn top() -> Self;
+
+    /// Check if `self` lies below `other` in the lattice order
+    ///
+    fn leq(&self, other: &Self) -> bool;
+}
+
+impl Lattice for Interval {
+    fn join(&self, other: &Self) -> Self {
+        self.hull(*other)
+    }
+
+    fn meet(&self, other: &Self) -> Self {
+        self.intersection(*other)
+    }
+
+    fn bottom() -> Self {
+        EMPTY
+    }
+
+    fn top() -> Self {
+        INFINITY
+    }
+
+    fn leq(&self, other: &Self) -> bool {
+        other.contains_interval(*self)
+    }
+}
+
+impl Lattice for IntervalSet {
+    fn join(&self, other: &Self) -> Self {
+        self.union_intervals(other)
+    }
+
+    fn meet(&self, other: &Self) -> Self {
+        self.intersection(other)
+    }
+
+    fn bottom() -> Self {
+        IntervalSet::new()
+    }
+
+    fn top() -> Self {
+        IntervalSet::from(&[INFINITY])
+    }
+
+    fn leq(&self, other: &Self) -> bool {
+        self.is_subset_of(other)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Closed, Open};
+
+    #[test]
+    fn test_interval_1() {
+        let a = Interval::new(Closed(0.), Closed(1.));
+        let b = Interval::new(Open(0.5), Closed(2.));
+        assert_eq!(a.join(&b), Interval::new(Closed(0.), Closed(2.)));
+        assert_eq!(a.meet(&b), Interval::new(Open(0.5), Closed(1.)));
+        assert!(a.meet(&b).leq(&a) && a.leq(&a.join(&b)));
+        assert!(!a.leq(&b) && !b.leq(&a));
+        assert!(Interval::bottom().leq(&a) && a.leq(&Interval::top()));
+        assert_eq!(a.join(&Interval::bottom()), a);
+        assert_eq!(a.meet(&Interval::top()), a);
+    }
+
+    #[test]
+    fn test_set_1() {
+        let a = IntervalSet::from(&[Interval::new(Closed(0.), Closed(1.))]);
+        let b = IntervalSet::from(&[Interval::new(Closed(5.), Closed(6.))]);
+        let ab = a.join(&b);
+        assert!(a.leq(&ab) && b.leq(&ab) && !ab.leq(&a));
+        assert!(a.meet(&b).is_empty());
+        assert!(IntervalSet::bottom().leq(&a) && ab.leq(&IntervalSet::top()));
+        assert_eq!(ab.meet(&IntervalSet::top()), ab);
+    }
+}
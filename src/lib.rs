@@ -28,10 +28,84 @@
 //! let s = Interval::singleton(42.); // {42}, equivalent to Interval::new(Closed(42.), Closed(42.))
 //! ```
 //!
+//! ## NaN
+//!
+//! NaN is not a valid endpoint: intervals built from a NaN endpoint are empty, and NaN
+//! belongs to no interval or set. Fallible constructors report NaN as
+//! `IntervalError::NanEndpoint`.
+//!
+//! ```
+//! use interval::{Interval, Closed, EMPTY, INFINITY};
+//!
+//! assert_eq!(Interval::new(Closed(f64::NAN), Closed(42.)), EMPTY);
+//! assert!(!INFINITY.contains(f64::NAN));
+//! ```
+//!
 //!
 
+mod allen;
+#[doc(hidden)]
+pub mod approx;
+mod arith;
+mod builder;
+mod circular;
+mod collection;
+mod contractor;
+mod error;
+#[cfg(feature = "fuzzy")]
+mod fuzzy;
+mod histogram;
+mod integer;
 mod interval;
+mod interval_box;
 mod interval_set;
+mod iter;
+mod lattice;
+mod modular;
+mod partition;
+mod persistent;
+mod piecewise;
+#[cfg(feature = "plotters")]
+pub mod plot;
+#[cfg(feature = "pyo3")]
+pub mod python;
+pub mod schedule;
+#[cfg(feature = "serde")]
+mod serialize;
+mod set_expr;
+mod solver;
+mod step_function;
+mod strided;
+#[cfg(feature = "taylor")]
+mod taylor;
+#[cfg(feature = "wasm")]
+pub mod wasm;
 
-pub use interval::{Closed, Interval, Open, Unbound, EMPTY, INFINITY};
-pub use interval_set::IntervalSet;
+pub use allen::{Relation, RelationSet};
+pub use builder::IntervalSetBuilder;
+pub use circular::CircularInterval;
+pub use collection::{interval_join, max_overlap, overlap_graph, select_max_non_overlapping};
+pub use contractor::{propagate, Constraint, Expr};
+pub use error::IntervalError;
+#[cfg(feature = "fuzzy")]
+pub use fuzzy::FuzzyInterval;
+pub use histogram::Histogram;
+pub use integer::{I64Interval, U64Interval};
+pub use interval::{
+    Bound, Closed, Interval, IntervalKind, Monotonicity, Open, Side, SnapMode, Unbound, EMPTY,
+    INFINITY,
+};
+pub use interval_box::IntervalBox;
+pub use interval_set::{Conflict, IntervalSet};
+pub use iter::IntervalIteratorExt;
+pub use lattice::Lattice;
+pub use modular::ModularInterval;
+pub use partition::Partition;
+pub use persistent::PersistentIntervalSet;
+pub use piecewise::Piecewise;
+pub use set_expr::SetExpr;
+pub use solver::{gauss_seidel, krawczyk, RootStatus};
+pub use step_function::StepFunction;
+pub use strided::StridedInterval;
+#[cfg(feature = "taylor")]
+pub use taylor::TaylorModel;
@@ -0,0 +1,491 @@
+use super::IntervalError;
+use auto_ops::impl_op_ex;
+use std::fmt::Display;
+
+/// Range of residues of `ℤ/nℤ`, such as indices of a ring buffer
+///
+/// A range runs upward from its start to its end and may wrap around `0`, like `[6, 1]`
+/// modulo `8`, which holds `6, 7, 0, 1`. Residues are normalized into `[0, n)`.
+///
+/// Arithmetic operators enclose every sum, difference or product of members of operands
+/// in a single range; operands must share their modulus.
+///
+/// # Example
+///
+/// ```
+/// use interval::ModularInterval;
+///
+/// let head = ModularInterval::new(6, 7, 8).unwrap();
+/// let step = ModularInterval::new(1, 2, 8).unwrap();
+///
+/// assert_eq!(head + step, ModularInterval::new(7, 1, 8).unwrap());
+/// assert!((head + step).contains(0));
+/// assert!(!(head + step).contains(2));
+/// ```
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ModularInterval {
+    modulus: u64,
+    range: Range,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Range {
+    Empty,
+    Full,
+    /// `start` lies in `[0, modulus)` and `len + 1 < modulus`
+    Span {
+        start: u64,
+        len: u64,
+    },
+}
+
+impl Display for ModularInterval {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.endpoints() {
+            Some((start, end)) => write!(f, "[{start},{end}] mod {}", self.modulus),
+            None if self.is_empty() => write!(f, "∅"),
+            None => write!(f, "ℤ/{}ℤ", self.modulus),
+        }
+    }
+}
+
+impl ModularInterval {
+    /// Build range running upward from `start` to `end`, both reduced modulo `modulus`
+    ///
+    /// Fails if `modulus` is zero.
+    ///
+    pub fn new(start: u64, end: u64, modulus: u64) -> Result<Self, IntervalError> {
+        ModularInterval::checked_modulus(modulus)?;
+        let n = modulus as u128;
+        let start = start as u128 % n;
+        let len = (end as u128 % n + n - start) % n;
+        Ok(ModularInterval::from_bounds(start, start + len, modulus))
+    }
+
+    /// Empty range of `ℤ/nℤ`, `n` being `modulus`
+    ///
+    /// Fails if `modulus` is zero.
+    ///
+    pub fn empty(modulus: u64) -> Result<Self, IntervalError> {
+        ModularInterval::checked_modulus(modulus)?;
+        Ok(ModularInterval {
+            modulus,
+            range: Range::Empty,
+        })
+    }
+
+    /// Whole `ℤ/nℤ`, `n` being `modulus`
+    ///
+    /// Fails if `modulus` is zero.
+    ///
+    pub fn full(modulus: u64) -> Result<Self, IntervalError> {
+        ModularInterval::checked_modulus(modulus)?;
+        Ok(ModularInterval {
+            modulus,
+            range: Range::Full,
+        })
+    }
+
+    pub fn modulus(&self) -> u64 {
+        self.modulus
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.range == Range::Empty
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.range == Range::Full
+    }
+
+    /// Start and end residues of range, `None` if empty or full
+    ///
+    pub fn endpoints(&self) -> Option<(u64, u64)> {
+        match self.range {
+            Range::Span { start, len } => Some((start, self.reduce(start as u128 + len as u128))),
+            _ => None,
+        }
+    }
+
+    /// Number of residues of range
+    ///
+    pub fn count(&self) -> u64 {
+        match self.range {
+            Range::Empty => 0,
+            Range::Full => self.modulus,
+            Range::Span { len, .. } => len + 1,
+        }
+    }
+
+    /// Check if `k` (reduced first) belongs to range
+    ///
+    pub fn contains(&self, k: u64) -> bool {
+        match self.range {
+            Range::Empty => false,
+            Range::Full => true,
+            Range::Span { start, len } => self.offset(start, k) <= len,
+        }
+    }
+
+    /// Compute union of ranges
+    ///
+    /// Returns a single range when ranges overlap or follow each other, both ranges otherwise
+    /// (in the same way as `CircularInterval::union`). Ranges of different moduli are not
+    /// merged.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::ModularInterval;
+    ///
+    /// let a = ModularInterval::new(6, 7, 8).unwrap();
+    /// let b = ModularInterval::new(0, 2, 8).unwrap();
+    ///
+    /// assert_eq!(a.union(b), (ModularInterval::new(6, 2, 8).unwrap(), None));
+    /// ```
+    ///
+    pub fn union(self, other: ModularInterval) -> (ModularInterval, Option<ModularInterval>) {
+        if self.modulus != other.modulus {
+            return (self, Some(other));
+        }
+        match (self.range, other.range) {
+            (Range::Empty, _) | (_, Range::Full) => (other, None),
+            (_, Range::Empty) | (Range::Full, _) => (self, None),
+            (Range::Span { start: s1, len: l1 }, Range::Span { start: s2, len: l2 }) => {
+                let (d12, d21) = (self.offset(s1, s2), self.offset(s2, s1));
+                let (s1, l1, s2, l2) = (s1 as u128, l1 as u128, s2 as u128, l2 as u128);
+                if d12 as u128 <= l1 + 1 && d21 as u128 <= l2 + 1 {
+                    (self.with_range(Range::Full), None)
+                } else if d12 as u128 <= l1 + 1 {
+                    (self.span(s1, l1.max(d12 as u128 + l2)), None)
+                } else if d21 as u128 <= l2 + 1 {
+                    (self.span(s2, l2.max(d21 as u128 + l1)), None)
+                } else {
+                    (self, Some(other))
+                }
+            }
+        }
+    }
+
+    /// Compute intersection of ranges
+    ///
+    /// Two ranges may intersect in two disjoint pieces, the second one being returned apart
+    /// (in the same way as `union`). Ranges of different moduli don't intersect.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::ModularInterval;
+    ///
+    /// let a = ModularInterval::new(0, 5, 8).unwrap();
+    /// let b = ModularInterval::new(4, 1, 8).unwrap();
+    ///
+    /// assert_eq!(a.intersection(b), (
+    ///     ModularInterval::new(0, 1, 8).unwrap(),
+    ///     Some(ModularInterval::new(4, 5, 8).unwrap()),
+    /// ));
+    /// ```
+    ///
+    pub fn intersection(
+        self,
+        other: ModularInterval,
+    ) -> (ModularInterval, Option<ModularInterval>) {
+        if self.modulus != other.modulus {
+            return (self.with_range(Range::Empty), None);
+        }
+        match (self.range, other.range) {
+            (Range::Empty, _) | (_, Range::Full) => (self, None),
+            (_, Range::Empty) | (Range::Full, _) => (other, None),
+            (Range::Span { start: s1, len: l1 }, Range::Span { start: s2, len: l2 }) => {
+                // unroll other range around self, starting at 0
+                let d = self.offset(s1, s2) as i128;
+                let (l1, l2) = (l1 as i128, l2 as i128);
+                let pieces: Vec<_> = [d - self.modulus as i128, d]
+                    .into_iter()
+                    .filter_map(|a| {
+                        let (lower, upper) = (a.max(0), (a + l2).min(l1));
+                        (lower <= upper)
+                            .then(|| self.span(s1 as u128 + lower as u128, (upper - lower) as u128))
+                    })
+                    .collect();
+                match pieces[..] {
+                    [] => (self.with_range(Range::Empty), None),
+                    [a] => (a, None),
+                    [a, b] => (a, Some(b)),
+                    _ => unreachable!(),
+                }
+            }
+        }
+    }
+
+    /// Smallest and largest representatives of range, as integers running upward from a
+    /// residue of `[0, n)`
+    ///
+    fn bounds(&self) -> Option<(u128, u128)> {
+        match self.range {
+            Range::Empty => None,
+            Range::Full => Some((0, self.modulus as u128 - 1)),
+            Range::Span { start, len } => Some((start as u128, start as u128 + len as u128)),
+        }
+    }
+
+    /// Residues of integers `lower..=upper`
+    ///
+    fn from_bounds(lower: u128, upper: u128, modulus: u64) -> Self {
+        ModularInterval {
+            modulus,
+            range: Range::Empty,
+        }
+        .span(lower, upper - lower)
+    }
+
+    fn checked_modulus(modulus: u64) -> Result<(), IntervalError> {
+        match modulus {
+            0 => Err(IntervalError::InvalidPeriod),
+            _ => Ok(()),
+        }
+    }
+
+    /// Range of the same modulus
+    ///
+    fn with_range(&self, range: Range) -> ModularInterval {
+        ModularInterval {
+            modulus: self.modulus,
+            range,
+        }
+    }
+
+    /// Range of `len + 1` residues from `start` (reduced first), of the same modulus
+    ///
+    fn span(&self, start: u128, len: u128) -> ModularInterval {
+        let range = if len + 1 >= self.modulus as u128 {
+            Range::Full
+        } else {
+            Range::Span {
+                start: self.reduce(start),
+                len: len as u64,
+            }
+        };
+        self.with_range(range)
+    }
+
+    fn reduce(&self, k: u128) -> u64 {
+        (k % self.modulus as u128) as u64
+    }
+
+    /// Number of steps upward from residue `a` to `b` (reduced first)
+    ///
+    fn offset(&self, a: u64, b: u64) -> u64 {
+        let n = self.modulus as u128;
+        self.reduce(b as u128 % n + n - a as u128)
+    }
+
+    fn assert_compatible(&self, other: &ModularInterval) {
+        assert_eq!(self.modulus, other.modulus, "ranges differ in modulus");
+    }
+}
+
+/// Range from representatives `lower..=upper` of operands, empty if any operand is
+///
+fn combine(
+    a: &ModularInterval,
+    b: &ModularInterval,
+    op: impl Fn((u128, u128), (u128, u128)) -> Option<(u128, u128)>,
+) -> ModularInterval {
+    a.assert_compatible(b);
+    match (a.bounds(), b.bounds()) {
+        (Some(x), Some(y)) => match op(x, y) {
+            Some((lower, upper)) => ModularInterval::from_bounds(lower, upper, a.modulus),
+            None => a.with_range(Range::Full),
+        },
+        _ => a.with_range(Range::Empty),
+    }
+}
+
+fn neg(a: &ModularInterval) -> ModularInterval {
+    match a.bounds() {
+        // Representatives stay below 2n, so that 2n - k is a non-negative representative of -k
+        Some((lower, upper)) => {
+            let n2 = 2 * a.modulus as u128;
+            ModularInterval::from_bounds(n2 - upper, n2 - lower, a.modulus)
+        }
+        None => *a,
+    }
+}
+
+impl_op_ex!(+ |a: &ModularInterval, b: &ModularInterval| -> ModularInterval {
+    combine(a, b, |(l1, u1), (l2, u2)| Some((l1 + l2, u1 + u2)))
+});
+impl_op_ex!(-|a: &ModularInterval, b: &ModularInterval| -> ModularInterval { a + neg(b) });
+impl_op_ex!(
+    *|a: &ModularInterval, b: &ModularInterval| -> ModularInterval {
+        // Products of large representatives may overflow, in which case any residue is possible
+        combine(a, b, |(l1, u1), (l2, u2)| {
+            Some((l1 * l2, u1.checked_mul(u2)?))
+        })
+    }
+);
+impl_op_ex!(-|a: &ModularInterval| -> ModularInterval { neg(a) });
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_new_1() {
+        assert_eq!(
+            ModularInterval::new(0, 1, 0),
+            Err(IntervalError::InvalidPeriod)
+        );
+        assert_eq!(ModularInterval::empty(0), Err(IntervalError::InvalidPeriod));
+        assert_eq!(ModularInterval::full(0), Err(IntervalError::InvalidPeriod));
+        assert!(ModularInterval::new(3, 2, 8).unwrap().is_full());
+        assert_eq!(
+            ModularInterval::new(10, 12, 8).unwrap(),
+            ModularInterval::new(2, 4, 8).unwrap()
+        );
+        assert_eq!(
+            ModularInterval::new(6, 1, 8).unwrap().endpoints(),
+            Some((6, 1))
+        );
+        assert_eq!(ModularInterval::new(6, 1, 8).unwrap().count(), 4);
+        assert_eq!(
+            ModularInterval::new(6, 1, 8).unwrap().to_string(),
+            "[6,1] mod 8"
+        );
+        assert_eq!(ModularInterval::full(8).unwrap().to_string(), "ℤ/8ℤ");
+        assert!(ModularInterval::new(0, 0, 1).unwrap().is_full());
+        let big = ModularInterval::new(u64::MAX - 1, 1, u64::MAX).unwrap();
+        assert_eq!(big.count(), 3);
+    }
+
+    #[test]
+    fn test_contains_1() {
+        let a = ModularInterval::new(6, 1, 8).unwrap();
+        assert!(a.contains(6) && a.contains(0) && a.contains(1) && a.contains(15));
+        assert!(!a.contains(2) && !a.contains(5));
+        assert!(!ModularInterval::empty(8).unwrap().contains(0));
+    }
+
+    #[test]
+    fn test_union_1() {
+        let (a, b) = (
+            ModularInterval::new(6, 7, 8).unwrap(),
+            ModularInterval::new(2, 3, 8).unwrap(),
+        );
+        assert_eq!(a.union(b), (a, Some(b)));
+        assert_eq!(
+            a.union(ModularInterval::new(0, 2, 8).unwrap()),
+            (ModularInterval::new(6, 2, 8).unwrap(), None)
+        );
+        assert_eq!(
+            ModularInterval::new(0, 2, 8).unwrap().union(a),
+            (ModularInterval::new(6, 2, 8).unwrap(), None)
+        );
+        assert!(ModularInterval::new(0, 4, 8)
+            .unwrap()
+            .union(ModularInterval::new(5, 7, 8).unwrap())
+            .0
+            .is_full());
+        assert!(ModularInterval::new(0, 5, 8)
+            .unwrap()
+            .union(ModularInterval::new(4, 1, 8).unwrap())
+            .0
+            .is_full());
+        assert_eq!(
+            ModularInterval::new(0, 5, 8)
+                .unwrap()
+                .union(ModularInterval::new(1, 2, 8).unwrap()),
+            (ModularInterval::new(0, 5, 8).unwrap(), None)
+        );
+    }
+
+    #[test]
+    fn test_intersection_1() {
+        assert_eq!(
+            ModularInterval::new(6, 1, 8)
+                .unwrap()
+                .intersection(ModularInterval::new(1, 3, 8).unwrap()),
+            (ModularInterval::new(1, 1, 8).unwrap(), None)
+        );
+        assert!(ModularInterval::new(6, 1, 8)
+            .unwrap()
+            .intersection(ModularInterval::new(2, 3, 8).unwrap())
+            .0
+            .is_empty());
+        assert_eq!(
+            ModularInterval::new(0, 3, 8)
+                .unwrap()
+                .intersection(ModularInterval::full(8).unwrap()),
+            (ModularInterval::new(0, 3, 8).unwrap(), None)
+        );
+    }
+
+    #[test]
+    fn test_arith_1() {
+        assert_eq!(
+            ModularInterval::new(6, 7, 8).unwrap() - ModularInterval::new(1, 2, 8).unwrap(),
+            ModularInterval::new(4, 6, 8).unwrap()
+        );
+        assert_eq!(
+            -ModularInterval::new(1, 2, 8).unwrap(),
+            ModularInterval::new(6, 7, 8).unwrap()
+        );
+        assert_eq!(
+            -ModularInterval::new(0, 0, 8).unwrap(),
+            ModularInterval::new(0, 0, 8).unwrap()
+        );
+        assert!(
+            (ModularInterval::new(0, 3, 8).unwrap() + ModularInterval::new(0, 4, 8).unwrap())
+                .is_full()
+        );
+        assert_eq!(
+            ModularInterval::new(2, 3, 8).unwrap() * ModularInterval::new(3, 3, 8).unwrap(),
+            ModularInterval::new(6, 1, 8).unwrap()
+        );
+        assert_eq!(
+            ModularInterval::full(8).unwrap() * ModularInterval::new(0, 0, 8).unwrap(),
+            ModularInterval::new(0, 0, 8).unwrap()
+        );
+        assert!(
+            (ModularInterval::new(0, 1, 8).unwrap() + ModularInterval::empty(8).unwrap())
+                .is_empty()
+        );
+        let n = u64::MAX;
+        let minus_one = ModularInterval::new(n - 1, n - 1, n).unwrap();
+        assert_eq!(
+            minus_one * minus_one,
+            ModularInterval::new(1, 1, n).unwrap()
+        );
+        // Products of representatives overflow
+        let wrapped = ModularInterval::new(n - 1, 1, n).unwrap();
+        assert!((wrapped * wrapped).is_full());
+    }
+
+    #[test]
+    fn test_arith_2() {
+        // Brute force soundness check over every pair of ranges modulo 5
+        let n = 5;
+        let ranges: Vec<_> = (0..n)
+            .flat_map(|s| (0..n).map(move |e| ModularInterval::new(s, e, n).unwrap()))
+            .collect();
+        for a in &ranges {
+            for b in &ranges {
+                for x in (0..n).filter(|x| a.contains(*x)) {
+                    for y in (0..n).filter(|y| b.contains(*y)) {
+                        assert!((a + b).contains(x + y));
+                        assert!((a - b).contains(x + n - y));
+                        assert!((a * b).contains(x * y));
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_arith_3() {
+        let _ = ModularInterval::new(0, 1, 8).unwrap() + ModularInterval::new(0, 1, 9).unwrap();
+    }
+}
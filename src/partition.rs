@@ -0,0 +1,244 @@
+use super::interval::Edge;
+use super::{Interval, IntervalError, EMPTY};
+
+/// Contiguous, non-overlapping cover of a domain interval by non-empty bins
+///
+/// Bins built from breakpoints are closed on the left and open on the right, except the
+/// last one which keeps the right bound of the domain.
+///
+/// # Example
+///
+/// ```
+/// use interval::{Interval, Partition, Closed};
+///
+/// let p = Partition::equal_width(Interval::new(Closed(0.), Closed(10.)), 4).unwrap();
+///
+/// assert_eq!(p.len(), 4);
+/// assert_eq!(p.locate(2.5), Some(1));
+/// assert_eq!(p.locate(10.), Some(3));
+/// assert_eq!(p.locate(11.), None);
+/// ```
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct Partition {
+    bins: Vec<Interval>,
+}
+
+impl Partition {
+    /// Build partition from its bins
+    ///
+    /// Fails unless there is at least one bin, no bin is empty and each bin starts exactly
+    /// where the previous one ends.
+    ///
+    pub fn new(bins: Vec<Interval>) -> Result<Self, IntervalError> {
+        if bins.is_empty() || bins.iter().any(|bin| bin.is_empty()) {
+            return Err(IntervalError::Empty);
+        }
+        for w in bins.windows(2) {
+            if !w[0].is_continued_by(w[1]) {
+                return Err(IntervalError::NotContiguous);
+            }
+        }
+        Ok(Partition { bins })
+    }
+
+    /// Split bounded `domain` into `n` bins of equal width
+    ///
+    /// Fails if `n` is zero, or if `domain` is unbounded or too narrow to hold `n`
+    /// non-empty bins.
+    ///
+    pub fn equal_width(domain: Interval, n: usize) -> Result<Self, IntervalError> {
+        let (start, end) = domain.edges().ok_or(IntervalError::Empty)?;
+        let (a, b) = (start.value, end.value);
+        if n == 0 {
+            return Err(IntervalError::ZeroCount);
+        }
+        if !a.is_finite() || !b.is_finite() {
+            return Err(IntervalError::Unbounded);
+        }
+        let mut edges = vec![start];
+        edges.extend((1..n).map(|i| Edge {
+            value: a + (b - a) * i as f64 / n as f64,
+            after: false,
+        }));
+        edges.push(end);
+        Partition::from_edges(&edges)
+    }
+
+    /// Build partition of `[p0, pn]` from breakpoints `p0 < p1 < ... < pn`
+    ///
+    /// Infinite first or last breakpoint gives an unbound domain. Fails if there are fewer
+    /// than two breakpoints, if one is NaN or if they are not strictly increasing.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Partition, Closed, Open};
+    ///
+    /// let p = Partition::from_breakpoints(&[0., 1., 5.]).unwrap();
+    ///
+    /// assert_eq!(p.bins(), &[
+    ///     Interval::new(Closed(0.), Open(1.)),
+    ///     Interval::new(Closed(1.), Closed(5.)),
+    /// ]);
+    /// ```
+    ///
+    pub fn from_breakpoints(breakpoints: &[f64]) -> Result<Self, IntervalError> {
+        let n = breakpoints.len();
+        if breakpoints.iter().any(|p| p.is_nan()) {
+            return Err(IntervalError::NanEndpoint);
+        }
+        if n < 2 {
+            return Err(IntervalError::Empty);
+        }
+        if !breakpoints.windows(2).all(|w| w[0] < w[1]) {
+            return Err(IntervalError::ReversedBounds);
+        }
+        let edges: Vec<_> = breakpoints
+            .iter()
+            .enumerate()
+            .map(|(i, &value)| Edge {
+                value,
+                after: i == n - 1,
+            })
+            .collect();
+        Partition::from_edges(&edges)
+    }
+
+    fn from_edges(edges: &[Edge]) -> Result<Self, IntervalError> {
+        let bins = edges
+            .windows(2)
+            .map(|w| Interval::from_edges(w[0], w[1]))
+            .collect();
+        Partition::new(bins)
+    }
+
+    pub fn bins(&self) -> &[Interval] {
+        &self.bins
+    }
+
+    pub fn len(&self) -> usize {
+        self.bins.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bins.is_empty()
+    }
+
+    /// Union of all bins
+    ///
+    pub fn domain(&self) -> Interval {
+        match (self.bins.first(), self.bins.last()) {
+            (Some(first), Some(last)) => first.hull(*last),
+            _ => EMPTY,
+        }
+    }
+
+    /// Index of the bin containing `x`
+    ///
+    pub fn locate(&self, x: f64) -> Option<usize> {
+        let i = self.bins.partition_point(|bin| bin.is_below(x));
+        match self.bins.get(i) {
+            Some(bin) if bin.contains(x) => Some(i),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Closed, Open, Unbound};
+
+    #[test]
+    fn test_new_1() {
+        let a = Interval::new(Closed(0.), Open(1.));
+        let b = Interval::new(Closed(1.), Closed(2.));
+        assert!(Partition::new(vec![a, b]).is_ok());
+        assert_eq!(
+            Partition::new(vec![b, a]),
+            Err(IntervalError::NotContiguous)
+        );
+        assert_eq!(
+            Partition::new(vec![a, a]),
+            Err(IntervalError::NotContiguous)
+        );
+        assert_eq!(Partition::new(vec![]), Err(IntervalError::Empty));
+        assert_eq!(Partition::new(vec![a, EMPTY]), Err(IntervalError::Empty));
+    }
+
+    #[test]
+    fn test_equal_width_1() {
+        let p = Partition::equal_width(Interval::new(Open(0.), Open(3.)), 3).unwrap();
+        assert_eq!(
+            p.bins(),
+            &[
+                Interval::new(Open(0.), Open(1.)),
+                Interval::new(Closed(1.), Open(2.)),
+                Interval::new(Closed(2.), Open(3.)),
+            ]
+        );
+        assert_eq!(p.domain(), Interval::new(Open(0.), Open(3.)));
+    }
+
+    #[test]
+    fn test_equal_width_2() {
+        let domain = Interval::new(Closed(0.), Closed(1.));
+        assert_eq!(
+            Partition::equal_width(domain, 0),
+            Err(IntervalError::ZeroCount)
+        );
+        assert_eq!(
+            Partition::equal_width(Interval::new(Closed(0.), Unbound), 2),
+            Err(IntervalError::Unbounded)
+        );
+        assert_eq!(Partition::equal_width(EMPTY, 2), Err(IntervalError::Empty));
+        assert!(Partition::equal_width(Interval::singleton(1.), 2).is_err());
+        assert_eq!(
+            Partition::equal_width(Interval::singleton(1.), 1)
+                .unwrap()
+                .bins(),
+            &[Interval::singleton(1.)]
+        );
+    }
+
+    #[test]
+    fn test_from_breakpoints_1() {
+        assert_eq!(
+            Partition::from_breakpoints(&[0.]),
+            Err(IntervalError::Empty)
+        );
+        assert_eq!(
+            Partition::from_breakpoints(&[0., 0.]),
+            Err(IntervalError::ReversedBounds)
+        );
+        assert!(Partition::from_breakpoints(&[0., 2., 1.]).is_err());
+        assert_eq!(
+            Partition::from_breakpoints(&[0., f64::NAN]),
+            Err(IntervalError::NanEndpoint)
+        );
+    }
+
+    #[test]
+    fn test_from_breakpoints_2() {
+        let p = Partition::from_breakpoints(&[f64::NEG_INFINITY, 0., f64::INFINITY]).unwrap();
+        assert_eq!(
+            p.bins(),
+            &[
+                Interval::new(Unbound, Open(0.)),
+                Interval::new(Closed(0.), Unbound),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_locate_1() {
+        let p = Partition::from_breakpoints(&[0., 1., 2., 4.]).unwrap();
+        assert_eq!(p.locate(-1.), None);
+        assert_eq!(p.locate(0.), Some(0));
+        assert_eq!(p.locate(1.), Some(1));
+        assert_eq!(p.locate(3.), Some(2));
+        assert_eq!(p.locate(4.), Some(2));
+        assert_eq!(p.locate(4.5), None);
+    }
+}
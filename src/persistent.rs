@@ -0,0 +1,260 @@
+use super::{Interval, IntervalSet};
+use std::sync::Arc;
+
+/// Maximum number of segments in a chunk
+///
+const CHUNK: usize = 32;
+
+/// Immutable interval set sharing unchanged segments between versions
+///
+/// Segments are stored, in the same canonical form as `IntervalSet`, in reference-counted
+/// chunks. Operations return a new version and only rebuild the chunks they change, so
+/// that keeping many historical versions of a large set is cheap. Versions can be sent
+/// between threads.
+///
+/// # Example
+///
+/// ```
+/// use interval::{Interval, IntervalSet, PersistentIntervalSet, Closed, Open};
+///
+/// let v1 = PersistentIntervalSet::from(&IntervalSet::from(&[
+///     Interval::new(Closed(0.), Open(10.)),
+///     Interval::new(Closed(20.), Open(30.)),
+/// ]));
+/// let v2 = v1.union_interval(&Interval::new(Closed(10.), Open(15.)));
+/// let v3 = v2.difference_interval(&Interval::new(Closed(5.), Open(25.)));
+///
+/// assert_eq!(v1.len(), 2);
+/// assert_eq!(v2.to_interval_set(), IntervalSet::from(&[
+///     Interval::new(Closed(0.), Open(15.)),
+///     Interval::new(Closed(20.), Open(30.)),
+/// ]));
+/// assert_eq!(v3.to_interval_set(), IntervalSet::from(&[
+///     Interval::new(Closed(0.), Open(5.)),
+///     Interval::new(Closed(25.), Open(30.)),
+/// ]));
+/// ```
+///
+#[derive(Debug, Default, Clone)]
+pub struct PersistentIntervalSet {
+    chunks: Vec<Arc<[Interval]>>,
+}
+
+impl From<&IntervalSet> for PersistentIntervalSet {
+    fn from(set: &IntervalSet) -> Self {
+        PersistentIntervalSet {
+            chunks: chunked(set.as_slice()),
+        }
+    }
+}
+
+impl PartialEq for PersistentIntervalSet {
+    fn eq(&self, other: &Self) -> bool {
+        self.iter().eq(other.iter())
+    }
+}
+
+impl PersistentIntervalSet {
+    pub fn new() -> Self {
+        PersistentIntervalSet { chunks: Vec::new() }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.chunks.is_empty()
+    }
+
+    /// Number of segments of set
+    ///
+    pub fn len(&self) -> usize {
+        self.chunks.iter().map(|chunk| chunk.len()).sum()
+    }
+
+    /// Segments of set, in increasing order
+    ///
+    pub fn iter(&self) -> impl Iterator<Item = &Interval> {
+        self.chunks.iter().flat_map(|chunk| chunk.iter())
+    }
+
+    pub fn to_interval_set(&self) -> IntervalSet {
+        IntervalSet::from_unsorted(self.iter().copied().collect())
+    }
+
+    pub fn contains(&self, x: f64) -> bool {
+        let i = self
+            .chunks
+            .partition_point(|chunk| chunk[chunk.len() - 1].is_below(x));
+        self.chunks.get(i).is_some_and(|chunk| {
+            let j = chunk.partition_point(|segment| segment.is_below(x));
+            chunk.get(j).is_some_and(|segment| segment.contains(x))
+        })
+    }
+
+    /// New version with `interval` added
+    ///
+    pub fn union_interval(&self, interval: &Interval) -> Self {
+        if interval.is_empty() {
+            return self.clone();
+        }
+        let a = *interval;
+        // Chunks holding segments that overlap or touch interval
+        let mut lo = self
+            .chunks
+            .partition_point(|chunk| precedes(chunk[chunk.len() - 1], a));
+        let mut hi = self.chunks.partition_point(|chunk| !precedes(a, chunk[0]));
+        if lo == hi {
+            // Interval lies between chunks: add it to a neighbour rather than to a chunk of
+            // its own
+            if lo > 0 {
+                lo -= 1;
+            } else if hi < self.chunks.len() {
+                hi += 1;
+            }
+        }
+        self.rebuild(lo, hi, |set| set.union_interval(&a))
+    }
+
+    /// New version with segments of `other` added
+    ///
+    pub fn union(&self, other: &PersistentIntervalSet) -> Self {
+        other
+            .iter()
+            .fold(self.clone(), |res, segment| res.union_interval(segment))
+    }
+
+    /// New version with `interval` removed
+    ///
+    pub fn difference_interval(&self, interval: &Interval) -> Self {
+        let a = *interval;
+        let disjoint_below =
+            |b: Interval, c: Interval| b.intersection(c).is_empty() && b.ends_before(c);
+        // Chunks holding segments that overlap interval
+        let lo = self
+            .chunks
+            .partition_point(|chunk| disjoint_below(chunk[chunk.len() - 1], a));
+        let hi = self
+            .chunks
+            .partition_point(|chunk| !disjoint_below(a, chunk[0]));
+        if lo >= hi {
+            return self.clone();
+        }
+        let removed = IntervalSet::from(&[a]).complement();
+        self.rebuild(lo, hi, |set| set.intersection(&removed))
+    }
+
+    /// New version with segments of `other` removed
+    ///
+    pub fn difference(&self, other: &PersistentIntervalSet) -> Self {
+        other.iter().fold(self.clone(), |res, segment| {
+            res.difference_interval(segment)
+        })
+    }
+
+    /// New version where chunks `lo..hi` are replaced by the chunks of `op` applied to
+    /// their segments, other chunks (or every chunk, if `op` changes nothing) being shared
+    ///
+    fn rebuild(&self, lo: usize, hi: usize, op: impl Fn(&IntervalSet) -> IntervalSet) -> Self {
+        let segments: Vec<_> = self.chunks[lo..hi]
+            .iter()
+            .flat_map(|chunk| chunk.iter())
+            .copied()
+            .collect();
+        let set = op(&IntervalSet::from_unsorted(segments.clone()));
+        if set.as_slice() == segments {
+            return self.clone();
+        }
+        let mut chunks = Vec::with_capacity(self.chunks.len() + 1);
+        chunks.extend_from_slice(&self.chunks[..lo]);
+        chunks.extend(chunked(set.as_slice()));
+        chunks.extend_from_slice(&self.chunks[hi..]);
+        PersistentIntervalSet { chunks }
+    }
+}
+
+/// Check if `a` lies below `b` without touching it
+///
+fn precedes(a: Interval, b: Interval) -> bool {
+    matches!(a.union(b), (lower, Some(_)) if lower == a)
+}
+
+fn chunked(segments: &[Interval]) -> Vec<Arc<[Interval]>> {
+    segments.chunks(CHUNK).map(Arc::from).collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Closed, Open, Unbound, INFINITY};
+
+    /// Set of `n` unit segments `[2i, 2i + 1]`
+    ///
+    fn comb(n: usize) -> PersistentIntervalSet {
+        let segments: Vec<_> = (0..n)
+            .map(|i| Interval::new(Closed(2. * i as f64), Closed(2. * i as f64 + 1.)))
+            .collect();
+        PersistentIntervalSet::from(&IntervalSet::from_unsorted(segments))
+    }
+
+    fn shared(a: &PersistentIntervalSet, b: &PersistentIntervalSet) -> usize {
+        a.chunks
+            .iter()
+            .filter(|x| b.chunks.iter().any(|y| Arc::ptr_eq(x, y)))
+            .count()
+    }
+
+    #[test]
+    fn test_union_interval_1() {
+        let a = comb(100);
+        assert_eq!(a.chunks.len(), 4);
+        let b = a.union_interval(&Interval::new(Open(1.), Open(2.)));
+        assert_eq!(b.len(), 99);
+        assert_eq!(shared(&a, &b), 3);
+        assert_eq!(a.len(), 100);
+        let c = b.union_interval(&Interval::new(Closed(-10.), Closed(-5.)));
+        assert_eq!(c.len(), 100);
+        assert_eq!(shared(&b, &c), 3);
+        assert_eq!(
+            c.iter().next(),
+            Some(&Interval::new(Closed(-10.), Closed(-5.)))
+        );
+    }
+
+    #[test]
+    fn test_union_interval_2() {
+        let a = comb(100);
+        // Across a chunk boundary
+        let b = a.union_interval(&Interval::new(Closed(63.), Closed(64.)));
+        assert_eq!(b.len(), 99);
+        assert!(b.contains(63.5) && !a.contains(63.5));
+        let c = PersistentIntervalSet::new().union_interval(&INFINITY);
+        assert_eq!(c.to_interval_set(), IntervalSet::from(&[INFINITY]));
+        assert_eq!(a.union_interval(&INFINITY), c);
+        assert_eq!(a.union(&c), c);
+    }
+
+    #[test]
+    fn test_difference_interval_1() {
+        let a = comb(100);
+        let b = a.difference_interval(&Interval::new(Closed(0.5), Closed(2.5)));
+        assert_eq!(b.len(), 100);
+        assert_eq!(shared(&a, &b), 3);
+        assert!(!b.contains(0.75) && b.contains(0.25) && b.contains(3.));
+        assert_eq!(
+            shared(
+                &a,
+                &a.difference_interval(&Interval::new(Open(1.), Open(2.)))
+            ),
+            4
+        );
+        let c = a.difference_interval(&Interval::new(Unbound, Closed(150.)));
+        assert_eq!(c.len(), 25);
+        assert!(a.difference(&a).is_empty());
+    }
+
+    #[test]
+    fn test_contains_1() {
+        let a = comb(100);
+        assert!(a.contains(0.) && a.contains(198.5) && a.contains(64.));
+        assert!(!a.contains(-1.) && !a.contains(1.5) && !a.contains(200.));
+        assert!(!PersistentIntervalSet::new().contains(0.));
+    }
+}
@@ -0,0 +1,282 @@
+use super::{Interval, IntervalError, EMPTY};
+
+/// Function defined piece by piece over an ordered partition of a domain interval
+///
+/// Each piece holds a value, which may be a constant or a closure evaluated with
+/// `Piecewise::apply`.
+///
+/// # Example
+///
+/// ```
+/// use interval::{Interval, Piecewise, Closed, Open, Unbound};
+///
+/// let tax = Piecewise::new(vec![
+///     (Interval::new(Closed(0.), Open(10.)), 0.),
+///     (Interval::new(Closed(10.), Open(50.)), 0.2),
+///     (Interval::new(Closed(50.), Unbound), 0.4),
+/// ])
+/// .unwrap();
+///
+/// assert_eq!(tax.eval(10.), Some(&0.2));
+/// assert_eq!(tax.eval(-1.), None);
+/// ```
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct Piecewise<V> {
+    pieces: Vec<(Interval, V)>,
+}
+
+impl<V> Piecewise<V> {
+    /// Build piecewise function from its pieces
+    ///
+    /// Fails unless pieces are non-empty, sorted and contiguous (each piece starting
+    /// exactly where the previous one ends, without overlapping it).
+    ///
+    pub fn new(pieces: Vec<(Interval, V)>) -> Result<Self, IntervalError> {
+        if pieces.iter().any(|(piece, _)| piece.is_empty()) {
+            return Err(IntervalError::Empty);
+        }
+        for w in pieces.windows(2) {
+            if !w[0].0.is_continued_by(w[1].0) {
+                return Err(IntervalError::NotContiguous);
+            }
+        }
+        Ok(Piecewise { pieces })
+    }
+
+    /// Piecewise function with a single piece
+    ///
+    pub fn constant(domain: Interval, value: V) -> Self {
+        if domain.is_empty() {
+            Piecewise { pieces: Vec::new() }
+        } else {
+            Piecewise {
+                pieces: vec![(domain, value)],
+            }
+        }
+    }
+
+    pub fn pieces(&self) -> &[(Interval, V)] {
+        &self.pieces
+    }
+
+    /// Union of all pieces
+    ///
+    pub fn domain(&self) -> Interval {
+        match (self.pieces.first(), self.pieces.last()) {
+            (Some((first, _)), Some((last, _))) => first.hull(*last),
+            _ => EMPTY,
+        }
+    }
+
+    /// Value of the piece containing `x`
+    ///
+    pub fn eval(&self, x: f64) -> Option<&V> {
+        let i = self.pieces.partition_point(|(piece, _)| piece.is_below(x));
+        match self.pieces.get(i) {
+            Some((piece, value)) if piece.contains(x) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Call the closure of the piece containing `x` with `x`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Piecewise, Closed, Open, Unbound};
+    ///
+    /// let abs = Piecewise::new(vec![
+    ///     (Interval::new(Unbound, Open(0.)), Box::new(|x: f64| -x) as Box<dyn Fn(f64) -> f64>),
+    ///     (Interval::new(Closed(0.), Unbound), Box::new(|x: f64| x)),
+    /// ])
+    /// .unwrap();
+    ///
+    /// assert_eq!(abs.apply(-2.), Some(2.));
+    /// ```
+    ///
+    pub fn apply<T>(&self, x: f64) -> Option<T>
+    where
+        V: Fn(f64) -> T,
+    {
+        self.eval(x).map(|f| f(x))
+    }
+
+    /// Transform value of every piece
+    ///
+    pub fn map<W>(&self, f: impl Fn(&V) -> W) -> Piecewise<W> {
+        Piecewise {
+            pieces: self
+                .pieces
+                .iter()
+                .map(|(piece, value)| (*piece, f(value)))
+                .collect(),
+        }
+    }
+
+    /// Restrict function to `domain`
+    ///
+    pub fn restrict(&self, domain: Interval) -> Self
+    where
+        V: Clone,
+    {
+        Piecewise {
+            pieces: self
+                .pieces
+                .iter()
+                .map(|(piece, value)| (piece.intersection(domain), value))
+                .filter(|(piece, _)| !piece.is_empty())
+                .map(|(piece, value)| (piece, value.clone()))
+                .collect(),
+        }
+    }
+
+    /// Combine two piecewise functions pointwise
+    ///
+    /// The result is defined over the intersection of both domains, on the common
+    /// refinement of both partitions.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Piecewise, Closed, Open};
+    ///
+    /// let f = Piecewise::new(vec![
+    ///     (Interval::new(Closed(0.), Open(5.)), 1.),
+    ///     (Interval::new(Closed(5.), Closed(10.)), 2.),
+    /// ])
+    /// .unwrap();
+    /// let g = Piecewise::constant(Interval::new(Closed(2.), Closed(20.)), 10.);
+    /// let h = f.combine(&g, |a, b| a + b);
+    ///
+    /// assert_eq!(h.domain(), Interval::new(Closed(2.), Closed(10.)));
+    /// assert_eq!(h.eval(3.), Some(&11.));
+    /// assert_eq!(h.eval(7.), Some(&12.));
+    /// ```
+    ///
+    pub fn combine<W, U>(&self, other: &Piecewise<W>, f: impl Fn(&V, &W) -> U) -> Piecewise<U> {
+        let mut pieces = Vec::new();
+        let (mut i, mut j) = (0, 0);
+
+        while let (Some((a, va)), Some((b, vb))) = (self.pieces.get(i), other.pieces.get(j)) {
+            let piece = a.intersection(*b);
+            if !piece.is_empty() {
+                pieces.push((piece, f(va, vb)));
+            }
+            if a.ends_before(*b) {
+                i += 1;
+            } else if b.ends_before(*a) {
+                j += 1;
+            } else {
+                i += 1;
+                j += 1;
+            }
+        }
+        Piecewise { pieces }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Closed, Open, Unbound, INFINITY};
+
+    fn steps() -> Piecewise<i32> {
+        Piecewise::new(vec![
+            (Interval::new(Unbound, Open(0.)), 0),
+            (Interval::singleton(0.), 1),
+            (Interval::new(Open(0.), Closed(10.)), 2),
+            (Interval::new(Open(10.), Closed(20.)), 3),
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    fn test_new_1() {
+        let a = Interval::new(Closed(0.), Open(10.));
+        let b = Interval::new(Closed(10.), Closed(20.));
+        assert!(Piecewise::new(vec![(a, 0), (b, 1)]).is_ok());
+        assert_eq!(
+            Piecewise::new(vec![(b, 0), (a, 1)]),
+            Err(IntervalError::NotContiguous)
+        );
+        assert_eq!(
+            Piecewise::new(vec![(a, 0), (EMPTY, 1)]),
+            Err(IntervalError::Empty)
+        );
+        assert!(Piecewise::<i32>::new(vec![]).is_ok());
+    }
+
+    #[test]
+    fn test_new_2() {
+        let a = Interval::new(Closed(0.), Closed(10.));
+        let b = Interval::new(Closed(10.), Closed(20.));
+        let c = Interval::new(Open(10.), Closed(20.));
+        let d = Interval::new(Open(11.), Closed(20.));
+        let e = Interval::new(Closed(0.), Open(10.));
+        assert!(Piecewise::new(vec![(a, 0), (b, 1)]).is_err());
+        assert!(Piecewise::new(vec![(a, 0), (d, 1)]).is_err());
+        assert!(Piecewise::new(vec![(e, 0), (c, 1)]).is_err());
+    }
+
+    #[test]
+    fn test_eval_1() {
+        let f = steps();
+        assert_eq!(f.eval(-1.), Some(&0));
+        assert_eq!(f.eval(0.), Some(&1));
+        assert_eq!(f.eval(10.), Some(&2));
+        assert_eq!(f.eval(10.5), Some(&3));
+        assert_eq!(f.eval(20.5), None);
+    }
+
+    #[test]
+    fn test_domain_1() {
+        assert_eq!(steps().domain(), Interval::new(Unbound, Closed(20.)));
+        assert_eq!(Piecewise::constant(EMPTY, 0).domain(), EMPTY);
+    }
+
+    #[test]
+    fn test_restrict_1() {
+        let f = steps().restrict(Interval::new(Closed(5.), Closed(15.)));
+        assert_eq!(
+            f.pieces(),
+            &[
+                (Interval::new(Closed(5.), Closed(10.)), 2),
+                (Interval::new(Open(10.), Closed(15.)), 3),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_combine_1() {
+        let f = steps();
+        let g = Piecewise::new(vec![
+            (Interval::new(Closed(-5.), Open(5.)), 10),
+            (Interval::new(Closed(5.), Unbound), 20),
+        ])
+        .unwrap();
+        let h = f.combine(&g, |a, b| a + b);
+        assert_eq!(
+            h.pieces(),
+            &[
+                (Interval::new(Closed(-5.), Open(0.)), 10),
+                (Interval::singleton(0.), 11),
+                (Interval::new(Open(0.), Open(5.)), 12),
+                (Interval::new(Closed(5.), Closed(10.)), 22),
+                (Interval::new(Open(10.), Closed(20.)), 23),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_map_1() {
+        let f = steps().map(|v| v * 2);
+        assert_eq!(f.eval(15.), Some(&6));
+    }
+
+    #[test]
+    fn test_apply_1() {
+        let f = Piecewise::constant(INFINITY, |x: f64| x * 2.);
+        assert_eq!(f.apply(21.), Some(42.));
+    }
+}
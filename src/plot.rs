@@ -0,0 +1,93 @@
+//! Drawing intervals with `plotters` (feature `plotters`)
+//!
+//! Intervals are drawn as shaded spans along the x axis of a `(f64, f64)` chart, between
+//! two heights. Unbound endpoints are clipped to a bounded view.
+//!
+//! ```
+//! use interval::{Interval, IntervalSet, Closed, Open};
+//! use interval::plot;
+//! use plotters::style::{Color, BLUE};
+//!
+//! let busy = IntervalSet::from(&[
+//!     Interval::new(Closed(9.), Open(12.)),
+//!     Interval::new(Closed(14.), Open(15.)),
+//! ]);
+//! let view = Interval::new(Closed(8.), Closed(18.));
+//!
+//! let rectangles = plot::spans(&busy, view, (0., 1.), BLUE.mix(0.3).filled());
+//! assert_eq!(rectangles.len(), 2);
+//! // chart.draw_series(rectangles)?;
+//! ```
+
+use plotters::element::Rectangle;
+use plotters::style::ShapeStyle;
+
+use super::{Interval, IntervalSet};
+
+/// Rectangle shading `interval` clipped to `view`, from height `y.0` to `y.1`
+///
+/// Returns `None` if the clipped interval is empty or still unbounded.
+///
+pub fn span<S: Into<ShapeStyle>>(
+    interval: Interval,
+    view: Interval,
+    y: (f64, f64),
+    style: S,
+) -> Option<Rectangle<(f64, f64)>> {
+    match interval.intersection(view).endpoints() {
+        Some((a, b)) if a.is_finite() && b.is_finite() => {
+            Some(Rectangle::new([(a, y.0), (b, y.1)], style))
+        }
+        _ => None,
+    }
+}
+
+/// Rectangles shading every segment of `set` clipped to `view`, from height `y.0` to `y.1`
+///
+pub fn spans<S: Into<ShapeStyle>>(
+    set: &IntervalSet,
+    view: Interval,
+    y: (f64, f64),
+    style: S,
+) -> Vec<Rectangle<(f64, f64)>> {
+    let style = style.into();
+    set.as_slice()
+        .iter()
+        .filter_map(|segment| span(*segment, view, y, style))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Closed, Open, Unbound, INFINITY};
+    use plotters::element::PointCollection;
+    use plotters::style::BLACK;
+
+    fn corners(rectangle: &Rectangle<(f64, f64)>) -> Vec<(f64, f64)> {
+        rectangle.point_iter().to_vec()
+    }
+
+    #[test]
+    fn test_span_1() {
+        let view = Interval::new(Closed(0.), Closed(10.));
+        let a = Interval::new(Closed(5.), Unbound);
+        let rectangle = span(a, view, (0., 1.), BLACK).unwrap();
+        assert_eq!(corners(&rectangle), vec![(5., 0.), (10., 1.)]);
+        assert!(span(a, INFINITY, (0., 1.), BLACK).is_none());
+        assert!(span(Interval::new(Open(20.), Unbound), view, (0., 1.), BLACK).is_none());
+    }
+
+    #[test]
+    fn test_spans_1() {
+        let set = IntervalSet::from(&[
+            Interval::new(Unbound, Closed(1.)),
+            Interval::new(Closed(2.), Closed(3.)),
+            Interval::new(Closed(20.), Closed(30.)),
+        ]);
+        let view = Interval::new(Closed(0.), Closed(10.));
+        let rectangles = spans(&set, view, (0., 1.), BLACK);
+        assert_eq!(rectangles.len(), 2);
+        assert_eq!(corners(&rectangles[0]), vec![(0., 0.), (1., 1.)]);
+    }
+}
@@ -0,0 +1,183 @@
+//! Python bindings with `pyo3` (feature `pyo3`)
+//!
+//! `Interval` and `IntervalSet` are exposed to Python under the same names, in a module
+//! named `interval`. `None` stands for an unbound endpoint. The extension library is built
+//! with `cargo rustc --release --lib --features pyo3 --crate-type cdylib`.
+//!
+//! ```python
+//! from interval import Interval, IntervalSet
+//!
+//! a = Interval(0., 8., right_closed=False)
+//! b = Interval(20., None)
+//! s = IntervalSet([a, b])
+//!
+//! assert 4. in s and 8. not in s
+//! assert s.intersection(IntervalSet([Interval(0., 24.)])).measure() == 12.
+//! ```
+
+use pyo3::prelude::*;
+
+use super::{Closed, Interval, IntervalSet, Open, Unbound, EMPTY};
+
+/// Python wrapper of `Interval`
+///
+#[pyclass(name = "Interval", frozen, eq)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PyInterval(pub Interval);
+
+/// Python wrapper of `IntervalSet`
+///
+#[pyclass(name = "IntervalSet", frozen, eq)]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PyIntervalSet(pub IntervalSet);
+
+#[pymethods]
+impl PyInterval {
+    #[new]
+    #[pyo3(signature = (lower=None, upper=None, left_closed=true, right_closed=true))]
+    fn new(lower: Option<f64>, upper: Option<f64>, left_closed: bool, right_closed: bool) -> Self {
+        let bound = |k: Option<f64>, closed: bool| match k {
+            None => Unbound,
+            Some(k) if closed => Closed(k),
+            Some(k) => Open(k),
+        };
+        PyInterval(Interval::new(
+            bound(lower, left_closed),
+            bound(upper, right_closed),
+        ))
+    }
+
+    #[staticmethod]
+    fn empty() -> Self {
+        PyInterval(EMPTY)
+    }
+
+    #[staticmethod]
+    fn singleton(k: f64) -> Self {
+        PyInterval(Interval::singleton(k))
+    }
+
+    fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    fn contains(&self, x: f64) -> bool {
+        self.0.contains(x)
+    }
+
+    fn __contains__(&self, x: f64) -> bool {
+        self.0.contains(x)
+    }
+
+    fn intersection(&self, other: &PyInterval) -> PyInterval {
+        PyInterval(self.0.intersection(other.0))
+    }
+
+    /// Union of intervals, as a set since it may not be an interval
+    ///
+    fn union(&self, other: &PyInterval) -> PyIntervalSet {
+        PyIntervalSet(IntervalSet::from(&[self.0, other.0]))
+    }
+
+    fn measure(&self) -> f64 {
+        self.0.width()
+    }
+
+    fn __str__(&self) -> String {
+        self.0.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Interval({})", self.0)
+    }
+}
+
+#[pymethods]
+impl PyIntervalSet {
+    #[new]
+    #[pyo3(signature = (intervals=Vec::new()))]
+    fn new(intervals: Vec<PyInterval>) -> Self {
+        let intervals: Vec<_> = intervals.into_iter().map(|i| i.0).collect();
+        PyIntervalSet(IntervalSet::from_unsorted(intervals))
+    }
+
+    fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    fn contains(&self, x: f64) -> bool {
+        self.0.contains(x)
+    }
+
+    fn __contains__(&self, x: f64) -> bool {
+        self.0.contains(x)
+    }
+
+    fn union(&self, other: &PyIntervalSet) -> PyIntervalSet {
+        PyIntervalSet(self.0.union_intervals(&other.0))
+    }
+
+    fn intersection(&self, other: &PyIntervalSet) -> PyIntervalSet {
+        PyIntervalSet(self.0.intersection(&other.0))
+    }
+
+    fn measure(&self) -> f64 {
+        self.0.measure()
+    }
+
+    /// Disjoint intervals of set, in increasing order
+    ///
+    fn intervals(&self) -> Vec<PyInterval> {
+        self.0.as_slice().iter().map(|i| PyInterval(*i)).collect()
+    }
+
+    fn __len__(&self) -> usize {
+        self.0.as_slice().len()
+    }
+
+    fn __str__(&self) -> String {
+        self.0.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("IntervalSet({})", self.0)
+    }
+}
+
+/// Python module `interval`
+///
+#[pymodule]
+#[pyo3(name = "interval")]
+pub fn init(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyInterval>()?;
+    m.add_class::<PyIntervalSet>()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_new_1() {
+        let a = PyInterval::new(Some(0.), Some(8.), true, false);
+        assert_eq!(a.0, Interval::new(Closed(0.), Open(8.)));
+        assert_eq!(
+            PyInterval::new(None, Some(1.), true, true).0,
+            Interval::new(Unbound, Closed(1.))
+        );
+        assert_eq!(a.__repr__(), "Interval([ 0.00, 8.00))");
+    }
+
+    #[test]
+    fn test_set_1() {
+        let a = PyInterval::new(Some(20.), None, true, true);
+        let b = PyInterval::new(Some(0.), Some(8.), true, false);
+        let s = PyIntervalSet::new(vec![a, b]);
+        assert_eq!(s.__len__(), 2);
+        assert!(s.__contains__(4.) && !s.__contains__(8.));
+        let day = PyIntervalSet::new(vec![PyInterval::new(Some(0.), Some(24.), true, true)]);
+        assert_eq!(s.intersection(&day).measure(), 12.);
+        assert_eq!(b.union(&a), s);
+    }
+}
@@ -0,0 +1,141 @@
+//! Free/busy computation over working hours
+//!
+//! Time is a plain `f64` in the unit of your choice (hours, seconds, timestamps...). Working
+//! hours of consecutive days are obtained by shifting a daily window by a period.
+//!
+//! ```
+//! use interval::{Interval, IntervalSet, Closed, Open};
+//! use interval::schedule;
+//!
+//! let working = schedule::working_days(Interval::new(Closed(9.), Open(17.)), 24., 2);
+//! let busy = IntervalSet::from(&[
+//!     Interval::new(Closed(10.), Open(12.)),
+//!     Interval::new(Closed(24. + 9.), Open(24. + 17.)),
+//! ]);
+//!
+//! let free = schedule::free(&working, &busy);
+//! assert_eq!(free, IntervalSet::from(&[
+//!     Interval::new(Closed(9.), Open(10.)),
+//!     Interval::new(Closed(12.), Open(17.)),
+//! ]));
+//! ```
+
+use super::{Interval, IntervalSet};
+
+/// Working hours of `days` consecutive days, day `k` being `hours` shifted by `k * period`
+///
+/// Days whose hours touch each other (e.g. round-the-clock `hours`) merge into a single
+/// segment.
+///
+pub fn working_days(hours: Interval, period: f64, days: usize) -> IntervalSet {
+    let windows: Vec<_> = (0..days)
+        .map(|k| hours.translate(k as f64 * period))
+        .collect();
+    IntervalSet::from_unsorted(windows)
+}
+
+/// Free slots of `working`, that is the part of it not covered by `busy`
+///
+pub fn free(working: &IntervalSet, busy: &IntervalSet) -> IntervalSet {
+    working & busy.complement()
+}
+
+/// Free slots of each of `days` consecutive days, kept apart day by day
+///
+/// # Example
+///
+/// ```
+/// use interval::{Interval, IntervalSet, Closed, Open};
+/// use interval::schedule;
+///
+/// let hours = Interval::new(Closed(0.), Open(24.));
+/// let busy = IntervalSet::from(&[Interval::new(Closed(8.), Open(20.))]);
+///
+/// let days = schedule::free_by_day(hours, 24., 2, &busy);
+/// assert_eq!(days, vec![
+///     IntervalSet::from(&[
+///         Interval::new(Closed(0.), Open(8.)),
+///         Interval::new(Closed(20.), Open(24.)),
+///     ]),
+///     IntervalSet::from(&[Interval::new(Closed(24.), Open(48.))]),
+/// ]);
+/// ```
+///
+pub fn free_by_day(
+    hours: Interval,
+    period: f64,
+    days: usize,
+    busy: &IntervalSet,
+) -> Vec<IntervalSet> {
+    (0..days)
+        .map(|k| {
+            let working = IntervalSet::from(&[hours.translate(k as f64 * period)]);
+            free(&working, busy)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Closed, Open};
+
+    #[test]
+    fn test_working_days_1() {
+        let a = working_days(Interval::new(Closed(9.), Open(17.)), 24., 3);
+        let b = IntervalSet::from(&[
+            Interval::new(Closed(9.), Open(17.)),
+            Interval::new(Closed(33.), Open(41.)),
+            Interval::new(Closed(57.), Open(65.)),
+        ]);
+        assert_eq!(a, b);
+        assert!(working_days(Interval::new(Closed(9.), Open(17.)), 24., 0).is_empty());
+    }
+
+    #[test]
+    fn test_working_days_2() {
+        let a = working_days(Interval::new(Closed(0.), Open(24.)), 24., 2);
+        assert_eq!(
+            a,
+            IntervalSet::from(&[Interval::new(Closed(0.), Open(48.))])
+        );
+    }
+
+    #[test]
+    fn test_free_1() {
+        let working = working_days(Interval::new(Closed(0.), Open(24.)), 24., 2);
+        let busy = IntervalSet::from(&[
+            Interval::new(Closed(8.), Open(20.)),
+            Interval::new(Closed(32.), Open(44.)),
+        ]);
+        let a = free(&working, &busy);
+        let b = IntervalSet::from(&[
+            Interval::new(Closed(0.), Open(8.)),
+            Interval::new(Closed(20.), Open(32.)),
+            Interval::new(Closed(44.), Open(48.)),
+        ]);
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn test_free_by_day_1() {
+        let busy = IntervalSet::from(&[
+            Interval::new(Closed(8.), Open(20.)),
+            Interval::new(Closed(32.), Open(44.)),
+        ]);
+        let days = free_by_day(Interval::new(Closed(0.), Open(24.)), 24., 2, &busy);
+        assert_eq!(
+            days,
+            vec![
+                IntervalSet::from(&[
+                    Interval::new(Closed(0.), Open(8.)),
+                    Interval::new(Closed(20.), Open(24.)),
+                ]),
+                IntervalSet::from(&[
+                    Interval::new(Closed(24.), Open(32.)),
+                    Interval::new(Closed(44.), Open(48.)),
+                ]),
+            ]
+        );
+    }
+}
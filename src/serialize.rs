@@ -0,0 +1,126 @@
+//! Serialization with `serde` (feature `serde`)
+//!
+//! An interval is serialized as the pair of its bounds, and a set as the sequence of its
+//! segments: in JSON, `[0, 8)` is `[{"Closed":0.0},{"Open":8.0}]` and `(-∞, 1]` is
+//! `["Unbound",{"Closed":1.0}]`. Deserialization validates untrusted input: intervals with
+//! a NaN endpoint or swapped endpoints are rejected with a descriptive error, and sets are
+//! restored to their canonical form (segments sorted, merged and non-empty, as with
+//! `IntervalSet::from`).
+
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use super::{Bound, Interval, IntervalError, IntervalSet, EMPTY};
+
+impl Serialize for Interval {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.into_bounds().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Interval {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (b1, b2) = <(Bound, Bound)>::deserialize(deserializer)?;
+        match Interval::try_new(b1, b2) {
+            Ok(a) => Ok(a),
+            Err(IntervalError::Empty) => Ok(EMPTY),
+            Err(e) => Err(D::Error::custom(format!("invalid interval: {e}"))),
+        }
+    }
+}
+
+impl Serialize for IntervalSet {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.as_slice().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for IntervalSet {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let intervals = Vec::<Interval>::deserialize(deserializer)?;
+        Ok(IntervalSet::from_unsorted(intervals))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Closed, Open, Unbound};
+    use serde_test::{assert_de_tokens, assert_de_tokens_error, assert_tokens, Token};
+
+    fn bound(b: Bound) -> Vec<Token> {
+        let variant = |variant| Token::NewtypeVariant {
+            name: "Bound",
+            variant,
+        };
+        match b {
+            Closed(k) => vec![variant("Closed"), Token::F64(k)],
+            Open(k) => vec![variant("Open"), Token::F64(k)],
+            Unbound => vec![Token::UnitVariant {
+                name: "Bound",
+                variant: "Unbound",
+            }],
+        }
+    }
+
+    fn interval(b1: Bound, b2: Bound) -> Vec<Token> {
+        let mut tokens = vec![Token::Tuple { len: 2 }];
+        tokens.extend(bound(b1));
+        tokens.extend(bound(b2));
+        tokens.push(Token::TupleEnd);
+        tokens
+    }
+
+    #[test]
+    fn test_interval_1() {
+        assert_tokens(
+            &Interval::new(Closed(0.), Open(8.)),
+            &interval(Closed(0.), Open(8.)),
+        );
+        assert_tokens(
+            &Interval::new(Unbound, Closed(1.)),
+            &interval(Unbound, Closed(1.)),
+        );
+        assert_tokens(&EMPTY, &interval(Open(0.), Open(0.)));
+        assert_de_tokens(&EMPTY, &interval(Open(1.), Closed(1.)));
+    }
+
+    #[test]
+    fn test_interval_2() {
+        assert_de_tokens_error::<Interval>(
+            &interval(Closed(2.), Open(1.)),
+            "invalid interval: lower endpoint above upper endpoint",
+        );
+        assert_de_tokens_error::<Interval>(
+            &interval(Closed(f64::NAN), Unbound),
+            "invalid interval: NaN endpoint",
+        );
+    }
+
+    #[test]
+    fn test_interval_set_1() {
+        let a = IntervalSet::from(&[
+            Interval::new(Unbound, Open(0.)),
+            Interval::new(Closed(1.), Closed(2.)),
+        ]);
+        let mut tokens = vec![Token::Seq { len: Some(2) }];
+        tokens.extend(interval(Unbound, Open(0.)));
+        tokens.extend(interval(Closed(1.), Closed(2.)));
+        tokens.push(Token::SeqEnd);
+        assert_tokens(&a, &tokens);
+    }
+
+    #[test]
+    fn test_interval_set_2() {
+        // Unsorted, overlapping and empty segments
+        let mut tokens = vec![Token::Seq { len: Some(3) }];
+        tokens.extend(interval(Closed(1.), Closed(3.)));
+        tokens.extend(interval(Open(5.), Open(5.)));
+        tokens.extend(interval(Unbound, Closed(2.)));
+        tokens.push(Token::SeqEnd);
+        assert_de_tokens(
+            &IntervalSet::from(&[Interval::new(Unbound, Closed(3.))]),
+            &tokens,
+        );
+    }
+}
@@ -0,0 +1,219 @@
+use super::IntervalSet;
+
+/// Lazy boolean expression over interval sets
+///
+/// Building an expression only borrows the sets. `eval` computes the result in a single
+/// sweep over the edges of all sets, without materializing intermediate sets.
+///
+/// # Example
+///
+/// ```
+/// use interval::{Interval, IntervalSet, SetExpr, Closed, Open};
+///
+/// let office = IntervalSet::from(&[Interval::new(Closed(8.), Open(18.))]);
+/// let alice = IntervalSet::from(&[Interval::new(Closed(9.), Open(12.))]);
+/// let bob = IntervalSet::from(&[Interval::new(Closed(11.), Open(14.))]);
+/// let lunch = IntervalSet::from(&[Interval::new(Closed(12.), Open(13.))]);
+///
+/// let busy = SetExpr::set(&alice).union(SetExpr::set(&bob));
+/// let free = SetExpr::set(&office)
+///     .difference(busy)
+///     .difference(SetExpr::set(&lunch));
+///
+/// assert_eq!(free.eval(), IntervalSet::from(&[
+///     Interval::new(Closed(8.), Open(9.)),
+///     Interval::new(Closed(14.), Open(18.)),
+/// ]));
+/// ```
+///
+#[derive(Debug, Clone)]
+pub enum SetExpr<'a> {
+    Set(&'a IntervalSet),
+    Union(Vec<SetExpr<'a>>),
+    Intersection(Vec<SetExpr<'a>>),
+    Difference(Box<SetExpr<'a>>, Box<SetExpr<'a>>),
+    Complement(Box<SetExpr<'a>>),
+}
+
+impl<'a> SetExpr<'a> {
+    pub fn set(set: &'a IntervalSet) -> Self {
+        SetExpr::Set(set)
+    }
+
+    /// Union of all `exprs`, the empty set if there are none
+    ///
+    pub fn union_of(exprs: impl IntoIterator<Item = SetExpr<'a>>) -> Self {
+        SetExpr::Union(exprs.into_iter().collect())
+    }
+
+    /// Intersection of all `exprs`, the real line if there are none
+    ///
+    pub fn intersection_of(exprs: impl IntoIterator<Item = SetExpr<'a>>) -> Self {
+        SetExpr::Intersection(exprs.into_iter().collect())
+    }
+
+    pub fn union(self, other: SetExpr<'a>) -> Self {
+        match self {
+            SetExpr::Union(mut exprs) => {
+                exprs.push(other);
+                SetExpr::Union(exprs)
+            }
+            expr => SetExpr::Union(vec![expr, other]),
+        }
+    }
+
+    pub fn intersection(self, other: SetExpr<'a>) -> Self {
+        match self {
+            SetExpr::Intersection(mut exprs) => {
+                exprs.push(other);
+                SetExpr::Intersection(exprs)
+            }
+            expr => SetExpr::Intersection(vec![expr, other]),
+        }
+    }
+
+    pub fn difference(self, other: SetExpr<'a>) -> Self {
+        SetExpr::Difference(Box::new(self), Box::new(other))
+    }
+
+    pub fn complement(self) -> Self {
+        SetExpr::Complement(Box::new(self))
+    }
+
+    /// Compute the set described by expression
+    ///
+    pub fn eval(&self) -> IntervalSet {
+        let mut sets = Vec::new();
+        self.leaves(&mut sets);
+        IntervalSet::sweep(&sets, |membership| {
+            let mut next = 0;
+            self.holds(membership, &mut next)
+        })
+    }
+
+    /// Collect leaf sets in depth-first order
+    ///
+    fn leaves(&self, sets: &mut Vec<&'a IntervalSet>) {
+        match self {
+            SetExpr::Set(set) => sets.push(set),
+            SetExpr::Union(exprs) | SetExpr::Intersection(exprs) => {
+                for expr in exprs {
+                    expr.leaves(sets);
+                }
+            }
+            SetExpr::Difference(lhs, rhs) => {
+                lhs.leaves(sets);
+                rhs.leaves(sets);
+            }
+            SetExpr::Complement(expr) => expr.leaves(sets),
+        }
+    }
+
+    /// Evaluate expression given membership to each leaf, `next` being the index of the
+    /// first leaf of expression
+    ///
+    fn holds(&self, membership: &[bool], next: &mut usize) -> bool {
+        match self {
+            SetExpr::Set(_) => {
+                *next += 1;
+                membership[*next - 1]
+            }
+            // every child is visited, so that `next` skips over all their leaves
+            SetExpr::Union(exprs) => {
+                let mut any = false;
+                for expr in exprs {
+                    any |= expr.holds(membership, next);
+                }
+                any
+            }
+            SetExpr::Intersection(exprs) => {
+                let mut all = true;
+                for expr in exprs {
+                    all &= expr.holds(membership, next);
+                }
+                all
+            }
+            SetExpr::Difference(lhs, rhs) => {
+                let lhs = lhs.holds(membership, next);
+                let rhs = rhs.holds(membership, next);
+                lhs && !rhs
+            }
+            SetExpr::Complement(expr) => !expr.holds(membership, next),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Closed, Interval, Open, Unbound, INFINITY};
+
+    #[test]
+    fn test_eval_1() {
+        let (a, b, c) = (
+            IntervalSet::from(&[Interval::new(Closed(0.), Open(10.))]),
+            IntervalSet::from(&[Interval::new(Closed(5.), Open(15.))]),
+            IntervalSet::from(&[Interval::new(Closed(8.), Open(20.))]),
+        );
+        let expr = SetExpr::set(&a)
+            .intersection(SetExpr::set(&b))
+            .intersection(SetExpr::set(&c));
+        assert_eq!(
+            expr.eval(),
+            IntervalSet::from(&[Interval::new(Closed(8.), Open(10.))])
+        );
+        assert_eq!(expr.eval(), &(&a & &b) & &c);
+    }
+
+    #[test]
+    fn test_eval_2() {
+        let (a, b) = (
+            IntervalSet::from(&[Interval::new(Closed(0.), Open(10.))]),
+            IntervalSet::from(&[Interval::new(Closed(5.), Open(15.))]),
+        );
+        let expr = SetExpr::set(&a).union(SetExpr::set(&b)).complement();
+        assert_eq!(
+            expr.eval(),
+            IntervalSet::from(&[
+                Interval::new(Unbound, Open(0.)),
+                Interval::new(Closed(15.), Unbound),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_eval_3() {
+        assert!(SetExpr::union_of([]).eval().is_empty());
+        assert!(SetExpr::intersection_of([]).eval().is_infinity());
+        let a = IntervalSet::new() | INFINITY;
+        assert!(SetExpr::set(&a)
+            .difference(SetExpr::set(&a))
+            .eval()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_eval_4() {
+        let sets: Vec<_> = (0..10)
+            .map(|k| IntervalSet::from(&[Interval::new(Closed(k as f64), Open(k as f64 + 1.5))]))
+            .collect();
+        let expr = SetExpr::union_of(sets.iter().map(SetExpr::set));
+        assert_eq!(
+            expr.eval(),
+            IntervalSet::from(&[Interval::new(Closed(0.), Open(10.5))])
+        );
+        let expr = SetExpr::union_of(sets.iter().step_by(2).map(SetExpr::set)).difference(
+            SetExpr::union_of(sets.iter().skip(1).step_by(2).map(SetExpr::set)),
+        );
+        assert_eq!(
+            expr.eval(),
+            IntervalSet::from(&[
+                Interval::new(Closed(0.), Open(1.)),
+                Interval::new(Closed(2.5), Open(3.)),
+                Interval::new(Closed(4.5), Open(5.)),
+                Interval::new(Closed(6.5), Open(7.)),
+                Interval::new(Closed(8.5), Open(9.)),
+            ])
+        );
+    }
+}
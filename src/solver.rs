@@ -0,0 +1,367 @@
+//! Enclosure of solutions of interval linear systems, and of roots of nonlinear systems
+//!
+//! Matrices are arrays of rows. Interval arithmetic rounds outward, so that enclosures are
+//! rigorous, as are the existence and absence proofs of `krawczyk`.
+
+use super::arith::{add, div, mul, sub};
+use super::{Expr, Interval, EMPTY};
+
+const MAX_ROUNDS: usize = 100;
+
+/// What `krawczyk` proved about the roots of a system in a box
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RootStatus {
+    /// There is no root in the box
+    NoRoot,
+    /// There is exactly one root in the box
+    UniqueRoot,
+    /// Neither could be proven, the box being too wide or the root singular
+    Undecided,
+}
+
+/// Contract `domains` around every solution of `a x = b`, for `a` and `b` taken anywhere
+/// in their interval entries, by preconditioned Gauss-Seidel iteration
+///
+/// The system is first multiplied by the inverse of the midpoint matrix of `a` (unless
+/// `a` has unbounded entries or a singular midpoint), which makes the iteration converge
+/// for well-conditioned systems. Iteration stops when a whole round leaves domains
+/// unchanged, or after a fixed number of rounds. Returns `false` if no solution lies in
+/// `domains`, every domain being then set to `EMPTY`.
+///
+/// # Example
+///
+/// ```
+/// use interval::{gauss_seidel, Interval, Closed};
+///
+/// let k = Interval::singleton;
+/// let a = [[k(4.), k(1.)], [k(1.), k(3.)]];
+/// let b = [k(1.), k(2.)];
+/// let mut domains = [Interval::new(Closed(-10.), Closed(10.)); 2];
+///
+/// assert!(gauss_seidel(&a, &b, &mut domains));
+/// assert!(domains[0].approx_eq(k(1. / 11.), 1e-12));
+/// assert!(domains[1].approx_eq(k(7. / 11.), 1e-12));
+/// ```
+///
+pub fn gauss_seidel<const N: usize>(
+    a: &[[Interval; N]; N],
+    b: &[Interval; N],
+    domains: &mut [Interval; N],
+) -> bool {
+    let (a, b) = precondition(a, b);
+    for _ in 0..MAX_ROUNDS {
+        let previous = *domains;
+        for i in 0..N {
+            let mut rhs = b[i];
+            for j in (0..N).filter(|&j| j != i) {
+                rhs = sub(rhs, mul(a[i][j], domains[j]));
+            }
+            let x = domains[i].intersection(div(rhs, a[i][i]));
+            if x.is_empty() {
+                domains.fill(EMPTY);
+                return false;
+            }
+            domains[i] = x;
+        }
+        if previous == *domains {
+            break;
+        }
+    }
+    true
+}
+
+/// Contract `domains` around the roots of `f` with the Krawczyk operator, proving
+/// existence and uniqueness of a root when possible
+///
+/// `jacobian[i][j]` is the derivative of `f[i]` with respect to variable `j`. Each round
+/// maps the box `X` of domains with midpoint `m` to
+/// `K(X) = m - Y f(m) + (I - Y J(X)) (X - m)`, where `Y` is the inverse of the midpoint
+/// of `J(X)`: every root in `X` lies in `K(X)`, and there is a unique root in `X` if
+/// `K(X)` lies in the interior of `X`. Domains are replaced by `K(X) ∩ X` until a fixpoint
+/// is reached, or after a fixed number of rounds. Domains are left unchanged if they
+/// are unbounded or if the midpoint of the jacobian is singular.
+///
+/// # Panics
+///
+/// Panics if a variable index of `f` or `jacobian` is not below `N`.
+///
+/// # Example
+///
+/// ```
+/// use interval::{krawczyk, Expr, Interval, RootStatus, Closed};
+///
+/// // x² + y² = 1, x = y
+/// let (x, y) = (Expr::var(0), Expr::var(1));
+/// let f = [x.clone().sqr() + y.clone().sqr() - 1., &x - &y];
+/// let jacobian = [
+///     [&x * 2., &y * 2.],
+///     [Expr::constant(1.), Expr::constant(-1.)],
+/// ];
+/// let mut domains = [Interval::new(Closed(0.5), Closed(1.)); 2];
+///
+/// assert_eq!(krawczyk(&f, &jacobian, &mut domains), RootStatus::UniqueRoot);
+/// assert!(domains[0].approx_eq(Interval::singleton(0.5f64.sqrt()), 1e-12));
+/// ```
+///
+pub fn krawczyk<const N: usize>(
+    f: &[Expr; N],
+    jacobian: &[[Expr; N]; N],
+    domains: &mut [Interval; N],
+) -> RootStatus {
+    let mut status = RootStatus::Undecided;
+    for _ in 0..MAX_ROUNDS {
+        let previous = *domains;
+        let Some(m) = domains
+            .iter()
+            .map(|&x| midpoint(x))
+            .collect::<Option<Vec<_>>>()
+        else {
+            break;
+        };
+        let point: [Interval; N] = std::array::from_fn(|i| Interval::singleton(m[i]));
+        let j: [[Interval; N]; N] =
+            std::array::from_fn(|r| std::array::from_fn(|c| jacobian[r][c].eval(domains)));
+        let Some(y) = midpoint_matrix(&j).and_then(inverse) else {
+            break;
+        };
+        let fm: [Interval; N] = std::array::from_fn(|i| f[i].eval(&point));
+        let offsets: [Interval; N] = std::array::from_fn(|i| sub(domains[i], point[i]));
+        let k: [Interval; N] = std::array::from_fn(|i| {
+            let newton = sub(point[i], dot(&y[i], |r| fm[r]));
+            (0..N).fold(newton, |acc, c| {
+                let identity = Interval::singleton(if i == c { 1. } else { 0. });
+                add(acc, mul(sub(identity, dot(&y[i], |r| j[r][c])), offsets[c]))
+            })
+        });
+
+        if k.iter()
+            .zip(domains.iter())
+            .all(|(k, x)| strictly_inside(*k, *x))
+        {
+            status = RootStatus::UniqueRoot;
+        }
+        for (x, k) in domains.iter_mut().zip(k) {
+            *x = x.intersection(k);
+        }
+        if domains.iter().any(|x| x.is_empty()) {
+            domains.fill(EMPTY);
+            return RootStatus::NoRoot;
+        }
+        if previous == *domains {
+            break;
+        }
+    }
+    status
+}
+
+/// Check if the closure of `a` lies in the interior of `b`
+///
+fn strictly_inside(a: Interval, b: Interval) -> bool {
+    match (a.endpoints(), b.endpoints()) {
+        (Some((a1, a2)), Some((b1, b2))) => b1 < a1 && a2 < b2,
+        _ => false,
+    }
+}
+
+/// Sum of `row[k] * column(k)`
+///
+fn dot<const N: usize>(row: &[f64; N], column: impl Fn(usize) -> Interval) -> Interval {
+    row.iter()
+        .enumerate()
+        .fold(Interval::singleton(0.), |acc, (k, &c)| {
+            add(acc, mul(Interval::singleton(c), column(k)))
+        })
+}
+
+/// System multiplied by the inverse of the midpoint matrix of `a`, or unchanged if there
+/// is no such inverse
+///
+fn precondition<const N: usize>(
+    a: &[[Interval; N]; N],
+    b: &[Interval; N],
+) -> ([[Interval; N]; N], [Interval; N]) {
+    let Some(c) = midpoint_matrix(a).and_then(inverse) else {
+        return (*a, *b);
+    };
+    let mut res = ([[EMPTY; N]; N], [EMPTY; N]);
+    for (i, row) in c.iter().enumerate() {
+        for (j, entry) in res.0[i].iter_mut().enumerate() {
+            *entry = dot(row, |k| a[k][j]);
+        }
+        res.1[i] = dot(row, |k| b[k]);
+    }
+    res
+}
+
+/// Matrix of entry midpoints, `None` if an entry is empty or unbounded
+///
+fn midpoint_matrix<const N: usize>(a: &[[Interval; N]; N]) -> Option<[[f64; N]; N]> {
+    let mut res = [[0.; N]; N];
+    for (res, a) in res.iter_mut().flatten().zip(a.iter().flatten()) {
+        *res = midpoint(*a)?;
+    }
+    Some(res)
+}
+
+/// Midpoint of bounded interval
+///
+fn midpoint(a: Interval) -> Option<f64> {
+    match a.endpoints() {
+        Some((lower, upper)) if lower.is_finite() && upper.is_finite() => {
+            Some(lower / 2. + upper / 2.)
+        }
+        _ => None,
+    }
+}
+
+/// Inverse of matrix by Gauss-Jordan elimination with partial pivoting, `None` if matrix
+/// is singular
+///
+fn inverse<const N: usize>(mut m: [[f64; N]; N]) -> Option<[[f64; N]; N]> {
+    let mut res = [[0.; N]; N];
+    for (i, row) in res.iter_mut().enumerate() {
+        row[i] = 1.;
+    }
+    for col in 0..N {
+        let pivot = (col..N).max_by(|&i, &j| m[i][col].abs().total_cmp(&m[j][col].abs()))?;
+        if m[pivot][col] == 0. || m[pivot][col].is_nan() {
+            return None;
+        }
+        m.swap(col, pivot);
+        res.swap(col, pivot);
+        let p = m[col][col];
+        for k in 0..N {
+            m[col][k] /= p;
+            res[col][k] /= p;
+        }
+        for row in (0..N).filter(|&row| row != col) {
+            let factor = m[row][col];
+            for k in 0..N {
+                m[row][k] -= factor * m[col][k];
+                res[row][k] -= factor * res[col][k];
+            }
+        }
+    }
+    res.iter().flatten().all(|x| x.is_finite()).then_some(res)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Closed, Unbound};
+
+    #[test]
+    fn test_inverse_1() {
+        assert_eq!(inverse([[2., 0.], [0., 4.]]), Some([[0.5, 0.], [0., 0.25]]));
+        assert_eq!(inverse([[0., 1.], [1., 0.]]), Some([[0., 1.], [1., 0.]]));
+        assert_eq!(inverse([[1., 2.], [2., 4.]]), None);
+        assert_eq!(inverse([[f64::NAN, 0.], [0., 1.]]), None);
+    }
+
+    #[test]
+    fn test_gauss_seidel_1() {
+        let a = [
+            [
+                Interval::new(Closed(3.), Closed(4.)),
+                Interval::new(Closed(-1.), Closed(1.)),
+            ],
+            [
+                Interval::new(Closed(-1.), Closed(1.)),
+                Interval::new(Closed(3.), Closed(4.)),
+            ],
+        ];
+        let b = [
+            Interval::new(Closed(1.), Closed(2.)),
+            Interval::new(Closed(1.), Closed(2.)),
+        ];
+        let mut domains = [Interval::new(Closed(-10.), Closed(10.)); 2];
+        assert!(gauss_seidel(&a, &b, &mut domains));
+        // Solutions of point systems taken in a and b
+        for (a11, a12, a21, a22, b1, b2) in [
+            (3., 1., -1., 4., 2., 1.),
+            (4., -1., 1., 3., 1., 2.),
+            (3.5, 0., 0., 3.5, 1.5, 1.5),
+        ] {
+            let det = a11 * a22 - a12 * a21;
+            let x = ((b1 * a22 - a12 * b2) / det, (a11 * b2 - a21 * b1) / det);
+            assert!(domains[0].contains(x.0) && domains[1].contains(x.1));
+        }
+        assert!(domains[0].contains_interval(Interval::new(Closed(0.25), Closed(0.6))));
+        assert!(Interval::new(Closed(-0.1), Closed(1.1)).contains_interval(domains[0]));
+    }
+
+    #[test]
+    fn test_gauss_seidel_2() {
+        let k = Interval::singleton;
+        let a = [[k(1.), k(1.)], [k(1.), k(-1.)]];
+        let b = [k(2.), k(0.)];
+        let mut domains = [
+            Interval::new(Closed(2.), Closed(3.)),
+            Interval::new(Closed(-10.), Closed(10.)),
+        ];
+        assert!(!gauss_seidel(&a, &b, &mut domains));
+        assert_eq!(domains, [EMPTY; 2]);
+        let mut domains = [
+            Interval::new(Closed(0.), Unbound),
+            Interval::new(Closed(-10.), Closed(10.)),
+        ];
+        assert!(gauss_seidel(&a, &b, &mut domains));
+        assert!(domains[0].approx_eq(k(1.), 1e-12) && domains[1].approx_eq(k(1.), 1e-12));
+    }
+
+    #[test]
+    fn test_gauss_seidel_3() {
+        let k = Interval::singleton;
+        let a = [[k(1.), Interval::new(Unbound, Closed(0.))], [k(0.), k(1.)]];
+        let b = [k(1.), k(0.)];
+        let mut domains = [Interval::new(Closed(-10.), Closed(10.)); 2];
+        assert!(gauss_seidel(&a, &b, &mut domains));
+        assert_eq!(domains, [k(1.), k(0.)]);
+    }
+
+    #[test]
+    fn test_krawczyk_1() {
+        let x = Expr::var(0);
+        let f = [x.clone().sqr() - 2.];
+        let jacobian = [[x * 2.]];
+        let mut domains = [Interval::new(Closed(1.), Closed(2.))];
+        assert_eq!(
+            krawczyk(&f, &jacobian, &mut domains),
+            RootStatus::UniqueRoot
+        );
+        assert!(domains[0].approx_eq(Interval::singleton(2f64.sqrt()), 1e-12));
+        let mut domains = [Interval::new(Closed(3.), Closed(4.))];
+        assert_eq!(krawczyk(&f, &jacobian, &mut domains), RootStatus::NoRoot);
+        assert_eq!(domains, [EMPTY]);
+    }
+
+    #[test]
+    fn test_krawczyk_3() {
+        // Contracting down to a few ulps keeps the root enclosed
+        let x = Expr::var(0);
+        let f = [x.clone().sqr() - 2.];
+        let jacobian = [[x * 2.]];
+        let mut domains = [Interval::new(Closed(1.4), Closed(1.5))];
+        assert_eq!(
+            krawczyk(&f, &jacobian, &mut domains),
+            RootStatus::UniqueRoot
+        );
+        let (lower, upper) = domains[0].endpoints().unwrap();
+        assert!(lower.mul_add(lower, -2.) < 0. && upper.mul_add(upper, -2.) > 0.);
+    }
+
+    #[test]
+    fn test_krawczyk_2() {
+        let x = Expr::var(0);
+        let f = [x.clone().sqr() - 2.];
+        let jacobian = [[x * 2.]];
+        // Both roots, and a singular midpoint jacobian
+        let mut domains = [Interval::new(Closed(-2.), Closed(2.))];
+        assert_eq!(krawczyk(&f, &jacobian, &mut domains), RootStatus::Undecided);
+        assert_eq!(domains, [Interval::new(Closed(-2.), Closed(2.))]);
+        let mut domains = [Interval::new(Closed(1.), Unbound)];
+        assert_eq!(krawczyk(&f, &jacobian, &mut domains), RootStatus::Undecided);
+        assert_eq!(domains, [Interval::new(Closed(1.), Unbound)]);
+    }
+}
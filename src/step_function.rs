@@ -0,0 +1,227 @@
+use super::interval::Edge;
+use super::{Interval, IntervalSet};
+use auto_ops::impl_op_ex;
+
+/// Real function taking a constant value on each of finitely many intervals, and zero
+/// elsewhere
+///
+/// Steps are kept sorted, disjoint and non-zero, adjacent steps of equal value being
+/// merged.
+///
+/// # Example
+///
+/// ```
+/// use interval::{Interval, StepFunction, Closed, Open};
+///
+/// let f = StepFunction::new(&[
+///     (Interval::new(Closed(0.), Open(10.)), 1.),
+///     (Interval::new(Closed(5.), Open(20.)), 2.),
+/// ]);
+///
+/// assert_eq!(f.eval(2.), 1.);
+/// assert_eq!(f.eval(5.), 3.);
+/// assert_eq!(f.eval(20.), 0.);
+/// assert_eq!(f.integrate(Interval::new(Closed(0.), Closed(10.))), 20.);
+/// ```
+///
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct StepFunction {
+    steps: Vec<(Interval, f64)>,
+}
+
+impl StepFunction {
+    /// Build step function from interval/value pairs
+    ///
+    /// Values of overlapping intervals add up.
+    ///
+    pub fn new(steps: &[(Interval, f64)]) -> Self {
+        let mut events = Vec::new();
+        for (interval, value) in steps {
+            if let Some((start, end)) = interval.edges() {
+                if *value != 0. {
+                    events.push((start, *value, 1));
+                    events.push((end, -*value, -1));
+                }
+            }
+        }
+        events.sort_by_key(|event| event.0);
+
+        let mut res: Vec<(Edge, Edge, f64)> = Vec::new();
+        let (mut value, mut active) = (0., 0);
+        let mut i = 0;
+        while i < events.len() {
+            let edge = events[i].0;
+            while i < events.len() && events[i].0 == edge {
+                value += events[i].1;
+                active += events[i].2;
+                i += 1;
+            }
+            if active == 0 {
+                // drop rounding residue once no step is active
+                value = 0.;
+            }
+            match (events.get(i), res.last_mut()) {
+                (None, _) => {}
+                (Some(_), _) if value == 0. => {}
+                (Some(_), Some((_, end, last))) if *end == edge && *last == value => {
+                    *end = events[i].0;
+                }
+                (Some((next, _, _)), _) => res.push((edge, *next, value)),
+            }
+        }
+
+        StepFunction {
+            steps: res
+                .into_iter()
+                .map(|(start, end, value)| (Interval::from_edges(start, end), value))
+                .collect(),
+        }
+    }
+
+    /// Indicator function of `set`
+    ///
+    pub fn indicator(set: &IntervalSet) -> Self {
+        let steps: Vec<_> = set.as_slice().iter().map(|a| (*a, 1.)).collect();
+        StepFunction::new(&steps)
+    }
+
+    pub fn steps(&self) -> &[(Interval, f64)] {
+        &self.steps
+    }
+
+    /// Value of function at `x`
+    ///
+    pub fn eval(&self, x: f64) -> f64 {
+        let i = self.steps.partition_point(|(step, _)| step.is_below(x));
+        match self.steps.get(i) {
+            Some((step, value)) if step.contains(x) => *value,
+            _ => 0.,
+        }
+    }
+
+    /// Set where function is non-zero
+    ///
+    pub fn support(&self) -> IntervalSet {
+        let intervals: Vec<_> = self.steps.iter().map(|(step, _)| *step).collect();
+        IntervalSet::from_unsorted(intervals)
+    }
+
+    /// Pointwise sum of step functions
+    ///
+    pub fn add(&self, other: &StepFunction) -> StepFunction {
+        let steps: Vec<_> = self.steps.iter().chain(&other.steps).copied().collect();
+        StepFunction::new(&steps)
+    }
+
+    /// Integral of function over `domain`
+    ///
+    /// Returns an infinite value if a non-zero step of `domain` is unbounded.
+    ///
+    pub fn integrate(&self, domain: Interval) -> f64 {
+        self.steps
+            .iter()
+            .map(|(step, value)| (step.intersection(domain), value))
+            .filter(|(step, _)| !step.is_empty())
+            .map(|(step, value)| value * step.width())
+            .sum()
+    }
+}
+
+impl_op_ex!(+ |lhs: &StepFunction, rhs: &StepFunction| -> StepFunction {
+    lhs.add(rhs)
+});
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Closed, Open, Unbound, EMPTY, INFINITY};
+
+    #[test]
+    fn test_new_1() {
+        let f = StepFunction::new(&[
+            (Interval::new(Closed(0.), Closed(10.)), 1.),
+            (Interval::new(Open(10.), Closed(20.)), 1.),
+            (EMPTY, 3.),
+            (Interval::new(Closed(30.), Closed(40.)), 0.),
+        ]);
+        assert_eq!(f.steps(), &[(Interval::new(Closed(0.), Closed(20.)), 1.)]);
+    }
+
+    #[test]
+    fn test_new_2() {
+        let f = StepFunction::new(&[
+            (Interval::new(Closed(0.), Closed(10.)), 1.),
+            (Interval::new(Closed(10.), Closed(20.)), 2.),
+        ]);
+        assert_eq!(
+            f.steps(),
+            &[
+                (Interval::new(Closed(0.), Open(10.)), 1.),
+                (Interval::singleton(10.), 3.),
+                (Interval::new(Open(10.), Closed(20.)), 2.),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_new_3() {
+        let f = StepFunction::new(&[
+            (Interval::new(Closed(0.), Closed(10.)), 1.),
+            (Interval::new(Closed(0.), Closed(5.)), -1.),
+        ]);
+        assert_eq!(f.steps(), &[(Interval::new(Open(5.), Closed(10.)), 1.)]);
+    }
+
+    #[test]
+    fn test_eval_1() {
+        let f = StepFunction::new(&[
+            (Interval::new(Unbound, Open(0.)), -1.),
+            (Interval::new(Open(0.), Unbound), 1.),
+        ]);
+        assert_eq!(f.eval(-5.), -1.);
+        assert_eq!(f.eval(0.), 0.);
+        assert_eq!(f.eval(5.), 1.);
+    }
+
+    #[test]
+    fn test_add_1() {
+        let f = StepFunction::new(&[(Interval::new(Closed(0.), Open(10.)), 1.)]);
+        let g = StepFunction::new(&[(Interval::new(Closed(5.), Open(15.)), -1.)]);
+        let h = f + g;
+        assert_eq!(
+            h.steps(),
+            &[
+                (Interval::new(Closed(0.), Open(5.)), 1.),
+                (Interval::new(Closed(10.), Open(15.)), -1.),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_integrate_1() {
+        let f = StepFunction::new(&[
+            (Interval::new(Closed(0.), Open(10.)), 1.),
+            (Interval::new(Closed(10.), Closed(20.)), 2.),
+        ]);
+        assert_eq!(f.integrate(INFINITY), 30.);
+        assert_eq!(f.integrate(Interval::new(Closed(5.), Closed(15.))), 15.);
+        assert_eq!(f.integrate(EMPTY), 0.);
+    }
+
+    #[test]
+    fn test_integrate_2() {
+        let f = StepFunction::new(&[(Interval::new(Closed(0.), Unbound), 1.)]);
+        assert_eq!(f.integrate(INFINITY), f64::INFINITY);
+    }
+
+    #[test]
+    fn test_support_1() {
+        let set = IntervalSet::from(&[
+            Interval::new(Closed(0.), Closed(1.)),
+            Interval::new(Closed(2.), Closed(3.)),
+        ]);
+        let f = StepFunction::indicator(&set);
+        assert!(f.support() == set);
+        assert_eq!(f.integrate(INFINITY), 2.);
+    }
+}
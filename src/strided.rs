@@ -0,0 +1,420 @@
+use super::{Closed, I64Interval, Interval, IntervalError};
+use auto_ops::impl_op_ex;
+use std::fmt::Display;
+
+/// Non-empty set of integers `lower, lower + stride, …, upper`, written `stride[lower,upper]`
+///
+/// Strided intervals refine integer ranges with a congruence, such as addresses of aligned
+/// fields in binary analysis. Singletons have a zero stride, other sets a positive one.
+///
+/// Arithmetic operators enclose every sum, difference or product of members of operands.
+/// Use `checked_add`, `checked_sub`, `checked_mul` and `checked_neg` when bounds may not
+/// fit in `i64`.
+///
+/// # Panics
+///
+/// Operators panic if bounds of the result don't fit in `i64`, in release builds too.
+///
+/// # Example
+///
+/// ```
+/// use interval::StridedInterval;
+///
+/// let offsets = StridedInterval::new(8, 0, 24).unwrap();
+/// let base = StridedInterval::singleton(0x1000);
+///
+/// let addresses = base + offsets;
+///
+/// assert_eq!(addresses, StridedInterval::new(8, 0x1000, 0x1018).unwrap());
+/// assert!(addresses.contains(0x1010));
+/// assert!(!addresses.contains(0x1004));
+/// ```
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct StridedInterval {
+    stride: u64,
+    lower: i64,
+    upper: i64,
+}
+
+impl Display for StridedInterval {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}[{},{}]", self.stride, self.lower, self.upper)
+    }
+}
+
+/// Integers held by a strided interval, with a stride of 1
+///
+impl From<I64Interval> for StridedInterval {
+    fn from(range: I64Interval) -> Self {
+        normalized(1, range.lower() as i128, range.upper() as i128)
+    }
+}
+
+/// Integers of an interval, unbound endpoints saturating to `i64::MIN` / `i64::MAX`
+///
+/// Fails if the interval holds no integer of the `i64` range.
+///
+impl TryFrom<Interval> for StridedInterval {
+    type Error = IntervalError;
+
+    fn try_from(interval: Interval) -> Result<Self, Self::Error> {
+        let (lower, upper) = interval.integer_range().ok_or(IntervalError::Empty)?;
+        Ok(normalized(1, lower as i128, upper as i128))
+    }
+}
+
+impl StridedInterval {
+    /// Build set `stride[lower, upper]`, `upper` being rounded down to the last member
+    ///
+    /// Fails if `lower > upper`, or if `stride` is zero while `lower < upper`.
+    ///
+    pub fn new(stride: u64, lower: i64, upper: i64) -> Result<Self, IntervalError> {
+        if lower > upper {
+            return Err(IntervalError::ReversedBounds);
+        }
+        if stride == 0 && lower < upper {
+            return Err(IntervalError::InvalidPeriod);
+        }
+        Ok(normalized(stride as i128, lower as i128, upper as i128))
+    }
+
+    pub fn singleton(k: i64) -> Self {
+        StridedInterval {
+            stride: 0,
+            lower: k,
+            upper: k,
+        }
+    }
+
+    pub fn stride(&self) -> u64 {
+        self.stride
+    }
+
+    pub fn lower(&self) -> i64 {
+        self.lower
+    }
+
+    pub fn upper(&self) -> i64 {
+        self.upper
+    }
+
+    /// Number of members of set
+    ///
+    pub fn count(&self) -> u64 {
+        match self.stride {
+            0 => 1,
+            s => (self.upper.abs_diff(self.lower)) / s + 1,
+        }
+    }
+
+    pub fn contains(&self, k: i64) -> bool {
+        self.lower <= k
+            && k <= self.upper
+            && (self.stride == 0 || k.abs_diff(self.lower) % self.stride == 0)
+    }
+
+    /// Smallest strided interval holding both sets
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::StridedInterval;
+    ///
+    /// let a = StridedInterval::new(4, 0, 8).unwrap();
+    /// let b = StridedInterval::singleton(14);
+    ///
+    /// assert_eq!(a.join(b), StridedInterval::new(2, 0, 14).unwrap());
+    /// ```
+    ///
+    pub fn join(&self, other: StridedInterval) -> StridedInterval {
+        let stride = gcd(
+            gcd(self.stride as i128, other.stride as i128),
+            self.lower as i128 - other.lower as i128,
+        );
+        normalized(
+            stride,
+            self.lower.min(other.lower) as i128,
+            self.upper.max(other.upper) as i128,
+        )
+    }
+
+    /// Sum `self + other`, `None` if bounds overflow
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::StridedInterval;
+    ///
+    /// let a = StridedInterval::new(2, 0, i64::MAX - 1).unwrap();
+    /// let one = StridedInterval::singleton(1);
+    ///
+    /// assert_eq!(a.checked_add(one), StridedInterval::new(2, 1, i64::MAX).ok());
+    /// assert_eq!(a.checked_add(one).unwrap().checked_add(one), None);
+    /// ```
+    ///
+    pub fn checked_add(&self, other: StridedInterval) -> Option<StridedInterval> {
+        add(self, &other)
+    }
+
+    /// Difference `self - other`, `None` if bounds overflow
+    ///
+    pub fn checked_sub(&self, other: StridedInterval) -> Option<StridedInterval> {
+        add(self, &neg(&other)?)
+    }
+
+    /// Product `self * other`, `None` if bounds overflow
+    ///
+    pub fn checked_mul(&self, other: StridedInterval) -> Option<StridedInterval> {
+        mul(self, &other)
+    }
+
+    /// Opposite `-self`, `None` if bounds overflow
+    ///
+    pub fn checked_neg(&self) -> Option<StridedInterval> {
+        neg(self)
+    }
+
+    /// Range of set, without its stride
+    ///
+    pub fn to_i64_interval(&self) -> I64Interval {
+        I64Interval::new(self.lower, self.upper).unwrap()
+    }
+
+    /// Closed interval between smallest and largest members of set
+    ///
+    pub fn to_interval(&self) -> Interval {
+        Interval::new(Closed(self.lower as f64), Closed(self.upper as f64))
+    }
+}
+
+/// Canonical form of `stride[lower, upper]`: `upper` is a member and singletons have a zero
+/// stride
+///
+/// Panics if bounds don't fit in `i64`, see `checked_normalized`.
+///
+fn normalized(stride: i128, lower: i128, upper: i128) -> StridedInterval {
+    checked_normalized(stride, lower, upper).expect(OVERFLOW)
+}
+
+/// Canonical form of `stride[lower, upper]`, `None` if bounds don't fit in `i64`
+///
+fn checked_normalized(stride: i128, lower: i128, upper: i128) -> Option<StridedInterval> {
+    let stride = stride.abs();
+    let upper = if stride == 0 {
+        lower
+    } else {
+        lower + (upper - lower) / stride * stride
+    };
+    Some(StridedInterval {
+        stride: if lower == upper { 0 } else { stride as u64 },
+        lower: i64::try_from(lower).ok()?,
+        upper: i64::try_from(upper).ok()?,
+    })
+}
+
+fn gcd(a: i128, b: i128) -> i128 {
+    let (mut a, mut b) = (a.abs(), b.abs());
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+fn add(a: &StridedInterval, b: &StridedInterval) -> Option<StridedInterval> {
+    checked_normalized(
+        gcd(a.stride as i128, b.stride as i128),
+        a.lower as i128 + b.lower as i128,
+        a.upper as i128 + b.upper as i128,
+    )
+}
+
+fn neg(a: &StridedInterval) -> Option<StridedInterval> {
+    checked_normalized(a.stride as i128, -(a.upper as i128), -(a.lower as i128))
+}
+
+fn mul(a: &StridedInterval, b: &StridedInterval) -> Option<StridedInterval> {
+    let (s1, l1, u1) = (a.stride as i128, a.lower as i128, a.upper as i128);
+    let (s2, l2, u2) = (b.stride as i128, b.lower as i128, b.upper as i128);
+    // (l1 + i s1) (l2 + j s2) - l1 l2 is a multiple of each of three terms
+    let stride = gcd(gcd(s1 * l2, s2 * l1), s1 * s2);
+    let products = [l1 * l2, l1 * u2, u1 * l2, u1 * u2];
+    let (lower, upper) = (
+        products.iter().min().unwrap(),
+        products.iter().max().unwrap(),
+    );
+    let base = l1 * l2;
+    // Snap bounds inward onto members of base + stride ℤ
+    let lower = match stride {
+        0 => base,
+        s => lower + (base - lower).rem_euclid(s),
+    };
+    checked_normalized(stride, lower, *upper)
+}
+
+/// Panic message of operators, see `StridedInterval`
+///
+const OVERFLOW: &str = "overflow in strided interval arithmetic";
+
+impl_op_ex!(+ |a: &StridedInterval, b: &StridedInterval| -> StridedInterval {
+    a.checked_add(*b).expect(OVERFLOW)
+});
+impl_op_ex!(
+    -|a: &StridedInterval, b: &StridedInterval| -> StridedInterval {
+        a.checked_sub(*b).expect(OVERFLOW)
+    }
+);
+impl_op_ex!(
+    *|a: &StridedInterval, b: &StridedInterval| -> StridedInterval {
+        a.checked_mul(*b).expect(OVERFLOW)
+    }
+);
+impl_op_ex!(-|a: &StridedInterval| -> StridedInterval { a.checked_neg().expect(OVERFLOW) });
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Open, Unbound, EMPTY};
+
+    #[test]
+    fn test_new_1() {
+        assert_eq!(
+            StridedInterval::new(1, 2, 1),
+            Err(IntervalError::ReversedBounds)
+        );
+        assert_eq!(
+            StridedInterval::new(0, 1, 2),
+            Err(IntervalError::InvalidPeriod)
+        );
+        assert_eq!(
+            StridedInterval::new(4, 0, 10).unwrap(),
+            StridedInterval::new(4, 0, 8).unwrap()
+        );
+        assert_eq!(
+            StridedInterval::new(4, 1, 4).unwrap(),
+            StridedInterval::singleton(1)
+        );
+        assert_eq!(
+            StridedInterval::new(4, 0, 10).unwrap().to_string(),
+            "4[0,8]"
+        );
+        assert_eq!(StridedInterval::new(4, -3, 9).unwrap().count(), 4);
+        assert!(
+            StridedInterval::new(4, -3, 9).unwrap().contains(1)
+                && !StridedInterval::new(4, -3, 9).unwrap().contains(3)
+        );
+    }
+
+    #[test]
+    fn test_convert_1() {
+        let a = Interval::new(Open(0.5), Closed(3.));
+        assert_eq!(
+            StridedInterval::try_from(a),
+            Ok(StridedInterval::new(1, 1, 3).unwrap())
+        );
+        assert_eq!(StridedInterval::try_from(EMPTY), Err(IntervalError::Empty));
+        assert_eq!(
+            StridedInterval::try_from(Interval::new(Closed(1e30), Closed(1e31))),
+            Err(IntervalError::Empty)
+        );
+        assert_eq!(
+            StridedInterval::try_from(Interval::new(Closed(0.), Unbound)).map(|a| a.upper()),
+            Ok(i64::MAX)
+        );
+        assert_eq!(
+            StridedInterval::new(4, 0, 8).unwrap().to_interval(),
+            Interval::new(Closed(0.), Closed(8.))
+        );
+        let range = I64Interval::new(2, 5).unwrap();
+        assert_eq!(StridedInterval::from(range).to_i64_interval(), range);
+    }
+
+    #[test]
+    fn test_arith_1() {
+        assert_eq!(
+            StridedInterval::new(4, 0, 8).unwrap() + StridedInterval::new(6, 1, 13).unwrap(),
+            StridedInterval::new(2, 1, 21).unwrap()
+        );
+        assert_eq!(
+            -StridedInterval::new(4, 0, 8).unwrap(),
+            StridedInterval::new(4, -8, 0).unwrap()
+        );
+        assert_eq!(
+            StridedInterval::new(4, 0, 8).unwrap() - StridedInterval::singleton(1),
+            StridedInterval::new(4, -1, 7).unwrap()
+        );
+        assert_eq!(
+            StridedInterval::new(2, 1, 5).unwrap() * StridedInterval::singleton(-3),
+            StridedInterval::new(6, -15, -3).unwrap()
+        );
+        assert_eq!(
+            StridedInterval::new(2, 0, 4).unwrap() * StridedInterval::new(3, 0, 6).unwrap(),
+            StridedInterval::new(6, 0, 24).unwrap()
+        );
+        assert_eq!(
+            StridedInterval::new(4, 0, 8)
+                .unwrap()
+                .join(StridedInterval::new(4, 2, 2).unwrap()),
+            StridedInterval::new(2, 0, 8).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_arith_2() {
+        // Brute force soundness check over small strided intervals
+        let mut all = Vec::new();
+        for stride in 0..4 {
+            for lower in -5..5 {
+                for upper in lower..5 {
+                    if let Ok(a) = StridedInterval::new(stride, lower, upper) {
+                        all.push(a);
+                    }
+                }
+            }
+        }
+        let members = |a: StridedInterval| (a.lower..=a.upper).filter(move |k| a.contains(*k));
+        for a in &all {
+            for b in &all {
+                for x in members(*a) {
+                    for y in members(*b) {
+                        assert!((a + b).contains(x + y));
+                        assert!((a - b).contains(x - y));
+                        assert!((a * b).contains(x * y));
+                        assert!(a.join(*b).contains(x) && a.join(*b).contains(y));
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_checked_1() {
+        let max = StridedInterval::singleton(i64::MAX);
+        let min = StridedInterval::singleton(i64::MIN);
+        let one = StridedInterval::singleton(1);
+        assert_eq!(max.checked_add(one), None);
+        assert_eq!(min.checked_sub(one), None);
+        assert_eq!(min.checked_neg(), None);
+        assert_eq!(
+            max.checked_neg(),
+            Some(StridedInterval::singleton(-i64::MAX))
+        );
+        assert_eq!(max.checked_mul(StridedInterval::singleton(2)), None);
+        assert_eq!(
+            StridedInterval::new(4, 0, 8)
+                .unwrap()
+                .checked_mul(StridedInterval::new(2, 1, 3).unwrap()),
+            Some(StridedInterval::new(4, 0, 24).unwrap())
+        );
+        assert_eq!(
+            StridedInterval::new(4, 0, 8).unwrap().checked_sub(one),
+            Some(StridedInterval::new(4, -1, 7).unwrap())
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "overflow")]
+    fn test_arith_3() {
+        let _ = StridedInterval::new(1, 0, i64::MAX).unwrap() + StridedInterval::singleton(1);
+    }
+}
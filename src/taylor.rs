@@ -0,0 +1,368 @@
+//! Taylor models (feature `taylor`)
+//!
+//! A Taylor model encloses a function over a bounded domain as a polynomial in `x - center`
+//! plus an interval remainder. Dependencies between occurrences of the variable are kept
+//! in the polynomial, so that enclosures of smooth functions are much tighter than those
+//! of plain interval arithmetic. Interval operations round outward, but rounding errors of
+//! the floating point coefficients are not carried into the remainder, so that models are
+//! close but not rigorous enclosures.
+
+use super::arith::{add, mul, sqr, sub};
+use super::{Interval, IntervalError, Monotonicity};
+use auto_ops::impl_op_ex;
+
+/// Polynomial in `x - center` of degree `order`, plus an interval remainder, over `domain`
+///
+/// Models combined by arithmetic operators must share domain and order.
+///
+/// # Example
+///
+/// ```
+/// use interval::{Expr, Interval, TaylorModel, Closed};
+///
+/// let domain = Interval::new(Closed(0.), Closed(1.));
+/// let x = TaylorModel::variable(domain, 3).unwrap();
+///
+/// let e = Expr::var(0) * (Expr::constant(1.) - Expr::var(0));
+///
+/// assert_eq!(e.eval(&[domain]), Interval::new(Closed(0.), Closed(1.)));
+/// assert_eq!((&x * (1. - &x)).bound(), Interval::new(Closed(0.), Closed(0.25)));
+/// ```
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct TaylorModel {
+    domain: Interval,
+    center: f64,
+    coefficients: Vec<f64>,
+    remainder: Interval,
+}
+
+impl TaylorModel {
+    /// Model of the identity function over `domain`, with polynomials of degree `order`
+    ///
+    /// Fails if `domain` is empty or unbounded.
+    ///
+    pub fn variable(domain: Interval, order: usize) -> Result<Self, IntervalError> {
+        let (a, b) = domain.endpoints().ok_or(IntervalError::Empty)?;
+        if !(a.is_finite() && b.is_finite()) {
+            return Err(IntervalError::Unbounded);
+        }
+        let center = a / 2. + b / 2.;
+        let mut x = TaylorModel {
+            domain: Interval::from_endpoints(a, b),
+            center,
+            coefficients: vec![0.; order + 1],
+            remainder: Interval::singleton(0.),
+        };
+        x.coefficients[0] = center;
+        if order > 0 {
+            x.coefficients[1] = 1.;
+        } else {
+            x.remainder = x.offsets();
+        }
+        Ok(x)
+    }
+
+    /// Closed domain of model
+    ///
+    pub fn domain(&self) -> Interval {
+        self.domain
+    }
+
+    /// Expansion point of polynomial, the midpoint of domain
+    ///
+    pub fn center(&self) -> f64 {
+        self.center
+    }
+
+    /// Polynomial coefficients, from degree `0` to degree `order`
+    ///
+    pub fn coefficients(&self) -> &[f64] {
+        &self.coefficients
+    }
+
+    pub fn remainder(&self) -> Interval {
+        self.remainder
+    }
+
+    pub fn order(&self) -> usize {
+        self.coefficients.len() - 1
+    }
+
+    /// Enclosure of model at `x`, which should lie in domain
+    ///
+    pub fn eval(&self, x: f64) -> Interval {
+        let t = x - self.center;
+        let p = self
+            .coefficients
+            .iter()
+            .rev()
+            .fold(0., |acc, c| acc * t + c);
+        add(Interval::singleton(p), self.remainder)
+    }
+
+    /// Enclosure of the range of model over its domain
+    ///
+    pub fn bound(&self) -> Interval {
+        add(self.polynomial_bound(&self.coefficients), self.remainder)
+    }
+
+    /// Model of `g ∘ self`, `derivative(a, k)` enclosing the `k`-th derivative of `g`
+    /// over `a`
+    ///
+    /// Derivatives at the constant term of `self`, up to `order`, give the polynomial part
+    /// (`derivative` is called with singletons there); the derivative of degree `order + 1`
+    /// over the range of `self` bounds the Lagrange remainder.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use interval::{Interval, Monotonicity, TaylorModel, Closed};
+    ///
+    /// let x = TaylorModel::variable(Interval::new(Closed(0.), Closed(1.)), 2).unwrap();
+    /// let square = x.compose(|a, k| match k {
+    ///     0 => a.map_monotonic(|x| x * x, Monotonicity::Increasing),
+    ///     1 => a.map_monotonic(|x| 2. * x, Monotonicity::Increasing),
+    ///     2 => Interval::singleton(2.),
+    ///     _ => Interval::singleton(0.),
+    /// });
+    ///
+    /// assert_eq!(square.coefficients(), (&x * &x).coefficients());
+    /// assert_eq!(square.eval(0.25), Interval::singleton(0.0625));
+    /// ```
+    ///
+    pub fn compose(&self, derivative: impl Fn(Interval, usize) -> Interval) -> TaylorModel {
+        let order = self.order();
+        let c0 = self.coefficients[0];
+        let mut h = self.clone();
+        h.coefficients[0] = 0.;
+        let h_bound = h.bound();
+
+        let mut res = self.constant(0.);
+        let mut power = self.constant(1.);
+        let mut factorial = 1.;
+        for k in 0..=order {
+            let d = derivative(Interval::singleton(c0), k);
+            let mid = match d.endpoints() {
+                Some((lower, upper)) if lower.is_finite() && upper.is_finite() => {
+                    lower / 2. + upper / 2.
+                }
+                _ => 0.,
+            };
+            let error = mul(
+                sub(d, Interval::singleton(mid)),
+                Interval::singleton(1. / factorial),
+            );
+            res = res + &power * (mid / factorial);
+            res.remainder = add(res.remainder, mul(error, power.bound()));
+            power = power * &h;
+            factorial *= (k + 1) as f64;
+        }
+        let lagrange = mul(
+            derivative(self.bound(), order + 1),
+            mul(pow(h_bound, order + 1), Interval::singleton(1. / factorial)),
+        );
+        res.remainder = add(res.remainder, lagrange);
+        res
+    }
+
+    /// Model of `exp ∘ self`
+    ///
+    pub fn exp(&self) -> TaylorModel {
+        self.compose(|a, _| a.map_monotonic(f64::exp, Monotonicity::Increasing))
+    }
+
+    /// Constant model `k` with the domain and order of `self`
+    ///
+    fn constant(&self, k: f64) -> TaylorModel {
+        let mut res = TaylorModel {
+            coefficients: vec![0.; self.coefficients.len()],
+            remainder: Interval::singleton(0.),
+            ..*self
+        };
+        res.coefficients[0] = k;
+        res
+    }
+
+    /// Range of `x - center` over domain
+    ///
+    fn offsets(&self) -> Interval {
+        sub(self.domain, Interval::singleton(self.center))
+    }
+
+    /// Enclosure of polynomial with `coefficients` over domain, summed term by term so
+    /// that even powers stay nonnegative
+    ///
+    fn polynomial_bound(&self, coefficients: &[f64]) -> Interval {
+        let t = self.offsets();
+        coefficients
+            .iter()
+            .enumerate()
+            .filter(|(_, &c)| c != 0.)
+            .fold(Interval::singleton(0.), |acc, (k, &c)| {
+                add(acc, mul(Interval::singleton(c), pow(t, k)))
+            })
+    }
+
+    /// Check that models can be combined
+    ///
+    fn assert_compatible(&self, other: &TaylorModel) {
+        assert!(
+            self.domain == other.domain && self.coefficients.len() == other.coefficients.len(),
+            "Taylor models differ in domain or order"
+        );
+    }
+}
+
+/// Enclosure of `a^k`
+///
+fn pow(a: Interval, k: usize) -> Interval {
+    match k {
+        0 => Interval::singleton(1.),
+        1 => a,
+        _ if k % 2 == 0 => sqr(pow(a, k / 2)),
+        _ => mul(a, pow(a, k - 1)),
+    }
+}
+
+fn add_models(a: &TaylorModel, b: &TaylorModel) -> TaylorModel {
+    a.assert_compatible(b);
+    TaylorModel {
+        coefficients: a
+            .coefficients
+            .iter()
+            .zip(&b.coefficients)
+            .map(|(x, y)| x + y)
+            .collect(),
+        remainder: add(a.remainder, b.remainder),
+        ..*a
+    }
+}
+
+fn mul_models(a: &TaylorModel, b: &TaylorModel) -> TaylorModel {
+    a.assert_compatible(b);
+    let n = a.coefficients.len();
+    let mut product = vec![0.; 2 * n - 1];
+    for (i, x) in a.coefficients.iter().enumerate() {
+        for (j, y) in b.coefficients.iter().enumerate() {
+            product[i + j] += x * y;
+        }
+    }
+    let mut truncated = vec![0.; 2 * n - 1];
+    truncated[n..].copy_from_slice(&product[n..]);
+    product.truncate(n);
+
+    let (bound_a, bound_b) = (
+        a.polynomial_bound(&a.coefficients),
+        b.polynomial_bound(&b.coefficients),
+    );
+    let remainder = [
+        a.polynomial_bound(&truncated),
+        mul(bound_a, b.remainder),
+        mul(a.remainder, bound_b),
+        mul(a.remainder, b.remainder),
+    ]
+    .into_iter()
+    .fold(Interval::singleton(0.), add);
+    TaylorModel {
+        coefficients: product,
+        remainder,
+        ..*a
+    }
+}
+
+fn scale_model(a: &TaylorModel, k: f64) -> TaylorModel {
+    TaylorModel {
+        coefficients: a.coefficients.iter().map(|c| c * k).collect(),
+        remainder: mul(Interval::singleton(k), a.remainder),
+        ..*a
+    }
+}
+
+impl_op_ex!(+ |a: &TaylorModel, b: &TaylorModel| -> TaylorModel { add_models(a, b) });
+impl_op_ex!(-|a: &TaylorModel, b: &TaylorModel| -> TaylorModel { add_models(a, &-b) });
+impl_op_ex!(*|a: &TaylorModel, b: &TaylorModel| -> TaylorModel { mul_models(a, b) });
+impl_op_ex!(-|a: &TaylorModel| -> TaylorModel { scale_model(a, -1.) });
+
+impl_op_ex!(+ |a: &TaylorModel, k: f64| -> TaylorModel { a + a.constant(k) });
+impl_op_ex!(+ |k: f64, a: &TaylorModel| -> TaylorModel { a + a.constant(k) });
+impl_op_ex!(-|a: &TaylorModel, k: f64| -> TaylorModel { a - a.constant(k) });
+impl_op_ex!(-|k: f64, a: &TaylorModel| -> TaylorModel { a.constant(k) - a });
+impl_op_ex!(*|a: &TaylorModel, k: f64| -> TaylorModel { scale_model(a, k) });
+impl_op_ex!(*|k: f64, a: &TaylorModel| -> TaylorModel { scale_model(a, k) });
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Closed, Unbound, EMPTY};
+
+    #[test]
+    fn test_variable_1() {
+        assert_eq!(TaylorModel::variable(EMPTY, 2), Err(IntervalError::Empty));
+        assert_eq!(
+            TaylorModel::variable(Interval::new(Closed(0.), Unbound), 2),
+            Err(IntervalError::Unbounded)
+        );
+        let x = TaylorModel::variable(Interval::new(Closed(1.), Closed(3.)), 2).unwrap();
+        assert_eq!(x.center(), 2.);
+        assert_eq!(x.coefficients(), &[2., 1., 0.]);
+        assert_eq!(x.bound(), Interval::new(Closed(1.), Closed(3.)));
+        let y = TaylorModel::variable(Interval::new(Closed(1.), Closed(3.)), 0).unwrap();
+        assert_eq!(y.coefficients(), &[2.]);
+        assert_eq!(y.bound(), Interval::new(Closed(1.), Closed(3.)));
+    }
+
+    #[test]
+    fn test_arith_1() {
+        let x = TaylorModel::variable(Interval::new(Closed(-1.), Closed(1.)), 3).unwrap();
+        assert_eq!((&x - &x).bound(), Interval::singleton(0.));
+        assert_eq!(
+            (&x * &x - 1.).bound(),
+            Interval::new(Closed(-1.), Closed(0.))
+        );
+        assert_eq!((2. * &x + 1.).eval(0.5), Interval::singleton(2.));
+        let cube = &x * &x * &x;
+        assert_eq!(cube.coefficients(), &[0., 0., 0., 1.]);
+        assert_eq!(cube.remainder(), Interval::singleton(0.));
+    }
+
+    #[test]
+    fn test_arith_2() {
+        let x = TaylorModel::variable(Interval::new(Closed(-1.), Closed(1.)), 1).unwrap();
+        let a = &x * &x;
+        assert_eq!(a.coefficients(), &[0., 0.]);
+        assert_eq!(a.remainder(), Interval::new(Closed(0.), Closed(1.)));
+        assert_eq!(a.eval(0.5), Interval::new(Closed(0.), Closed(1.)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_arith_3() {
+        let x = TaylorModel::variable(Interval::new(Closed(-1.), Closed(1.)), 1).unwrap();
+        let y = TaylorModel::variable(Interval::new(Closed(-1.), Closed(1.)), 2).unwrap();
+        let _ = x + y;
+    }
+
+    #[test]
+    fn test_exp_1() {
+        let x = TaylorModel::variable(Interval::new(Closed(0.), Closed(1.)), 8).unwrap();
+        let (lower, upper) = x.exp().bound().endpoints().unwrap();
+        let e = std::f64::consts::E;
+        assert!(lower <= 1.);
+        assert!(upper >= e && upper < e + 1e-6);
+        let y = x.exp().eval(0.5).endpoints().unwrap();
+        assert!(y.0 <= 0.5f64.exp() && 0.5f64.exp() <= y.1);
+        assert!(y.1 - y.0 < 1e-6);
+    }
+
+    #[test]
+    fn test_exp_2() {
+        let x = TaylorModel::variable(Interval::new(Closed(-1.), Closed(1.)), 4).unwrap();
+        let a = (-(&x * &x)).exp();
+        let (lower, upper) = a.bound().endpoints().unwrap();
+        assert!(lower <= (-1f64).exp() && upper >= 1.);
+        assert!(upper - lower < 2.);
+        let y = a.eval(0.5).endpoints().unwrap();
+        assert!(y.0 <= (-0.25f64).exp() && (-0.25f64).exp() <= y.1);
+    }
+}
@@ -0,0 +1,245 @@
+//! JavaScript bindings with `wasm-bindgen` (feature `wasm`)
+//!
+//! `Interval` and `IntervalSet` are exported under the same names. Endpoints are plain
+//! numbers, `undefined` standing for an unbound endpoint when building an interval and
+//! `±Infinity` being returned for one. Both types parse text written as they display it,
+//! though `toString` rounds endpoints to two decimals and doesn't read back exactly. They
+//! convert to JSON with `toJSON`: an interval is `null` if empty, else
+//! `{"lower": …, "upper": …, "lowerClosed": …, "upperClosed": …}` with `null` for unbound
+//! endpoints; a set is an array of intervals.
+//!
+//! ```js
+//! import { Interval, IntervalSet } from "interval";
+//!
+//! const a = new Interval(0, 8, true, false);
+//! const s = IntervalSet.parse("[0,8) U [20,+∞)");
+//!
+//! s.contains(4);                // true
+//! JSON.stringify(a);            // '{"lower":0,"upper":8,"lowerClosed":true,"upperClosed":false}'
+//! s.intervals()[1].upper;       // Infinity
+//! ```
+
+use wasm_bindgen::prelude::*;
+
+use super::{Closed, Interval, IntervalSet, Open, Unbound};
+
+/// JavaScript wrapper of `Interval`
+///
+#[wasm_bindgen(js_name = Interval)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct JsInterval(Interval);
+
+/// JavaScript wrapper of `IntervalSet`
+///
+#[wasm_bindgen(js_name = IntervalSet)]
+#[derive(Debug, Clone, PartialEq)]
+pub struct JsIntervalSet(IntervalSet);
+
+#[wasm_bindgen(js_class = Interval)]
+impl JsInterval {
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        lower: Option<f64>,
+        upper: Option<f64>,
+        lower_closed: bool,
+        upper_closed: bool,
+    ) -> JsInterval {
+        let bound = |k: Option<f64>, closed: bool| match k {
+            None => Unbound,
+            Some(k) if closed => Closed(k),
+            Some(k) => Open(k),
+        };
+        JsInterval(Interval::new(
+            bound(lower, lower_closed),
+            bound(upper, upper_closed),
+        ))
+    }
+
+    pub fn parse(text: &str) -> Result<JsInterval, JsError> {
+        Ok(JsInterval(text.parse()?))
+    }
+
+    /// Lower endpoint, `undefined` if interval is empty
+    ///
+    #[wasm_bindgen(getter)]
+    pub fn lower(&self) -> Option<f64> {
+        self.0.endpoints().map(|(lower, _)| lower)
+    }
+
+    /// Upper endpoint, `undefined` if interval is empty
+    ///
+    #[wasm_bindgen(getter)]
+    pub fn upper(&self) -> Option<f64> {
+        self.0.endpoints().map(|(_, upper)| upper)
+    }
+
+    #[wasm_bindgen(getter, js_name = lowerClosed)]
+    pub fn lower_closed(&self) -> bool {
+        self.0.is_left_closed()
+    }
+
+    #[wasm_bindgen(getter, js_name = upperClosed)]
+    pub fn upper_closed(&self) -> bool {
+        self.0.is_right_closed()
+    }
+
+    #[wasm_bindgen(js_name = isEmpty)]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn contains(&self, x: f64) -> bool {
+        self.0.contains(x)
+    }
+
+    pub fn intersection(&self, other: &JsInterval) -> JsInterval {
+        JsInterval(self.0.intersection(other.0))
+    }
+
+    /// Union of intervals, as a set since it may not be an interval
+    ///
+    pub fn union(&self, other: &JsInterval) -> JsIntervalSet {
+        JsIntervalSet(IntervalSet::from(&[self.0, other.0]))
+    }
+
+    #[wasm_bindgen(js_name = toString)]
+    pub fn to_string_js(&self) -> String {
+        self.0.to_string()
+    }
+
+    #[wasm_bindgen(js_name = toJSON)]
+    pub fn to_json(&self) -> Result<JsValue, JsValue> {
+        js_sys::JSON::parse(&interval_json(self.0))
+    }
+}
+
+#[wasm_bindgen(js_class = IntervalSet)]
+impl JsIntervalSet {
+    #[wasm_bindgen(constructor)]
+    pub fn new(intervals: Vec<JsInterval>) -> JsIntervalSet {
+        JsIntervalSet(IntervalSet::from_unsorted(
+            intervals.into_iter().map(|i| i.0).collect(),
+        ))
+    }
+
+    pub fn parse(text: &str) -> Result<JsIntervalSet, JsError> {
+        Ok(JsIntervalSet(text.parse()?))
+    }
+
+    #[wasm_bindgen(js_name = isEmpty)]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn contains(&self, x: f64) -> bool {
+        self.0.contains(x)
+    }
+
+    pub fn union(&self, other: &JsIntervalSet) -> JsIntervalSet {
+        JsIntervalSet(self.0.union_intervals(&other.0))
+    }
+
+    pub fn intersection(&self, other: &JsIntervalSet) -> JsIntervalSet {
+        JsIntervalSet(self.0.intersection(&other.0))
+    }
+
+    pub fn measure(&self) -> f64 {
+        self.0.measure()
+    }
+
+    /// Disjoint intervals of set, in increasing order
+    ///
+    pub fn intervals(&self) -> Vec<JsInterval> {
+        self.0.as_slice().iter().map(|i| JsInterval(*i)).collect()
+    }
+
+    #[wasm_bindgen(js_name = toString)]
+    pub fn to_string_js(&self) -> String {
+        self.0.to_string()
+    }
+
+    #[wasm_bindgen(js_name = toJSON)]
+    pub fn to_json(&self) -> Result<JsValue, JsValue> {
+        js_sys::JSON::parse(&set_json(&self.0))
+    }
+}
+
+/// JSON text of interval, see module documentation
+///
+fn interval_json(a: Interval) -> String {
+    let Some((lower, upper)) = a.endpoints() else {
+        return "null".to_string();
+    };
+    let number = |k: f64| {
+        if k.is_finite() {
+            format!("{k}")
+        } else {
+            "null".to_string()
+        }
+    };
+    format!(
+        r#"{{"lower":{},"upper":{},"lowerClosed":{},"upperClosed":{}}}"#,
+        number(lower),
+        number(upper),
+        a.is_left_closed(),
+        a.is_right_closed()
+    )
+}
+
+/// JSON text of set, an array of intervals
+///
+fn set_json(set: &IntervalSet) -> String {
+    let intervals: Vec<_> = set.as_slice().iter().map(|a| interval_json(*a)).collect();
+    format!("[{}]", intervals.join(","))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::EMPTY;
+
+    #[test]
+    fn test_new_1() {
+        let a = JsInterval::new(Some(0.), Some(8.), true, false);
+        assert_eq!(a.0, Interval::new(Closed(0.), Open(8.)));
+        let b = JsInterval::new(None, Some(1.), true, true);
+        assert_eq!((b.lower(), b.upper()), (Some(f64::NEG_INFINITY), Some(1.)));
+        assert!(!b.lower_closed() && b.upper_closed());
+        assert_eq!(JsInterval(EMPTY).lower(), None);
+    }
+
+    #[test]
+    fn test_parse_1() {
+        // Errors can't be built outside of a JavaScript engine, only check parsed values
+        let a = JsInterval::parse("(-inf, 8]").unwrap();
+        assert_eq!(a.0, Interval::new(Unbound, Closed(8.)));
+        assert_eq!(JsInterval::parse("[-∞,+∞)").unwrap().0, crate::INFINITY);
+        let s = JsIntervalSet::parse("[20,+∞) U [0,8)").unwrap();
+        assert_eq!(
+            s.0,
+            IntervalSet::from(&[
+                Interval::new(Closed(0.), Open(8.)),
+                Interval::new(Closed(20.), Unbound),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_json_1() {
+        assert_eq!(interval_json(EMPTY), "null");
+        assert_eq!(
+            interval_json(Interval::new(Closed(0.), Open(8.5))),
+            r#"{"lower":0,"upper":8.5,"lowerClosed":true,"upperClosed":false}"#
+        );
+        let set =
+            IntervalSet::from(&[Interval::new(Unbound, Closed(-1.)), Interval::singleton(2.)]);
+        assert_eq!(
+            set_json(&set),
+            concat!(
+                r#"[{"lower":null,"upper":-1,"lowerClosed":false,"upperClosed":true},"#,
+                r#"{"lower":2,"upper":2,"lowerClosed":true,"upperClosed":true}]"#
+            )
+        );
+        assert_eq!(set_json(&IntervalSet::new()), "[]");
+    }
+}